@@ -1406,6 +1406,45 @@ pub trait CommonInteractorTrait: InteractorHelpers {
         assert_expected_logs(logs, expected_log.unwrap_or_default());
     }
 
+    async fn deposit_in_mvx_esdt_safe_and_get_gas_used(
+        &mut self,
+        to: Address,
+        shard: u32,
+        payments: PaymentsVec<StaticApi>,
+    ) -> u64 {
+        let user_address = self.user_address().clone();
+        let current_mvx_esdt_safe_address =
+            self.common_state().get_mvx_esdt_safe_address(shard).clone();
+        self.interactor()
+            .tx()
+            .from(user_address)
+            .to(current_mvx_esdt_safe_address)
+            .gas(90_000_000u64)
+            .typed(MvxEsdtSafeProxy)
+            .deposit(to, OptionalValueTransferDataTuple::<StaticApi>::None)
+            .payment(payments)
+            .returns(ReturnsGasUsed)
+            .run()
+            .await
+    }
+
+    async fn fund_issue_pool_in_mvx_esdt_safe(&mut self, shard: u32, amount: BigUint<StaticApi>) {
+        let user_address = self.user_address().clone();
+        let current_mvx_esdt_safe_address =
+            self.common_state().get_mvx_esdt_safe_address(shard).clone();
+        self.interactor()
+            .tx()
+            .from(user_address)
+            .to(current_mvx_esdt_safe_address)
+            .gas(90_000_000u64)
+            .typed(MvxEsdtSafeProxy)
+            .fund_issue_pool()
+            .egld(amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+    }
+
     async fn withdraw_from_testing_sc(
         &mut self,
         expected_token: EsdtTokenInfo,
@@ -8,14 +8,17 @@ use common_test_setup::constants::{
     UNKNOWN_FEE_MARKET, UNKNOWN_MVX_ESDT_SAFE, USER_ADDRESS_STR,
 };
 use common_test_setup::log;
-use error_messages::{AMOUNT_IS_TOO_LARGE, FAILED_TO_PARSE_AS_NUMBER};
+use error_messages::{
+    AMOUNT_IS_TOO_LARGE, FAILED_TO_PARSE_AS_NUMBER, PERCENTAGE_FEE_TOKEN_NOT_PRICED,
+};
+use fee_common::helpers::TOTAL_PERCENTAGE;
 use multiversx_sc::api::{ESDT_LOCAL_BURN_FUNC_NAME, ESDT_NFT_BURN_FUNC_NAME};
 use multiversx_sc::{
     codec::{num_bigint, TopEncode},
     imports::{Bech32Address, MultiValue3, OptionalValue},
     types::{
-        Address, BigUint, EgldOrEsdtTokenPayment, EsdtTokenData, EsdtTokenType, ManagedAddress,
-        ManagedBuffer, ManagedVec, MultiValueEncoded,
+        Address, BigUint, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, EsdtTokenData,
+        EsdtTokenType, ManagedAddress, ManagedBuffer, ManagedVec, MultiValueEncoded,
     },
 };
 use multiversx_sc_snippets::{
@@ -225,17 +228,63 @@ pub trait InteractorHelpers {
         with_transfer_data: bool,
         token: Option<EsdtTokenInfo>,
     ) -> BigUint<StaticApi> {
+        let fixed_fee = |per_transfer: &BigUint<StaticApi>, per_gas: &BigUint<StaticApi>| {
+            match (with_transfer_data, token.is_some()) {
+                (true, true) => per_transfer.clone() + per_gas.clone() * GAS_LIMIT, // Transfer + SC call
+                (true, false) => per_gas.clone() * GAS_LIMIT,                       // SC call only
+                (false, _) => per_transfer.clone(),                                 // Transfer only
+            }
+        };
+        let percentage_fee = |fee_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
+                              basis_points: usize,
+                              min: &BigUint<StaticApi>,
+                              max: &BigUint<StaticApi>| {
+            let Some(token) = token.as_ref() else {
+                return BigUint::zero();
+            };
+            // No conversion rates are set up here, so the fee market rejects any other token.
+            assert!(
+                &token.token_id == fee_token,
+                "{}",
+                PERCENTAGE_FEE_TOKEN_NOT_PRICED
+            );
+            let transfer_fee = token.amount.clone() * basis_points as u64 / TOTAL_PERCENTAGE as u64;
+
+            if &transfer_fee < min {
+                min.clone()
+            } else if &transfer_fee > max {
+                max.clone()
+            } else {
+                transfer_fee
+            }
+        };
+
         match &fee_struct.fee_type {
             FeeType::Fixed {
                 per_transfer,
                 per_gas,
                 ..
+            } => fixed_fee(per_transfer, per_gas),
+            FeeType::Percentage {
+                token: fee_token,
+                basis_points,
+                min,
+                max,
+            } => percentage_fee(fee_token, *basis_points, min, max),
+            FeeType::FixedPlusPercentage {
+                token: fee_token,
+                per_transfer,
+                per_gas,
+                basis_points,
+                min,
+                max,
             } => {
-                match (with_transfer_data, token.is_some()) {
-                    (true, true) => per_transfer.clone() + per_gas.clone() * GAS_LIMIT, // Transfer + SC call
-                    (true, false) => per_gas.clone() * GAS_LIMIT, // SC call only
-                    (false, _) => per_transfer.clone(),           // Transfer only
-                }
+                fixed_fee(per_transfer, per_gas)
+                    + percentage_fee(fee_token, *basis_points, min, max)
+            }
+            FeeType::Tiered { tiers, .. } => {
+                let first_tier = tiers.get(0);
+                fixed_fee(&first_tier.per_transfer, &first_tier.per_gas)
             }
             FeeType::None => BigUint::zero(),
         }
@@ -15,7 +15,7 @@ use multiversx_sc_scenario::{
 };
 use mvx_esdt_safe::bridging_mechanism::BridgingMechanism;
 use proxies::mvx_fee_market_proxy::MvxFeeMarketProxy;
-use structs::OperationHashStatus;
+use structs::{OperationHashStatus, TokenMechanism};
 
 use crate::base_setup::init::ErrorPayloadToString;
 use crate::{
@@ -147,26 +147,39 @@ impl BaseSetup {
     pub fn check_deposited_tokens_amount(
         &mut self,
         tokens: Vec<(EgldOrEsdtTokenIdentifier<StaticApi>, u64)>,
+    ) {
+        let tokens = tokens
+            .into_iter()
+            .map(|(token_id, amount)| (token_id, 0u64, amount))
+            .collect();
+
+        self.check_deposited_tokens_amount_with_nonce(tokens);
+    }
+
+    pub fn check_deposited_tokens_amount_with_nonce(
+        &mut self,
+        tokens: Vec<(EgldOrEsdtTokenIdentifier<StaticApi>, u64, u64)>,
     ) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
             .to(ESDT_SAFE_ADDRESS)
             .whitebox(mvx_esdt_safe::contract_obj, |sc| {
-                let tokens: Vec<(EgldOrEsdtTokenIdentifier<DebugApi>, u64)> = tokens
+                let tokens: Vec<(EgldOrEsdtTokenIdentifier<DebugApi>, u64, u64)> = tokens
                     .into_iter()
-                    .map(|(token_id, amount)| {
+                    .map(|(token_id, nonce, amount)| {
                         let token_id_bytes = token_id.to_boxed_bytes();
                         (
                             EgldOrEsdtTokenIdentifier::<DebugApi>::from(token_id_bytes.as_slice()),
+                            nonce,
                             amount,
                         )
                     })
                     .collect();
                 for token in tokens {
-                    let (token_id, amount) = token;
+                    let (token_id, nonce, amount) = token;
                     assert!(
-                        sc.deposited_tokens_amount(&token_id).get() == amount,
+                        sc.deposited_tokens_amount(&token_id, nonce).get() == amount,
                         "{}",
                         INCORRECT_DEPOSIT_AMOUNT
                     );
@@ -174,6 +187,18 @@ impl BaseSetup {
             });
     }
 
+    pub fn check_token_registry_mechanism(&mut self, token_id: &str, mechanism: TokenMechanism) {
+        self.world
+            .query()
+            .to(ESDT_SAFE_ADDRESS)
+            .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+                let registry_entry = sc
+                    .token_registry_entry(&EgldOrEsdtTokenIdentifier::from(token_id))
+                    .get();
+                assert!(registry_entry.mechanism == mechanism);
+            });
+    }
+
     pub fn check_multiversx_to_sovereign_token_id_mapper_is_empty(&mut self, token_name: &str) {
         self.world
             .query()
@@ -19,7 +19,7 @@ use multiversx_sc_scenario::{
     api::StaticApi,
     imports::{
         ManagedBuffer, MultiEgldOrEsdtPayment, MultiValueEncoded, ReturnsHandledOrError,
-        TestAddress,
+        TestAddress, TestSCAddress,
     },
     ReturnsLogs, ScenarioTxRun,
 };
@@ -28,8 +28,10 @@ use proxies::{
     chain_config_proxy::ChainConfigContractProxy, header_verifier_proxy::HeaderverifierProxy,
     mvx_fee_market_proxy::MvxFeeMarketProxy,
 };
+use sovereign_forge::forge_common::storage::StorageModule;
 use structs::aliases::TxNonce;
 use structs::fee::FeeStruct;
+use structs::forge::{ContractInfo, ScArray};
 use structs::generate_hash::GenerateHash;
 use structs::{ValidatorData, ValidatorOperation};
 
@@ -116,6 +118,36 @@ impl BaseSetup {
             .run();
     }
 
+    pub fn set_esdt_safe_successor_in_forge(&mut self, chain_id: &str, successor: TestSCAddress) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SOVEREIGN_FORGE_SC_ADDRESS)
+            .whitebox(sovereign_forge::contract_obj, |sc| {
+                sc.esdt_safe_successor(&ManagedBuffer::from(chain_id))
+                    .set(successor.to_managed_address());
+            });
+    }
+
+    pub fn set_deployed_sovereign_contracts_in_forge(
+        &mut self,
+        chain_id: &str,
+        contracts: Vec<(ScArray, TestSCAddress)>,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SOVEREIGN_FORGE_SC_ADDRESS)
+            .whitebox(sovereign_forge::contract_obj, |sc| {
+                let mut deployed_contracts_mapper =
+                    sc.sovereign_deployed_contracts(&ManagedBuffer::from(chain_id));
+                for (id, address) in contracts {
+                    deployed_contracts_mapper
+                        .insert(ContractInfo::new(id, address.to_managed_address()));
+                }
+            });
+    }
+
     pub fn register_validator(
         &mut self,
         hash_of_hashes: &ManagedBuffer<StaticApi>,
@@ -1,7 +1,14 @@
 use multiversx_sc_scenario::imports::{MxscPath, TestAddress, TestSCAddress, TestTokenIdentifier};
 
 pub const ESDT_SAFE_ADDRESS: TestSCAddress = TestSCAddress::new("esdt-safe");
+pub const SUCCESSOR_ESDT_SAFE_ADDRESS: TestSCAddress = TestSCAddress::new("successor-esdt-safe");
+pub const OTHER_SHARD_SUCCESSOR_ADDRESS: TestSCAddress =
+    TestSCAddress::new("other-shard-successor0");
+pub const DESTINATION_ESDT_SAFE_ADDRESS: TestSCAddress =
+    TestSCAddress::new("destination-esdt-safe");
 pub const FEE_MARKET_ADDRESS: TestSCAddress = TestSCAddress::new("fee-market");
+pub const DESTINATION_FEE_MARKET_ADDRESS: TestSCAddress =
+    TestSCAddress::new("destination-fee-market");
 pub const SOV_FEE_MARKET_ADDRESS: TestSCAddress = TestSCAddress::new("sov-fee-market");
 pub const HEADER_VERIFIER_ADDRESS: TestSCAddress = TestSCAddress::new("header-verifier");
 pub const CHAIN_CONFIG_ADDRESS: TestSCAddress = TestSCAddress::new("chain-config");
@@ -65,6 +72,7 @@ pub const PREFIX_NFT_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("s
 pub const WEGLD_IDENTIFIER: TestTokenIdentifier = TestTokenIdentifier::new("WEGLD-123456");
 pub const WRONG_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("WRONG-TOKEN");
 pub const TRUSTED_TOKEN: &str = "USDC-c76f1f";
+pub const TRUSTED_SFT_TOKEN: &str = "LSTSFT-a1b2c3";
 
 pub const SOVEREIGN_RECEIVER_ADDRESS: TestAddress =
     TestAddress::new("erd18tudnj2z8vjh0339yu3vrkgzz2jpz8mjq0uhgnmklnap6z33qqeszq2yn4");
@@ -75,6 +83,7 @@ pub const MVX_TO_SOV_TOKEN_STORAGE_KEY: &str = "mvxToSovTokenId";
 pub const OPERATION_HASH_STATUS_STORAGE_KEY: &str = "operationHashStatus";
 pub const SOVEREIGN_TOKEN_PREFIX: &str = "sov";
 pub const CHAIN_ID: &str = "svch";
+pub const DESTINATION_CHAIN_ID: &str = "dest";
 pub const INTERACTOR_WORKING_DIR: &str = "interactor";
 pub const WRONG_ENDPOINT_NAME: &str = "WRONG-ENDPOINT-NAME";
 pub const ESDT_SAFE_CONFIG_STORAGE_KEY: &str = "crossChainConfig";
@@ -86,6 +95,7 @@ pub const SHARD_1: u32 = 1;
 pub const SHARD_2: u32 = 2;
 pub const PAUSE_CONTRACT_LOG: &str = "pauseContract";
 pub const UNPAUSE_CONTRACT_LOG: &str = "unpauseContract";
+pub const SET_TOKEN_FREEZE_STATUS_LOG: &str = "setTokenFreezeStatus";
 pub const TESTING_SC_ENDPOINT: &str = "hello";
 pub const READ_NATIVE_TOKEN_TESTING_SC_ENDPOINT: &str = "read_native_token";
 pub const STATE_FILE: &str = "state.toml";
@@ -112,19 +122,33 @@ pub const NUM_TOKENS_TO_MINT: u64 = 2;
 pub const EXECUTED_BRIDGE_OP_EVENT: &str = "executedBridgeOp";
 pub const DEPOSIT_EVENT: &str = "deposit";
 pub const SC_CALL_EVENT: &str = "scCall";
+pub const FEE_COLLECTED_EVENT: &str = "feeCollected";
+pub const FEE_EXEMPTIONS_ADDED_EVENT: &str = "feeExemptionsAdded";
+pub const FEE_EXEMPTIONS_REMOVED_EVENT: &str = "feeExemptionsRemoved";
 pub const REGISTER_TOKEN_ENDPOINT: &str = "registerToken";
 pub const EXECUTE_OPERATION_ENDPOINT: &str = "execute";
 pub const EXECUTE_BRIDGE_OPS_ENDPOINT: &str = "executeBridgeOps";
 pub const REGISTER_TOKEN_EVENT: &str = "register_token";
 pub const CHANGE_VALIDATOR_SET_ENDPOINT: &str = "changeValidatorSet";
 pub const UPDATE_ESDT_SAFE_CONFIG_ENDPOINT: &str = "updateEsdtSafeConfig";
+pub const PATCH_ESDT_SAFE_CONFIG_ENDPOINT: &str = "patchEsdtSafeConfig";
+pub const MIGRATE_SAFE_ENDPOINT: &str = "migrateSafe";
 pub const SET_FEE_ENDPOINT: &str = "setFee";
 pub const REMOVE_FEE_ENDPOINT: &str = "removeFee";
 pub const DISTRIBUTE_FEES_ENDPOINT: &str = "distributeFees";
+pub const ALLOCATE_FEES_ENDPOINT: &str = "allocateFees";
+pub const SET_FEE_SPLIT_ENDPOINT: &str = "setFeeSplit";
+pub const SUBTRACT_FEE_ENDPOINT: &str = "subtractFee";
 pub const COMPLETE_SETUP_PHASE_ENDPOINT: &str = "completeSetupPhase";
 pub const REGISTER_BLS_KEY_ENDPOINT: &str = "registerBlsKey";
 pub const UNREGISTER_BLS_KEY_ENDPOINT: &str = "unregisterBlsKey";
 pub const UPDATE_SOVEREIGN_CONFIG_ENDPOINT: &str = "updateSovereignConfig";
+pub const DEPOSIT_MULTI_ENDPOINT: &str = "depositMulti";
+pub const UPDATE_NFT_METADATA_ENDPOINT: &str = "updateNftMetadata";
+pub const SET_ISSUE_COST_ENDPOINT: &str = "setIssueCost";
+pub const SET_FEE_PAYMENT_TOKEN_ENDPOINT: &str = "setFeePaymentToken";
+pub const ADD_FEE_EXEMPTIONS_ENDPOINT: &str = "addFeeExemptions";
+pub const REMOVE_FEE_EXEMPTIONS_ENDPOINT: &str = "removeFeeExemptions";
 pub const MULTI_ESDT_NFT_TRANSFER_EVENT: &str = "MultiESDTNFTTransfer";
 pub const TRANSFER_VALUE_ONLY_LOG: &str = "transferValueOnly";
 
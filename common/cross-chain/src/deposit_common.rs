@@ -1,14 +1,18 @@
 use error_messages::{
-    BANNED_ENDPOINT_NAME, CALLER_IS_BLACKLISTED, DEPOSIT_OVER_MAX_AMOUNT, ESDT_SAFE_STILL_PAUSED,
-    GAS_LIMIT_TOO_HIGH, NOTHING_TO_TRANSFER, TOKEN_BLACKLISTED, TOO_MANY_TOKENS,
+    BANNED_ENDPOINT_NAME, CALLER_IS_BLACKLISTED, DEPOSIT_ENTRIES_PAYMENTS_MISMATCH,
+    DEPOSIT_OVER_MAX_AMOUNT, DEPOSIT_UNDER_MIN_AMOUNT, ESDT_SAFE_STILL_PAUSED, GAS_LIMIT_TOO_HIGH,
+    INVALID_PAYMENT_INDEX, INVALID_REFUND_ADDRESS, NOTHING_TO_TRANSFER, SC_CALL_TARGET_NOT_ALLOWED,
+    TOKEN_BLACKLISTED, TOO_MANY_TOKENS,
 };
 use multiversx_sc::api::ESDT_LOCAL_BURN_FUNC_NAME;
 use proxies::mvx_fee_market_proxy::MvxFeeMarketProxy;
 use structs::{
     aliases::{
-        EventPaymentTuple, ExtractedFeeResult, GasLimit, OptionalValueTransferDataTuple, TxNonce,
+        EventPaymentTuple, ExtractedFeeResult, GasLimit, OptionalValueTransferDataTuple,
+        PaymentsVec, TxNonce,
     },
-    operation::{OperationData, TransferData},
+    operation::{MultiDepositEntry, OperationData, TransferData},
+    EsdtInfo,
 };
 
 use crate::MAX_TRANSFERS_PER_TX;
@@ -27,88 +31,197 @@ pub trait DepositCommonModule:
         &self,
         to: ManagedAddress,
         opt_transfer_data: OptionalValueTransferDataTuple<Self::Api>,
+        opt_refund_address: Option<ManagedAddress>,
         process_payment: F,
     ) where
         F: Fn(&EgldOrEsdtTokenPayment<Self::Api>) -> EventPaymentTuple<Self::Api>,
     {
         require!(self.not_paused(), ESDT_SAFE_STILL_PAUSED);
         self.require_caller_not_blacklisted();
+        if let Some(refund_address) = opt_refund_address.as_ref() {
+            require!(!refund_address.is_zero(), INVALID_REFUND_ADDRESS);
+        }
 
         let option_transfer_data = TransferData::from_optional_value(opt_transfer_data.clone());
 
         if let Some(transfer_data) = option_transfer_data.as_ref() {
             self.require_gas_limit_under_limit(transfer_data.gas_limit);
             self.require_endpoint_not_banned(&transfer_data.function);
+            self.require_sc_call_target_allowed(&to, &transfer_data.function);
         }
 
+        let destinations = ManagedVec::from_single_item(to.clone());
         let (fees_payment, payments) = self
-            .check_and_extract_fee(opt_transfer_data.is_some())
+            .check_and_extract_fee(opt_transfer_data.is_some(), &destinations)
             .into_tuple();
 
-        let mut total_tokens_for_fees = 0usize;
+        let mut payments_for_fees = PaymentsVec::new();
         let mut event_payments = MultiValueEncoded::new();
         let mut refundable_payments = ManagedVec::<Self::Api, _>::new();
 
         for payment in &payments {
             let token_identifier = payment.token_identifier.clone();
             self.require_below_max_amount(&token_identifier, &payment.amount);
+            self.require_above_min_amount(&token_identifier, &payment.amount);
             self.require_token_not_on_blacklist(&token_identifier);
 
             if !self.is_token_whitelist_empty() && !self.is_token_whitelisted(&token_identifier) {
                 refundable_payments.push(payment.clone());
                 continue;
             }
-            total_tokens_for_fees += 1;
+            payments_for_fees.push(payment.clone());
 
             let processed_payment = process_payment(&payment);
 
             event_payments.push(processed_payment);
         }
 
-        self.match_fee_payment(total_tokens_for_fees, &fees_payment, &option_transfer_data);
+        let gas_limit = option_transfer_data
+            .as_ref()
+            .map_or(0, |transfer_data| transfer_data.gas_limit);
+        self.match_fee_payment(payments_for_fees, &fees_payment, gas_limit, destinations);
 
         let caller = self.blockchain().get_caller();
-        self.refund_tokens(&caller, refundable_payments);
-
-        let tx_nonce = self.get_current_and_increment_tx_nonce();
+        let operation_data = OperationData::new(
+            self.get_current_and_increment_tx_nonce(),
+            caller,
+            option_transfer_data,
+        )
+        .with_refund_address(opt_refund_address);
+        self.refund_tokens(operation_data.refund_address(), refundable_payments);
 
         if payments.is_empty() {
-            self.sc_call_event(
-                &to,
-                OperationData::new(tx_nonce, caller, option_transfer_data),
-            );
+            self.sc_call_event(&to, operation_data);
 
             return;
         }
 
-        self.deposit_event(
-            &to,
-            &event_payments,
-            OperationData::new(tx_nonce, caller, option_transfer_data),
+        self.deposit_event(&to, &event_payments, operation_data);
+    }
+
+    /// Each entry carries exactly one of the deposited payments. Fees are charged once for the
+    /// entries that are sent, with the gas limits of their transfer data added up.
+    fn deposit_multi_common<F>(
+        &self,
+        entries: MultiValueEncoded<MultiDepositEntry<Self::Api>>,
+        opt_refund_address: Option<ManagedAddress>,
+        process_payment: F,
+    ) where
+        F: Fn(&EgldOrEsdtTokenPayment<Self::Api>) -> EventPaymentTuple<Self::Api>,
+    {
+        require!(self.not_paused(), ESDT_SAFE_STILL_PAUSED);
+        self.require_caller_not_blacklisted();
+        if let Some(refund_address) = opt_refund_address.as_ref() {
+            require!(!refund_address.is_zero(), INVALID_REFUND_ADDRESS);
+        }
+
+        let entries = entries.to_vec();
+        require!(!entries.is_empty(), NOTHING_TO_TRANSFER);
+
+        let mut has_transfer_data = false;
+        let mut destinations = ManagedVec::new();
+        for entry in entries.iter() {
+            destinations.push(entry.to.clone());
+            if let Some(transfer_data) = entry.opt_transfer_data.as_ref() {
+                self.require_gas_limit_under_limit(transfer_data.gas_limit);
+                self.require_endpoint_not_banned(&transfer_data.function);
+                self.require_sc_call_target_allowed(&entry.to, &transfer_data.function);
+                has_transfer_data = true;
+            }
+        }
+
+        // Which entries are sent is only known once the fee payment is split off, so every
+        // destination counts here.
+        let (fees_payment, payments) = self
+            .check_and_extract_fee(has_transfer_data, &destinations)
+            .into_tuple();
+        require!(
+            entries.len() == payments.len(),
+            DEPOSIT_ENTRIES_PAYMENTS_MISMATCH
+        );
+
+        let mut used_payment_indexes = ManagedVec::<Self::Api, usize>::new();
+        for entry in entries.iter() {
+            require!(
+                entry.payment_index < payments.len()
+                    && !used_payment_indexes.contains(&entry.payment_index),
+                INVALID_PAYMENT_INDEX
+            );
+            used_payment_indexes.push(entry.payment_index);
+        }
+
+        let mut payments_for_fees = PaymentsVec::new();
+        let mut sent_destinations = ManagedVec::new();
+        let mut total_gas_limit: GasLimit = 0;
+        let mut deposits = ManagedVec::<Self::Api, MultiDepositEntry<Self::Api>>::new();
+        let mut refundable_payments = ManagedVec::<Self::Api, _>::new();
+
+        for entry in entries.iter() {
+            let payment = payments.get(entry.payment_index);
+            let token_identifier = &payment.token_identifier;
+            self.require_below_max_amount(token_identifier, &payment.amount);
+            self.require_above_min_amount(token_identifier, &payment.amount);
+            self.require_token_not_on_blacklist(token_identifier);
+
+            if !self.is_token_whitelist_empty() && !self.is_token_whitelisted(token_identifier) {
+                refundable_payments.push(payment.clone());
+                continue;
+            }
+            payments_for_fees.push(payment.clone());
+            sent_destinations.push(entry.to.clone());
+
+            if let Some(transfer_data) = entry.opt_transfer_data.as_ref() {
+                total_gas_limit += transfer_data.gas_limit;
+            }
+            deposits.push(entry.clone());
+        }
+
+        self.match_fee_payment(
+            payments_for_fees,
+            &fees_payment,
+            total_gas_limit,
+            sent_destinations,
         );
+
+        let caller = self.blockchain().get_caller();
+        let refund_address = opt_refund_address.clone().unwrap_or_else(|| caller.clone());
+        self.refund_tokens(&refund_address, refundable_payments);
+
+        for deposit in deposits.iter() {
+            let mut event_payments = MultiValueEncoded::new();
+            event_payments.push(process_payment(&payments.get(deposit.payment_index)));
+
+            let operation_data = OperationData::new(
+                self.get_current_and_increment_tx_nonce(),
+                caller.clone(),
+                deposit.opt_transfer_data.clone(),
+            )
+            .with_refund_address(opt_refund_address.clone());
+            self.deposit_event(&deposit.to, &event_payments, operation_data);
+        }
     }
 
     fn match_fee_payment(
         &self,
-        total_tokens_for_fees: usize,
+        payments_for_fees: PaymentsVec<Self::Api>,
         fees_payment: &OptionalValue<EgldOrEsdtTokenPayment<Self::Api>>,
-        opt_transfer_data: &Option<TransferData<<Self as ContractBase>::Api>>,
+        gas: GasLimit,
+        destinations: ManagedVec<ManagedAddress>,
     ) {
         match fees_payment {
             OptionalValue::Some(fee) => {
-                let mut gas: GasLimit = 0;
-
-                if let Some(transfer_data) = opt_transfer_data {
-                    gas = transfer_data.gas_limit;
-                }
-
                 let fee_market_address = self.fee_market_address().get();
                 let caller = self.blockchain().get_caller();
 
                 self.tx()
                     .to(fee_market_address)
                     .typed(MvxFeeMarketProxy)
-                    .subtract_fee(caller, total_tokens_for_fees, OptionalValue::Some(gas))
+                    .subtract_fee(
+                        caller,
+                        destinations,
+                        payments_for_fees,
+                        OptionalValue::Some(gas),
+                    )
                     .payment(fee.clone())
                     .sync_call();
             }
@@ -116,15 +229,56 @@ pub trait DepositCommonModule:
         };
     }
 
-    fn check_and_extract_fee(&self, has_transfer_data: bool) -> ExtractedFeeResult<Self::Api> {
+    /// Once the fee market has any active fee, a fee is due unless all destinations are fee
+    /// exempt or all deposited tokens, the fee payment included, are fee exempt. SC calls without
+    /// tokens are charged unless their destination is exempt. The first payment is then the fee.
+    fn is_fee_required(
+        &self,
+        payments: &PaymentsVec<Self::Api>,
+        destinations: &ManagedVec<ManagedAddress>,
+    ) -> bool {
+        self.is_fee_required_by_fee_market(self.fee_market_address().get(), payments, destinations)
+    }
+
+    fn is_fee_required_by_fee_market(
+        &self,
+        fee_market_address: ManagedAddress,
+        payments: &PaymentsVec<Self::Api>,
+        destinations: &ManagedVec<ManagedAddress>,
+    ) -> bool {
+        if self
+            .external_active_fee_tokens(fee_market_address.clone())
+            .is_empty()
+        {
+            return false;
+        }
+
+        let exempt_destinations = self.external_fee_exempt_destinations(fee_market_address.clone());
+        if destinations
+            .iter()
+            .all(|destination| exempt_destinations.contains(&destination))
+        {
+            return false;
+        }
+        if payments.is_empty() {
+            return true;
+        }
+
+        let exempt_tokens = self.external_fee_exempt_tokens(fee_market_address);
+        !payments
+            .iter()
+            .all(|payment| exempt_tokens.contains(&payment.token_identifier))
+    }
+
+    fn check_and_extract_fee(
+        &self,
+        has_transfer_data: bool,
+        destinations: &ManagedVec<ManagedAddress>,
+    ) -> ExtractedFeeResult<Self::Api> {
         let payments = self.call_value().all_transfers().clone();
         require!(payments.len() <= MAX_TRANSFERS_PER_TX, TOO_MANY_TOKENS);
 
-        let fee_enabled = self
-            .external_fee_enabled(self.fee_market_address().get())
-            .get();
-
-        if fee_enabled {
+        if self.is_fee_required(&payments, destinations) {
             return self.pop_first_payment(payments);
         } else {
             if payments.is_empty() {
@@ -169,11 +323,10 @@ pub trait DepositCommonModule:
     }
 
     fn is_above_max_amount(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) -> bool {
-        self.esdt_safe_config()
-            .get()
-            .max_bridged_token_amounts
-            .iter()
-            .any(|m| m.token_id == *token_id && amount > &m.amount)
+        match self.max_bridged_token_amounts().get(token_id) {
+            Some(max_amount) => amount > &max_amount,
+            None => false,
+        }
     }
 
     fn require_below_max_amount(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
@@ -183,6 +336,12 @@ pub trait DepositCommonModule:
         );
     }
 
+    fn require_above_min_amount(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        if let Some(min_amount) = self.min_bridged_token_amounts().get(token_id) {
+            require!(amount >= &min_amount, DEPOSIT_UNDER_MIN_AMOUNT);
+        }
+    }
+
     #[inline]
     fn refund_tokens(
         &self,
@@ -235,6 +394,10 @@ pub trait DepositCommonModule:
     ) {
         self.multiversx_to_sovereign_esdt_info_mapper(id, nonce)
             .take();
+        self.registered_nft_nonces().swap_remove(&EsdtInfo {
+            token_identifier: id.clone(),
+            token_nonce: nonce,
+        });
     }
 
     #[inline]
@@ -265,44 +428,46 @@ pub trait DepositCommonModule:
     #[inline]
     fn require_token_not_on_blacklist(&self, token_id: &EgldOrEsdtTokenIdentifier<Self::Api>) {
         require!(
-            !self
-                .esdt_safe_config()
-                .get()
-                .token_blacklist
-                .contains(token_id),
+            !self.token_blacklist().contains(token_id),
             TOKEN_BLACKLISTED
         );
     }
 
     #[inline]
     fn is_token_whitelist_empty(&self) -> bool {
-        self.esdt_safe_config().get().token_whitelist.is_empty()
+        self.token_whitelist().is_empty()
     }
 
     #[inline]
     fn require_endpoint_not_banned(&self, function: &ManagedBuffer) {
         require!(
-            !self
-                .esdt_safe_config()
-                .get()
-                .banned_endpoints
-                .contains(function),
+            !self.banned_endpoints().contains(function),
             BANNED_ENDPOINT_NAME
         );
     }
 
+    /// An empty allowlist lets SC calls target any contract.
+    fn is_sc_call_target_allowed(&self, to: &ManagedAddress, function: &ManagedBuffer) -> bool {
+        self.allowed_targets().is_empty() || self.allowed_target_endpoints(to).contains(function)
+    }
+
+    #[inline]
+    fn require_sc_call_target_allowed(&self, to: &ManagedAddress, function: &ManagedBuffer) {
+        require!(
+            self.is_sc_call_target_allowed(to, function),
+            SC_CALL_TARGET_NOT_ALLOWED
+        );
+    }
+
     #[inline]
     fn is_token_whitelisted(&self, token_id: &EgldOrEsdtTokenIdentifier<Self::Api>) -> bool {
-        self.esdt_safe_config()
-            .get()
-            .token_whitelist
-            .contains(token_id)
+        self.token_whitelist().contains(token_id)
     }
 
     #[inline]
     fn require_gas_limit_under_limit(&self, gas_limit: GasLimit) {
         require!(
-            gas_limit <= self.esdt_safe_config().get().max_tx_gas_limit,
+            gas_limit <= self.max_tx_gas_limit().get(),
             GAS_LIMIT_TOO_HIGH
         );
     }
@@ -311,11 +476,7 @@ pub trait DepositCommonModule:
     fn require_caller_not_blacklisted(&self) {
         let caller = self.blockchain().get_caller();
         require!(
-            !self
-                .esdt_safe_config()
-                .get()
-                .address_blacklist
-                .contains(&caller),
+            !self.address_blacklist().contains(&caller),
             CALLER_IS_BLACKLISTED
         );
     }
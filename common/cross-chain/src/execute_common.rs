@@ -14,6 +14,19 @@ pub trait ExecuteCommonModule: crate::storage::CrossChainStorage {
         token_identifier == &esdt_safe_native_token_mapper.get()
     }
 
+    /// Returns the MultiversX identifier of a registered sovereign token, or the identifier itself otherwise.
+    fn resolve_mvx_token_id(
+        &self,
+        token_identifier: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> EgldOrEsdtTokenIdentifier<Self::Api> {
+        let mvx_token_mapper = self.sovereign_to_multiversx_token_id_mapper(token_identifier);
+        if mvx_token_mapper.is_empty() {
+            token_identifier.clone()
+        } else {
+            mvx_token_mapper.get()
+        }
+    }
+
     #[inline]
     fn format_error(
         &self,
@@ -1,7 +1,15 @@
 #![no_std]
 
-use error_messages::MAX_GAS_LIMIT_PER_TX_EXCEEDED;
-use structs::configs::EsdtSafeConfig;
+use error_messages::{
+    ADDRESS_ALREADY_BLACKLISTED, ADDRESS_NOT_BLACKLISTED, ALLOWED_TARGET_WITHOUT_ENDPOINTS,
+    ENDPOINT_ALREADY_BANNED, ENDPOINT_NOT_BANNED, MAX_BRIDGED_AMOUNT_NOT_SET,
+    MAX_GAS_LIMIT_PER_TX_EXCEEDED, MIN_BRIDGED_AMOUNT_ABOVE_MAX, MIN_BRIDGED_AMOUNT_NOT_SET,
+    TARGET_ENDPOINT_ALREADY_ALLOWED, TARGET_ENDPOINT_NOT_ALLOWED, TOKEN_ALREADY_BLACKLISTED,
+    TOKEN_ALREADY_WHITELISTED, TOKEN_NOT_BLACKLISTED, TOKEN_NOT_WHITELISTED,
+};
+use structs::configs::{
+    AllowedTarget, EsdtSafeConfig, EsdtSafeConfigPatch, MaxBridgedAmount, MinBridgedAmount,
+};
 multiversx_sc::imports!();
 
 pub mod deposit_common;
@@ -16,11 +24,229 @@ pub const MAX_GAS_PER_TRANSACTION: u64 = 500_000_000;
 #[multiversx_sc::module]
 pub trait LibCommon: crate::storage::CrossChainStorage {
     fn is_esdt_safe_config_valid(&self, config: &EsdtSafeConfig<Self::Api>) -> Option<&str> {
-        if config.max_tx_gas_limit <= MAX_GAS_PER_TRANSACTION {
-            None
-        } else {
-            Some(MAX_GAS_LIMIT_PER_TX_EXCEEDED)
+        if config.max_tx_gas_limit > MAX_GAS_PER_TRANSACTION {
+            return Some(MAX_GAS_LIMIT_PER_TX_EXCEEDED);
         }
+
+        for min_bridged_amount in config.min_bridged_token_amounts.iter() {
+            let is_above_max = config
+                .max_bridged_token_amounts
+                .iter()
+                .any(|max_bridged_amount| {
+                    max_bridged_amount.token_id == min_bridged_amount.token_id
+                        && min_bridged_amount.amount > max_bridged_amount.amount
+                });
+            if is_above_max {
+                return Some(MIN_BRIDGED_AMOUNT_ABOVE_MAX);
+            }
+        }
+
+        if config
+            .allowed_targets
+            .iter()
+            .any(|allowed_target| allowed_target.endpoints.is_empty())
+        {
+            return Some(ALLOWED_TARGET_WITHOUT_ENDPOINTS);
+        }
+
+        None
+    }
+
+    fn apply_esdt_safe_config_patch(&self, patch: EsdtSafeConfigPatch<Self::Api>) -> Option<&str> {
+        match patch {
+            EsdtSafeConfigPatch::AddTokenToWhitelist { token_id } => {
+                if !self.token_whitelist().insert(token_id) {
+                    return Some(TOKEN_ALREADY_WHITELISTED);
+                }
+            }
+            EsdtSafeConfigPatch::RemoveTokenFromWhitelist { token_id } => {
+                if !self.token_whitelist().swap_remove(&token_id) {
+                    return Some(TOKEN_NOT_WHITELISTED);
+                }
+            }
+            EsdtSafeConfigPatch::AddTokenToBlacklist { token_id } => {
+                if !self.token_blacklist().insert(token_id) {
+                    return Some(TOKEN_ALREADY_BLACKLISTED);
+                }
+            }
+            EsdtSafeConfigPatch::RemoveTokenFromBlacklist { token_id } => {
+                if !self.token_blacklist().swap_remove(&token_id) {
+                    return Some(TOKEN_NOT_BLACKLISTED);
+                }
+            }
+            EsdtSafeConfigPatch::AddBannedEndpoint { endpoint_name } => {
+                if !self.banned_endpoints().insert(endpoint_name) {
+                    return Some(ENDPOINT_ALREADY_BANNED);
+                }
+            }
+            EsdtSafeConfigPatch::RemoveBannedEndpoint { endpoint_name } => {
+                if !self.banned_endpoints().swap_remove(&endpoint_name) {
+                    return Some(ENDPOINT_NOT_BANNED);
+                }
+            }
+            EsdtSafeConfigPatch::AddBlacklistedAddress { address } => {
+                if !self.address_blacklist().insert(address) {
+                    return Some(ADDRESS_ALREADY_BLACKLISTED);
+                }
+            }
+            EsdtSafeConfigPatch::RemoveBlacklistedAddress { address } => {
+                if !self.address_blacklist().swap_remove(&address) {
+                    return Some(ADDRESS_NOT_BLACKLISTED);
+                }
+            }
+            EsdtSafeConfigPatch::SetMaxBridgedAmount { max_bridged_amount } => {
+                if let Some(min_amount) = self
+                    .min_bridged_token_amounts()
+                    .get(&max_bridged_amount.token_id)
+                {
+                    if min_amount > max_bridged_amount.amount {
+                        return Some(MIN_BRIDGED_AMOUNT_ABOVE_MAX);
+                    }
+                }
+
+                self.max_bridged_token_amounts()
+                    .insert(max_bridged_amount.token_id, max_bridged_amount.amount);
+            }
+            EsdtSafeConfigPatch::RemoveMaxBridgedAmount { token_id } => {
+                if self.max_bridged_token_amounts().remove(&token_id).is_none() {
+                    return Some(MAX_BRIDGED_AMOUNT_NOT_SET);
+                }
+            }
+            EsdtSafeConfigPatch::SetMinBridgedAmount { min_bridged_amount } => {
+                if let Some(max_amount) = self
+                    .max_bridged_token_amounts()
+                    .get(&min_bridged_amount.token_id)
+                {
+                    if min_bridged_amount.amount > max_amount {
+                        return Some(MIN_BRIDGED_AMOUNT_ABOVE_MAX);
+                    }
+                }
+
+                self.min_bridged_token_amounts()
+                    .insert(min_bridged_amount.token_id, min_bridged_amount.amount);
+            }
+            EsdtSafeConfigPatch::RemoveMinBridgedAmount { token_id } => {
+                if self.min_bridged_token_amounts().remove(&token_id).is_none() {
+                    return Some(MIN_BRIDGED_AMOUNT_NOT_SET);
+                }
+            }
+            EsdtSafeConfigPatch::AddAllowedTargetEndpoint {
+                address,
+                endpoint_name,
+            } => {
+                if !self
+                    .allowed_target_endpoints(&address)
+                    .insert(endpoint_name)
+                {
+                    return Some(TARGET_ENDPOINT_ALREADY_ALLOWED);
+                }
+                self.allowed_targets().insert(address);
+            }
+            EsdtSafeConfigPatch::RemoveAllowedTargetEndpoint {
+                address,
+                endpoint_name,
+            } => {
+                let mut endpoints = self.allowed_target_endpoints(&address);
+                if !endpoints.swap_remove(&endpoint_name) {
+                    return Some(TARGET_ENDPOINT_NOT_ALLOWED);
+                }
+                if endpoints.is_empty() {
+                    self.allowed_targets().swap_remove(&address);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn store_esdt_safe_config(&self, config: EsdtSafeConfig<Self::Api>) {
+        let mut token_whitelist = self.token_whitelist();
+        token_whitelist.clear();
+        token_whitelist.extend(config.token_whitelist.iter().map(|token| token.clone()));
+
+        let mut token_blacklist = self.token_blacklist();
+        token_blacklist.clear();
+        token_blacklist.extend(config.token_blacklist.iter().map(|token| token.clone()));
+
+        let mut banned_endpoints = self.banned_endpoints();
+        banned_endpoints.clear();
+        banned_endpoints.extend(config.banned_endpoints.iter().map(|name| name.clone()));
+
+        let mut address_blacklist = self.address_blacklist();
+        address_blacklist.clear();
+        address_blacklist.extend(
+            config
+                .address_blacklist
+                .iter()
+                .map(|address| address.clone()),
+        );
+
+        let mut max_bridged_token_amounts = self.max_bridged_token_amounts();
+        max_bridged_token_amounts.clear();
+        for max_bridged_amount in config.max_bridged_token_amounts.iter() {
+            max_bridged_token_amounts.insert(
+                max_bridged_amount.token_id.clone(),
+                max_bridged_amount.amount.clone(),
+            );
+        }
+
+        let mut min_bridged_token_amounts = self.min_bridged_token_amounts();
+        min_bridged_token_amounts.clear();
+        for min_bridged_amount in config.min_bridged_token_amounts.iter() {
+            min_bridged_token_amounts.insert(
+                min_bridged_amount.token_id.clone(),
+                min_bridged_amount.amount.clone(),
+            );
+        }
+
+        let mut allowed_targets = self.allowed_targets();
+        for address in allowed_targets.iter() {
+            self.allowed_target_endpoints(&address).clear();
+        }
+        allowed_targets.clear();
+        for allowed_target in config.allowed_targets.iter() {
+            allowed_targets.insert(allowed_target.address.clone());
+            self.allowed_target_endpoints(&allowed_target.address)
+                .extend(allowed_target.endpoints.iter().map(|name| name.clone()));
+        }
+
+        self.max_tx_gas_limit().set(config.max_tx_gas_limit);
+    }
+
+    #[view(getEsdtSafeConfig)]
+    fn get_esdt_safe_config(&self) -> EsdtSafeConfig<Self::Api> {
+        EsdtSafeConfig::new(
+            self.token_whitelist().iter().collect(),
+            self.token_blacklist().iter().collect(),
+            self.max_tx_gas_limit().get(),
+            self.banned_endpoints().iter().collect(),
+            self.address_blacklist().iter().collect(),
+            self.max_bridged_token_amounts()
+                .iter()
+                .map(|(token_id, amount)| MaxBridgedAmount { token_id, amount })
+                .collect(),
+            self.min_bridged_token_amounts()
+                .iter()
+                .map(|(token_id, amount)| MinBridgedAmount { token_id, amount })
+                .collect(),
+            self.allowed_targets()
+                .iter()
+                .map(|address| AllowedTarget {
+                    endpoints: self.allowed_target_endpoints(&address).iter().collect(),
+                    address,
+                })
+                .collect(),
+        )
+    }
+
+    /// Moves a config stored as a single `EsdtSafeConfig` value into the per-field mappers.
+    fn migrate_legacy_esdt_safe_config(&self) {
+        let legacy_config_mapper = self.legacy_esdt_safe_config();
+        if legacy_config_mapper.is_empty() {
+            return;
+        }
+
+        self.store_esdt_safe_config(legacy_config_mapper.take().into());
     }
 
     fn resolve_esdt_safe_config(
@@ -1,4 +1,9 @@
-use structs::{aliases::TxNonce, configs::EsdtSafeConfig, fee::FeeType, EsdtInfo};
+use structs::{
+    aliases::{GasLimit, TxNonce},
+    configs::LegacyEsdtSafeConfig,
+    fee::FeeType,
+    EsdtInfo, TokenRegistryEntry,
+};
 
 multiversx_sc::imports!();
 
@@ -10,8 +15,43 @@ pub trait CrossChainStorage {
     #[storage_mapper("sovTokenPrefix")]
     fn sov_token_prefix(&self) -> SingleValueMapper<ManagedBuffer<Self::Api>>;
 
+    /// Only read by the `upgrade()` migration, the config now lives in the mappers below.
     #[storage_mapper("crossChainConfig")]
-    fn esdt_safe_config(&self) -> SingleValueMapper<EsdtSafeConfig<Self::Api>>;
+    fn legacy_esdt_safe_config(&self) -> SingleValueMapper<LegacyEsdtSafeConfig<Self::Api>>;
+
+    #[storage_mapper("tokenWhitelist")]
+    fn token_whitelist(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
+
+    #[storage_mapper("tokenBlacklist")]
+    fn token_blacklist(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
+
+    #[storage_mapper("maxTxGasLimit")]
+    fn max_tx_gas_limit(&self) -> SingleValueMapper<GasLimit>;
+
+    #[storage_mapper("bannedEndpoints")]
+    fn banned_endpoints(&self) -> UnorderedSetMapper<ManagedBuffer<Self::Api>>;
+
+    #[storage_mapper("addressBlacklist")]
+    fn address_blacklist(&self) -> UnorderedSetMapper<ManagedAddress<Self::Api>>;
+
+    #[storage_mapper("maxBridgedTokenAmounts")]
+    fn max_bridged_token_amounts(
+        &self,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier<Self::Api>, BigUint<Self::Api>>;
+
+    #[storage_mapper("minBridgedTokenAmounts")]
+    fn min_bridged_token_amounts(
+        &self,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier<Self::Api>, BigUint<Self::Api>>;
+
+    #[storage_mapper("allowedTargets")]
+    fn allowed_targets(&self) -> UnorderedSetMapper<ManagedAddress<Self::Api>>;
+
+    #[storage_mapper("allowedTargetEndpoints")]
+    fn allowed_target_endpoints(
+        &self,
+        address: &ManagedAddress<Self::Api>,
+    ) -> UnorderedSetMapper<ManagedBuffer<Self::Api>>;
 
     #[storage_mapper("feeMarketAddress")]
     fn fee_market_address(&self) -> SingleValueMapper<ManagedAddress>;
@@ -46,6 +86,23 @@ pub trait CrossChainStorage {
         nonce: u64,
     ) -> SingleValueMapper<EsdtInfo<Self::Api>>;
 
+    #[storage_mapper("registeredTokenPairs")]
+    fn registered_token_pairs(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
+
+    #[storage_mapper("tokenRegistryEntry")]
+    fn token_registry_entry(
+        &self,
+        sov_token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> SingleValueMapper<TokenRegistryEntry<Self::Api>>;
+
+    #[storage_mapper("registeredNftNonces")]
+    fn registered_nft_nonces(&self) -> UnorderedSetMapper<EsdtInfo<Self::Api>>;
+
+    /// Set for good once the safe is migrated. Deposits and executions check it on top of the
+    /// pause status, since the pause module's `unpause` can not be restricted.
+    #[storage_mapper("safeMigrated")]
+    fn safe_migrated(&self) -> SingleValueMapper<bool>;
+
     #[view(getNativeToken)]
     #[storage_mapper("nativeToken")]
     fn native_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
@@ -53,11 +110,23 @@ pub trait CrossChainStorage {
     #[storage_mapper("isSovereignChain")]
     fn is_sovereign_chain(&self) -> SingleValueMapper<bool>;
 
-    #[storage_mapper_from_address("feeEnabledFlag")]
-    fn external_fee_enabled(
+    #[storage_mapper_from_address("activeFeeTokens")]
+    fn external_active_fee_tokens(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>, ManagedAddress>;
+
+    #[storage_mapper_from_address("feeExemptDestinations")]
+    fn external_fee_exempt_destinations(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> UnorderedSetMapper<ManagedAddress, ManagedAddress>;
+
+    #[storage_mapper_from_address("feeExemptTokens")]
+    fn external_fee_exempt_tokens(
         &self,
         sc_address: ManagedAddress,
-    ) -> SingleValueMapper<bool, ManagedAddress>;
+    ) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>, ManagedAddress>;
 
     #[storage_mapper_from_address("tokenFee")]
     fn external_token_fee(
@@ -2,9 +2,10 @@
 
 use structs::{
     aliases::{EventPaymentTuple, TxId},
-    configs::{EsdtSafeConfig, SovereignConfig},
+    configs::{EsdtSafeConfig, EsdtSafeConfigPatch, NftMetadata, SovereignConfig},
     fee::{AddressPercentagePair, FeeStruct},
     operation::OperationData,
+    MigrationStep,
 };
 
 multiversx_sc::imports!();
@@ -92,6 +93,13 @@ pub trait CustomEventsModule {
         op_nonce: TxId,
     );
 
+    #[event("patchEsdtSafeConfig")]
+    fn patch_esdt_safe_config_event(
+        &self,
+        #[indexed] patch: EsdtSafeConfigPatch<Self::Api>,
+        op_nonce: TxId,
+    );
+
     #[event("addUsersToFeeWhitelist")]
     fn add_users_to_fee_whitelist_event(
         &self,
@@ -106,6 +114,22 @@ pub trait CustomEventsModule {
         op_nonce: TxId,
     );
 
+    #[event("addFeeExemptions")]
+    fn add_fee_exemptions_event(
+        &self,
+        #[indexed] destinations: ManagedVec<ManagedAddress<Self::Api>>,
+        #[indexed] tokens: ManagedVec<EgldOrEsdtTokenIdentifier<Self::Api>>,
+        op_nonce: TxId,
+    );
+
+    #[event("removeFeeExemptions")]
+    fn remove_fee_exemptions_event(
+        &self,
+        #[indexed] destinations: ManagedVec<ManagedAddress<Self::Api>>,
+        #[indexed] tokens: ManagedVec<EgldOrEsdtTokenIdentifier<Self::Api>>,
+        op_nonce: TxId,
+    );
+
     #[event("registerToken")]
     fn register_token_event(
         &self,
@@ -117,6 +141,15 @@ pub trait CustomEventsModule {
         op_data: OperationData<Self::Api>,
     );
 
+    #[event("updateNftMetadata")]
+    fn update_nft_metadata_event(
+        &self,
+        #[indexed] token_id: EgldOrEsdtTokenIdentifier<Self::Api>,
+        #[indexed] token_nonce: u64,
+        #[indexed] metadata: NftMetadata<Self::Api>,
+        op_data: OperationData<Self::Api>,
+    );
+
     #[event("setTokenBurnMechanism")]
     fn set_token_burn_mechanism_event(
         &self,
@@ -130,4 +163,45 @@ pub trait CustomEventsModule {
         #[indexed] token_id: EgldOrEsdtTokenIdentifier<Self::Api>,
         op_nonce: TxId,
     );
+
+    #[event("migrationProgress")]
+    fn migration_progress_event(
+        &self,
+        #[indexed] step: MigrationStep,
+        #[indexed] counterpart: &ManagedAddress,
+        processed_entries: usize,
+    );
+
+    #[event("issuePoolFunded")]
+    fn issue_pool_funded_event(&self, #[indexed] funder: &ManagedAddress, amount: &BigUint);
+
+    #[event("feeExemptionsAdded")]
+    fn fee_exemptions_added_event(
+        &self,
+        #[indexed] destinations: &ManagedVec<ManagedAddress<Self::Api>>,
+        #[indexed] tokens: &ManagedVec<EgldOrEsdtTokenIdentifier<Self::Api>>,
+    );
+
+    #[event("feeExemptionsRemoved")]
+    fn fee_exemptions_removed_event(
+        &self,
+        #[indexed] destinations: &ManagedVec<ManagedAddress<Self::Api>>,
+        #[indexed] tokens: &ManagedVec<EgldOrEsdtTokenIdentifier<Self::Api>>,
+    );
+
+    #[event("feeCollected")]
+    fn fee_collected_event(
+        &self,
+        #[indexed] payer: &ManagedAddress,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        amount: &BigUint,
+    );
+
+    #[event("feesClaimed")]
+    fn fees_claimed_event(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        amount: &BigUint,
+    );
 }
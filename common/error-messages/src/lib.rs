@@ -140,3 +140,63 @@ pub const TOKEN_ALREADY_REGISTERED_WITH_BURN_MECHANISM: &str =
     "Token already registered in burn mechanism";
 pub const TOKEN_NOT_REGISTERED_WITH_BURN_MECHANISM: &str = "Token not registered in burn mechanism";
 pub const TOKEN_NOT_REGISTERED: &str = "Token not registered";
+pub const TOKEN_FROZEN: &str = "Token is frozen";
+pub const TOKEN_ALREADY_FROZEN: &str = "Token is already frozen";
+pub const TOKEN_NOT_FROZEN: &str = "Token is not frozen";
+pub const TOKEN_ALREADY_WHITELISTED: &str = "Token is already whitelisted";
+pub const TOKEN_NOT_WHITELISTED: &str = "Token is not whitelisted";
+pub const TOKEN_ALREADY_BLACKLISTED: &str = "Token is already blacklisted";
+pub const TOKEN_NOT_BLACKLISTED: &str = "Token is not blacklisted";
+pub const ENDPOINT_ALREADY_BANNED: &str = "Endpoint is already banned";
+pub const ENDPOINT_NOT_BANNED: &str = "Endpoint is not banned";
+pub const ADDRESS_ALREADY_BLACKLISTED: &str = "Address is already blacklisted";
+pub const ADDRESS_NOT_BLACKLISTED: &str = "Address is not blacklisted";
+pub const MAX_BRIDGED_AMOUNT_NOT_SET: &str = "No max bridged amount is set for this token";
+pub const INBOUND_TOKEN_BLACKLISTED: &str = "Token is blacklisted for inbound transfers";
+pub const RECEIVER_IS_BLACKLISTED: &str = "Receiver address is blacklisted";
+pub const EXECUTION_OVER_MAX_AMOUNT: &str = "Execution amount is over the max bridged amount";
+pub const SUCCESSOR_NOT_REGISTERED: &str = "The successor is not registered in the sovereign forge";
+pub const SAFE_ALREADY_MIGRATED: &str = "The safe was already migrated";
+pub const SAFE_NOT_MIGRATED: &str = "The safe was not migrated";
+pub const CALLER_NOT_SUCCESSOR: &str = "Caller is not the successor safe";
+pub const PREDECESSOR_ALREADY_SET: &str = "The predecessor safe was already set";
+pub const PREDECESSOR_NOT_SET: &str = "There is no predecessor safe set";
+pub const CALLER_NOT_PREDECESSOR: &str = "Caller is not the predecessor safe";
+pub const INVALID_REFUND_ADDRESS: &str = "Invalid refund address";
+pub const MIN_BRIDGED_AMOUNT_ABOVE_MAX: &str = "Min bridged amount is above the max bridged amount";
+pub const MIN_BRIDGED_AMOUNT_NOT_SET: &str = "No min bridged amount is set for this token";
+pub const DEPOSIT_UNDER_MIN_AMOUNT: &str = "Deposit amount is under the min bridged amount";
+pub const DEPOSIT_ENTRIES_PAYMENTS_MISMATCH: &str =
+    "The number of deposit entries does not match the number of payments";
+pub const INVALID_PAYMENT_INDEX: &str = "Invalid payment index";
+pub const INVALID_DESTINATION_CHAIN: &str = "The destination chain is the current chain";
+pub const DESTINATION_ESDT_SAFE_NOT_FOUND: &str =
+    "There is no ESDT safe deployed for the destination chain";
+pub const ALLOWED_TARGET_WITHOUT_ENDPOINTS: &str = "Allowed target has no endpoints";
+pub const TARGET_ENDPOINT_ALREADY_ALLOWED: &str = "Endpoint is already allowed for this target";
+pub const TARGET_ENDPOINT_NOT_ALLOWED: &str = "Endpoint is not allowed for this target";
+pub const SC_CALL_TARGET_NOT_ALLOWED: &str = "SC call target is not in the allowlist";
+pub const NFT_NONCE_NOT_REGISTERED: &str = "There is no MultiversX nonce registered for this NFT";
+pub const UPDATE_NFT_METADATA_FAILED: &str = "Failed to update NFT metadata:";
+pub const NFT_NOT_DYNAMIC: &str = "Only dynamic NFTs can have their metadata synchronised";
+pub const ISSUE_POOL_FUNDING_AMOUNT_ZERO: &str =
+    "The issue pool funding amount must be greater than zero";
+pub const INVALID_ISSUE_COST: &str = "The issue cost must be greater than zero";
+pub const INVALID_FEE_BASIS_POINTS: &str = "Fee basis points cannot exceed 10000";
+pub const MIN_FEE_ABOVE_MAX_FEE: &str = "The minimum fee cannot be above the maximum fee";
+pub const BASE_TOKEN_FEE_NOT_SET: &str = "There is no fee set for the base token";
+pub const INVALID_FEE_CONVERSION_RATE: &str = "The fee conversion rate must be greater than zero";
+pub const FEE_PAYMENT_TOKEN_ALREADY_USED: &str = "The token is already accepted as a fee payment";
+pub const FEE_PAYMENT_TOKEN_NOT_SET: &str =
+    "The token is not accepted as a fee payment for this base token";
+pub const INVALID_FEE_TIERS: &str =
+    "Fee tiers must start from a zero volume and have strictly increasing volumes";
+pub const NO_FEES_TO_CLAIM: &str = "There are no fees to claim";
+pub const FEE_SPLIT_NOT_SET: &str = "There is no fee split set";
+pub const DISTRIBUTION_INTERVAL_NOT_ELAPSED: &str =
+    "The distribution interval has not elapsed since the last distribution";
+pub const SUCCESSOR_IN_OTHER_SHARD: &str = "The successor safe must be in the same shard";
+pub const DESTINATION_FEE_NOT_COVERED: &str =
+    "The routed payments cannot cover the fee of the destination safe";
+pub const PERCENTAGE_FEE_TOKEN_NOT_PRICED: &str =
+    "Percentage fees can only be charged on the fee token and its accepted payment tokens";
@@ -1,4 +1,8 @@
-use structs::{aliases::GasLimit, fee::FinalPayment};
+use error_messages::{DISTRIBUTION_INTERVAL_NOT_ELAPSED, FEE_SPLIT_NOT_SET, NO_FEES_TO_CLAIM};
+use structs::{
+    aliases::{GasLimit, PaymentsVec},
+    fee::{FeeQuote, FeeType, FinalPayment},
+};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -15,30 +19,55 @@ pub trait FeeCommonEndpointsModule:
     fn subtract_fee(
         &self,
         original_caller: ManagedAddress,
-        total_transfers: usize,
+        destinations: ManagedVec<ManagedAddress>,
+        transfers: PaymentsVec<Self::Api>,
         opt_gas_limit: OptionalValue<GasLimit>,
     ) -> FinalPayment<Self::Api> {
         self.require_caller_esdt_safe();
 
-        let caller = self.blockchain().get_caller();
-        let payment = self.call_value().single_esdt().clone();
+        let payment = self.call_value().egld_or_single_esdt();
+        let chargeable_transfers = self.get_chargeable_transfers(&transfers);
 
-        if !self.is_fee_enabled() || self.users_whitelist().contains(&original_caller) {
-            self.tx().to(&caller).payment(payment.clone()).transfer();
+        if !self.is_fee_enabled()
+            || self.is_exempt_from_fee(
+                &original_caller,
+                &destinations,
+                &payment.token_identifier,
+                &chargeable_transfers,
+            )
+        {
+            self.tx()
+                .to(&original_caller)
+                .payment(payment.clone())
+                .transfer();
 
             return FinalPayment {
-                fee: EsdtTokenPayment::new(payment.token_identifier.clone(), 0, BigUint::zero()),
+                fee: EgldOrEsdtTokenPayment::new(
+                    payment.token_identifier.clone(),
+                    0,
+                    BigUint::zero(),
+                ),
                 remaining_tokens: payment,
             };
         }
 
-        let final_payment = self.subtract_fee_by_type(payment, total_transfers, opt_gas_limit);
+        let is_tiered_fee = self.is_tiered_fee(&payment.token_identifier);
+        let final_payment = self.subtract_fee_by_type(
+            &original_caller,
+            payment,
+            &chargeable_transfers,
+            opt_gas_limit,
+        );
+        if is_tiered_fee {
+            self.record_user_volume(&original_caller, chargeable_transfers.len() as u64);
+        }
 
         self.tokens_for_fees()
             .insert(final_payment.fee.token_identifier.clone());
 
         self.accumulated_fees(&final_payment.fee.token_identifier)
             .update(|amt| *amt += &final_payment.fee.amount);
+        self.record_fee_collection(&original_caller, &final_payment.fee);
 
         if final_payment.remaining_tokens.amount > 0 {
             self.tx()
@@ -49,4 +78,120 @@ pub trait FeeCommonEndpointsModule:
 
         final_payment
     }
+
+    /// Claims the allocated fees of the caller for `token_ids`, or for every token when none is given.
+    #[endpoint(claimFees)]
+    fn claim_fees(&self, token_ids: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
+        let recipient = self.blockchain().get_caller();
+        let mut claimable_fees = self.claimable_fees(&recipient);
+
+        let tokens_to_claim: ManagedVec<EgldOrEsdtTokenIdentifier> = if token_ids.is_empty() {
+            claimable_fees.keys().collect()
+        } else {
+            token_ids.to_vec()
+        };
+
+        let mut has_claimed = false;
+        for token_id in tokens_to_claim.iter() {
+            let Some(amount) = claimable_fees.remove(&token_id) else {
+                continue;
+            };
+            if amount == 0u32 {
+                continue;
+            }
+
+            self.send_fee_payment(&recipient, &token_id, amount.clone());
+            self.fees_claimed_event(&recipient, &token_id, &amount);
+            has_claimed = true;
+        }
+
+        require!(has_claimed, NO_FEES_TO_CLAIM);
+    }
+
+    #[endpoint(triggerDistribution)]
+    fn trigger_distribution(&self) {
+        let fee_split_mapper = self.fee_split();
+        require!(!fee_split_mapper.is_empty(), FEE_SPLIT_NOT_SET);
+
+        let current_block = self.blockchain().get_block_nonce();
+        require!(
+            current_block
+                >= self.last_distribution_block().get() + self.distribution_interval().get(),
+            DISTRIBUTION_INTERVAL_NOT_ELAPSED
+        );
+
+        self.distribute_fees_and_reset(&fee_split_mapper.get());
+        self.last_distribution_block().set(current_block);
+    }
+
+    /// Returns the fee `subtractFee` would charge in `fee_token`. `opt_caller` is needed for
+    /// whitelisted callers and tiered fees. Exempt destinations are not taken into account.
+    #[view(getFeeQuote)]
+    fn get_fee_quote(
+        &self,
+        fee_token: EgldOrEsdtTokenIdentifier,
+        transfers: PaymentsVec<Self::Api>,
+        gas_limit: GasLimit,
+        opt_caller: OptionalValue<ManagedAddress>,
+    ) -> FeeQuote<Self::Api> {
+        let (base_token, _) = self.resolve_fee_base_token(&fee_token);
+        let accepted_tokens = self.get_accepted_fee_tokens(&base_token);
+        let caller = opt_caller
+            .into_option()
+            .unwrap_or_else(ManagedAddress::zero);
+        let chargeable_transfers = self.get_chargeable_transfers(&transfers);
+
+        let is_fee_charged = self.is_fee_enabled()
+            && !self.is_exempt_from_fee(
+                &caller,
+                &ManagedVec::new(),
+                &fee_token,
+                &chargeable_transfers,
+            );
+        if !is_fee_charged {
+            return FeeQuote {
+                fee: EgldOrEsdtTokenPayment::new(fee_token, 0, BigUint::zero()),
+                accepted_tokens,
+            };
+        }
+
+        let (fee_token, fee_amount) = self.calculate_fee_by_type(
+            &caller,
+            &fee_token,
+            &chargeable_transfers,
+            OptionalValue::Some(gas_limit),
+        );
+
+        FeeQuote {
+            fee: EgldOrEsdtTokenPayment::new(fee_token, 0, fee_amount),
+            accepted_tokens,
+        }
+    }
+
+    /// Number of transfers the user paid a tiered fee for, decayed over the volume window.
+    #[view(getUserVolume)]
+    fn get_user_volume(&self, user: ManagedAddress) -> u64 {
+        self.get_current_user_volume(&user)
+    }
+
+    /// Returns `None` when the fee paid with `token_id` is not tiered.
+    #[view(getUserFeeTier)]
+    fn get_user_fee_tier(
+        &self,
+        user: ManagedAddress,
+        token_id: EgldOrEsdtTokenIdentifier,
+    ) -> Option<usize> {
+        let (base_token, _) = self.resolve_fee_base_token(&token_id);
+        let fee_mapper = self.token_fee(&base_token);
+        if fee_mapper.is_empty() {
+            return None;
+        }
+
+        match fee_mapper.get() {
+            FeeType::Tiered { tiers, .. } => {
+                Some(self.get_fee_tier_index(&tiers, self.get_current_user_volume(&user)))
+            }
+            _ => None,
+        }
+    }
 }
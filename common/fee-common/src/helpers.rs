@@ -1,16 +1,24 @@
 use error_messages::{
-    INVALID_FEE, INVALID_FEE_TYPE, INVALID_PERCENTAGE_SUM, INVALID_TOKEN_ID,
-    INVALID_TOKEN_PROVIDED_FOR_FEE, PAYMENT_DOES_NOT_COVER_FEE, TOKEN_NOT_ACCEPTED_AS_FEE,
+    BASE_TOKEN_FEE_NOT_SET, FEE_PAYMENT_TOKEN_ALREADY_USED, FEE_PAYMENT_TOKEN_NOT_SET, INVALID_FEE,
+    INVALID_FEE_BASIS_POINTS, INVALID_FEE_CONVERSION_RATE, INVALID_FEE_TIERS, INVALID_FEE_TYPE,
+    INVALID_PERCENTAGE_SUM, INVALID_TOKEN_ID, INVALID_TOKEN_PROVIDED_FOR_FEE,
+    MIN_FEE_ABOVE_MAX_FEE, PAYMENT_DOES_NOT_COVER_FEE, PERCENTAGE_FEE_TOKEN_NOT_PRICED,
+    TOKEN_NOT_ACCEPTED_AS_FEE,
 };
 use structs::{
-    aliases::GasLimit,
-    fee::{AddressPercentagePair, FeeStruct, FeeType, FinalPayment, SubtractPaymentArguments},
+    aliases::{GasLimit, PaymentsVec},
+    fee::{
+        AddressPercentagePair, FeeStruct, FeeTier, FeeType, FinalPayment, SubtractPaymentArguments,
+        VolumeWindow,
+    },
 };
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 pub const TOTAL_PERCENTAGE: usize = 10_000;
+pub const FEE_RATE_PRECISION: u64 = 1_000_000_000_000_000_000;
+pub const VOLUME_WINDOW_SECONDS: u64 = 30 * 24 * 60 * 60;
 
 #[multiversx_sc::module]
 pub trait FeeCommonHelpersModule:
@@ -41,6 +49,7 @@ pub trait FeeCommonHelpersModule:
 
                 if amount_to_send > 0 {
                     remaining_fees -= &amount_to_send;
+                    self.record_fee_distribution(&pair.address, &token_id, &amount_to_send);
                     self.send_fee_payment(&pair.address, &token_id, amount_to_send);
                 }
             }
@@ -49,17 +58,223 @@ pub trait FeeCommonHelpersModule:
         }
     }
 
+    /// Records each recipient's share instead of sending it, to be pulled later with `claimFees`.
+    fn allocate_token_fees(&self, pairs: &ManagedVec<Self::Api, AddressPercentagePair<Self::Api>>) {
+        let percentage_total = BigUint::from(TOTAL_PERCENTAGE);
+
+        for token_id in self.tokens_for_fees().iter() {
+            let accumulated_fees = self.accumulated_fees(&token_id).get();
+            if accumulated_fees == 0u32 {
+                continue;
+            }
+
+            let mut remaining_fees = accumulated_fees.clone();
+
+            for pair in pairs {
+                let amount_to_allocate = self.calculate_fee_amount(
+                    &accumulated_fees,
+                    pair.percentage,
+                    &percentage_total,
+                );
+
+                if amount_to_allocate > 0 {
+                    remaining_fees -= &amount_to_allocate;
+                    self.record_fee_distribution(&pair.address, &token_id, &amount_to_allocate);
+                    let mut claimable_fees = self.claimable_fees(&pair.address);
+                    let claimable_amount = claimable_fees.get(&token_id).unwrap_or_default();
+                    claimable_fees.insert(token_id.clone(), claimable_amount + amount_to_allocate);
+                }
+            }
+
+            self.accumulated_fees(&token_id).set(&remaining_fees);
+        }
+    }
+
+    fn allocate_fees_and_reset(
+        &self,
+        pairs: &ManagedVec<Self::Api, AddressPercentagePair<Self::Api>>,
+    ) {
+        self.allocate_token_fees(pairs);
+        self.clear_distributed_fee_tokens();
+    }
+
+    fn record_fee_collection(&self, payer: &ManagedAddress, fee: &EgldOrEsdtTokenPayment) {
+        let epoch = self.blockchain().get_block_epoch();
+        self.total_fees_collected(&fee.token_identifier)
+            .update(|total| *total += &fee.amount);
+        self.epoch_fees_collected(epoch, &fee.token_identifier)
+            .update(|total| *total += &fee.amount);
+
+        self.fee_collected_event(payer, &fee.token_identifier, &fee.amount);
+    }
+
+    fn record_fee_distribution(
+        &self,
+        recipient: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        let epoch = self.blockchain().get_block_epoch();
+        self.total_fees_distributed(token_id)
+            .update(|total| *total += amount);
+        self.epoch_fees_distributed(epoch, token_id)
+            .update(|total| *total += amount);
+        self.recipient_fees_distributed(recipient, token_id)
+            .update(|total| *total += amount);
+    }
+
     fn distribute_fees_and_reset(
         &self,
         pairs: &ManagedVec<Self::Api, AddressPercentagePair<Self::Api>>,
     ) {
         self.distribute_token_fees(pairs);
-        self.tokens_for_fees().clear();
+        self.clear_distributed_fee_tokens();
+    }
+
+    /// Tokens with a rounding remainder stay tracked so it is carried over to the next distribution.
+    fn clear_distributed_fee_tokens(&self) {
+        let mut tokens_for_fees = self.tokens_for_fees();
+        let distributed_tokens: ManagedVec<EgldOrEsdtTokenIdentifier> = tokens_for_fees
+            .iter()
+            .filter(|token_id| self.accumulated_fees(token_id).get() == 0u32)
+            .collect();
+
+        for token_id in distributed_tokens.iter() {
+            tokens_for_fees.swap_remove(&token_id);
+        }
+    }
+
+    fn set_fee_split_in_storage(
+        &self,
+        pairs: &ManagedVec<Self::Api, AddressPercentagePair<Self::Api>>,
+        distribution_interval: u64,
+    ) -> Option<ManagedBuffer> {
+        if let Some(err_msg) = self.validate_percentage_sum(pairs) {
+            return Some(err_msg);
+        }
+
+        self.fee_split().set(pairs);
+        self.distribution_interval().set(distribution_interval);
+        self.last_distribution_block()
+            .set(self.blockchain().get_block_nonce());
+
+        None
+    }
+
+    fn add_fee_exemptions_to_storage(
+        &self,
+        destinations: &ManagedVec<ManagedAddress>,
+        tokens: &ManagedVec<EgldOrEsdtTokenIdentifier>,
+    ) {
+        for destination in destinations.iter() {
+            self.fee_exempt_destinations().insert(destination.clone());
+        }
+        for token_id in tokens.iter() {
+            self.fee_exempt_tokens().insert(token_id.clone());
+        }
+
+        self.fee_exemptions_added_event(destinations, tokens);
+    }
+
+    /// Destinations and tokens that are not exempt are ignored, so both fee markets behave the
+    /// same whether the removal comes from the owner or from a signed operation.
+    fn remove_fee_exemptions_from_storage(
+        &self,
+        destinations: &ManagedVec<ManagedAddress>,
+        tokens: &ManagedVec<EgldOrEsdtTokenIdentifier>,
+    ) {
+        for destination in destinations.iter() {
+            self.fee_exempt_destinations().swap_remove(&destination);
+        }
+        for token_id in tokens.iter() {
+            self.fee_exempt_tokens().swap_remove(&token_id);
+        }
+
+        self.fee_exemptions_removed_event(destinations, tokens);
     }
 
     fn remove_fee_from_storage(&self, token_id: &EgldOrEsdtTokenIdentifier) {
         self.token_fee(token_id).clear();
-        self.fee_enabled().set(false);
+        self.active_fee_tokens().swap_remove(token_id);
+
+        let mut payment_token_rates = self.fee_payment_token_rates(token_id);
+        for payment_token in payment_token_rates.keys() {
+            self.fee_payment_token_base(&payment_token).clear();
+        }
+        payment_token_rates.clear();
+    }
+
+    fn set_fee_payment_token_in_storage(
+        &self,
+        base_token: &EgldOrEsdtTokenIdentifier,
+        payment_token: &EgldOrEsdtTokenIdentifier,
+        rate: &BigUint,
+    ) -> Option<&str> {
+        if !self.is_valid_token_id(payment_token) {
+            return Some(INVALID_TOKEN_ID);
+        }
+        if self.token_fee(base_token).is_empty() {
+            return Some(BASE_TOKEN_FEE_NOT_SET);
+        }
+        if rate == &0u64 {
+            return Some(INVALID_FEE_CONVERSION_RATE);
+        }
+
+        let payment_token_base_mapper = self.fee_payment_token_base(payment_token);
+        let is_payment_token_of_other_base =
+            !payment_token_base_mapper.is_empty() && &payment_token_base_mapper.get() != base_token;
+        if is_payment_token_of_other_base || !self.token_fee(payment_token).is_empty() {
+            return Some(FEE_PAYMENT_TOKEN_ALREADY_USED);
+        }
+
+        self.fee_payment_token_rates(base_token)
+            .insert(payment_token.clone(), rate.clone());
+        payment_token_base_mapper.set(base_token);
+
+        None
+    }
+
+    fn remove_fee_payment_token_from_storage(
+        &self,
+        base_token: &EgldOrEsdtTokenIdentifier,
+        payment_token: &EgldOrEsdtTokenIdentifier,
+    ) -> Option<&str> {
+        if self
+            .fee_payment_token_rates(base_token)
+            .remove(payment_token)
+            .is_none()
+        {
+            return Some(FEE_PAYMENT_TOKEN_NOT_SET);
+        }
+
+        self.fee_payment_token_base(payment_token).clear();
+
+        None
+    }
+
+    /// Fees paid in an accepted payment token are computed in its base token and then converted.
+    fn resolve_fee_base_token(
+        &self,
+        payment_token: &EgldOrEsdtTokenIdentifier,
+    ) -> (EgldOrEsdtTokenIdentifier, Option<BigUint>) {
+        let base_token_mapper = self.fee_payment_token_base(payment_token);
+        if base_token_mapper.is_empty() {
+            return (payment_token.clone(), None);
+        }
+
+        let base_token = base_token_mapper.get();
+        let rate = self
+            .fee_payment_token_rates(&base_token)
+            .get(payment_token)
+            .unwrap_or_default();
+
+        (base_token, Some(rate))
+    }
+
+    fn convert_fee_amount(&self, fee_amount: BigUint, rate: &BigUint) -> BigUint {
+        let precision = BigUint::from(FEE_RATE_PRECISION);
+
+        (fee_amount * rate + &precision - 1u64) / precision
     }
 
     fn parse_pairs(
@@ -93,12 +308,12 @@ pub trait FeeCommonHelpersModule:
     fn send_fee_payment(
         &self,
         address: &ManagedAddress,
-        token_id: &EsdtTokenIdentifier,
+        token_id: &EgldOrEsdtTokenIdentifier,
         amount: BigUint,
     ) {
         self.tx()
             .to(address)
-            .payment(EsdtTokenPayment::new(token_id.clone(), 0, amount))
+            .payment(EgldOrEsdtTokenPayment::new(token_id.clone(), 0, amount))
             .transfer();
     }
 
@@ -117,33 +332,273 @@ pub trait FeeCommonHelpersModule:
 
     fn subtract_fee_by_type(
         &self,
-        payment: EsdtTokenPayment,
-        total_transfers: usize,
+        original_caller: &ManagedAddress,
+        payment: EgldOrEsdtTokenPayment,
+        transfers: &PaymentsVec<Self::Api>,
         opt_gas_limit: OptionalValue<GasLimit>,
     ) -> FinalPayment<Self::Api> {
-        let fee_type = self
-            .token_fee(&EgldOrEsdtTokenIdentifier::esdt(
-                payment.token_identifier.clone(),
-            ))
-            .get();
-        match fee_type {
+        let (fee_token, total_fee) = self.calculate_fee_by_type(
+            original_caller,
+            &payment.token_identifier,
+            transfers,
+            opt_gas_limit,
+        );
+
+        self.subtract_fee_same_token(SubtractPaymentArguments {
+            fee_token,
+            total_fee,
+            payment,
+        })
+    }
+
+    fn calculate_fee_by_type(
+        &self,
+        original_caller: &ManagedAddress,
+        payment_token: &EgldOrEsdtTokenIdentifier,
+        transfers: &PaymentsVec<Self::Api>,
+        opt_gas_limit: OptionalValue<GasLimit>,
+    ) -> (EgldOrEsdtTokenIdentifier, BigUint) {
+        let (base_token, opt_rate) = self.resolve_fee_base_token(payment_token);
+        let fee_type = self.token_fee(&base_token).get();
+        let (fee_token, total_fee) = match fee_type {
             FeeType::None => sc_panic!(TOKEN_NOT_ACCEPTED_AS_FEE),
             FeeType::Fixed {
                 token,
                 per_transfer,
                 per_gas,
             } => {
-                let args = SubtractPaymentArguments {
-                    fee_token: token,
-                    per_transfer,
-                    per_gas,
-                    payment,
-                    total_transfers,
-                    opt_gas_limit,
+                let fixed_fee =
+                    self.calculate_fixed_fee(&per_transfer, &per_gas, transfers, &opt_gas_limit);
+                (token, fixed_fee)
+            }
+            FeeType::Percentage {
+                token,
+                basis_points,
+                min,
+                max,
+            } => {
+                let percentage_fee =
+                    self.calculate_percentage_fee(&token, basis_points, &min, &max, transfers);
+                (token, percentage_fee)
+            }
+            FeeType::FixedPlusPercentage {
+                token,
+                per_transfer,
+                per_gas,
+                basis_points,
+                min,
+                max,
+            } => {
+                let fixed_fee =
+                    self.calculate_fixed_fee(&per_transfer, &per_gas, transfers, &opt_gas_limit);
+                let percentage_fee =
+                    self.calculate_percentage_fee(&token, basis_points, &min, &max, transfers);
+                (token, fixed_fee + percentage_fee)
+            }
+            FeeType::Tiered { token, tiers } => {
+                let volume = self.get_current_user_volume(original_caller);
+                let tier = tiers.get(self.get_fee_tier_index(&tiers, volume));
+                let tiered_fee = self.calculate_fixed_fee(
+                    &tier.per_transfer,
+                    &tier.per_gas,
+                    transfers,
+                    &opt_gas_limit,
+                );
+                (token, tiered_fee)
+            }
+        };
+
+        match opt_rate {
+            Some(rate) => (
+                payment_token.clone(),
+                self.convert_fee_amount(total_fee, &rate),
+            ),
+            None => (fee_token, total_fee),
+        }
+    }
+
+    /// Whitelisted callers, deposits whose destinations are all exempt and deposits made only of
+    /// exempt tokens are not charged. The fee token counts as deposited, so the token set is the
+    /// one the ESDT safe checks before splitting off the fee.
+    fn is_exempt_from_fee(
+        &self,
+        original_caller: &ManagedAddress,
+        destinations: &ManagedVec<ManagedAddress>,
+        fee_token: &EgldOrEsdtTokenIdentifier,
+        chargeable_transfers: &PaymentsVec<Self::Api>,
+    ) -> bool {
+        if self.users_whitelist().contains(original_caller) {
+            return true;
+        }
+
+        let exempt_destinations = self.fee_exempt_destinations();
+        if !destinations.is_empty()
+            && destinations
+                .iter()
+                .all(|destination| exempt_destinations.contains(&destination))
+        {
+            return true;
+        }
+
+        chargeable_transfers.is_empty() && self.fee_exempt_tokens().contains(fee_token)
+    }
+
+    fn get_chargeable_transfers(
+        &self,
+        transfers: &PaymentsVec<Self::Api>,
+    ) -> PaymentsVec<Self::Api> {
+        let exempt_tokens = self.fee_exempt_tokens();
+        let mut chargeable_transfers = PaymentsVec::new();
+        for transfer in transfers.iter() {
+            if !exempt_tokens.contains(&transfer.token_identifier) {
+                chargeable_transfers.push(transfer.clone());
+            }
+        }
+
+        chargeable_transfers
+    }
+
+    fn get_accepted_fee_tokens(
+        &self,
+        base_token: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedVec<EgldOrEsdtTokenIdentifier> {
+        let mut accepted_tokens = ManagedVec::new();
+        if self.token_fee(base_token).is_empty() {
+            return accepted_tokens;
+        }
+
+        accepted_tokens.push(base_token.clone());
+        for payment_token in self.fee_payment_token_rates(base_token).keys() {
+            accepted_tokens.push(payment_token);
+        }
+
+        accepted_tokens
+    }
+
+    fn calculate_fixed_fee(
+        &self,
+        per_transfer: &BigUint,
+        per_gas: &BigUint,
+        transfers: &PaymentsVec<Self::Api>,
+        opt_gas_limit: &OptionalValue<GasLimit>,
+    ) -> BigUint {
+        let mut fixed_fee = per_transfer * transfers.len() as u32;
+        if let OptionalValue::Some(gas_limit) = opt_gas_limit {
+            fixed_fee += per_gas * *gas_limit;
+        }
+
+        fixed_fee
+    }
+
+    /// Transfers in an accepted fee payment token are valued in the fee token through its
+    /// conversion rate. Transfers in any other token can't be valued and are rejected.
+    fn calculate_percentage_fee(
+        &self,
+        fee_token: &EgldOrEsdtTokenIdentifier,
+        basis_points: usize,
+        min: &BigUint,
+        max: &BigUint,
+        transfers: &PaymentsVec<Self::Api>,
+    ) -> BigUint {
+        let percentage_total = BigUint::from(TOTAL_PERCENTAGE);
+        let payment_token_rates = self.fee_payment_token_rates(fee_token);
+        let mut percentage_fee = BigUint::zero();
+
+        for transfer in transfers.iter() {
+            let transfer_value = if &transfer.token_identifier == fee_token {
+                transfer.amount.clone()
+            } else {
+                let Some(rate) = payment_token_rates.get(&transfer.token_identifier) else {
+                    sc_panic!(PERCENTAGE_FEE_TOKEN_NOT_PRICED);
                 };
-                self.subtract_fee_same_token(args)
+                &transfer.amount * FEE_RATE_PRECISION / rate
+            };
+            let transfer_fee =
+                self.calculate_fee_amount(&transfer_value, basis_points, &percentage_total);
+
+            percentage_fee += if &transfer_fee < min {
+                min.clone()
+            } else if &transfer_fee > max {
+                max.clone()
+            } else {
+                transfer_fee
+            };
+        }
+
+        percentage_fee
+    }
+
+    /// Volume is the number of transfers paid for, not their amount, since amounts of different
+    /// tokens can not be added up. It decays linearly over `VOLUME_WINDOW_SECONDS` since the last
+    /// recorded deposit, so old transfers fade out instead of the whole window resetting at once.
+    fn get_current_user_volume(&self, user: &ManagedAddress) -> u64 {
+        let user_volume_mapper = self.user_volume(user);
+        if user_volume_mapper.is_empty() {
+            return 0;
+        }
+
+        self.get_decayed_volume(&user_volume_mapper.get())
+    }
+
+    fn record_user_volume(&self, user: &ManagedAddress, transfers_count: u64) {
+        let current_volume = self.get_current_user_volume(user);
+
+        self.user_volume(user).set(VolumeWindow {
+            last_update: self
+                .blockchain()
+                .get_block_timestamp_seconds()
+                .as_u64_seconds(),
+            volume: current_volume + transfers_count,
+        });
+    }
+
+    fn get_decayed_volume(&self, volume_window: &VolumeWindow) -> u64 {
+        let current_timestamp = self
+            .blockchain()
+            .get_block_timestamp_seconds()
+            .as_u64_seconds();
+        let elapsed = current_timestamp.saturating_sub(volume_window.last_update);
+        if elapsed >= VOLUME_WINDOW_SECONDS {
+            return 0;
+        }
+
+        let remaining = (VOLUME_WINDOW_SECONDS - elapsed) as u128;
+        (volume_window.volume as u128 * remaining / VOLUME_WINDOW_SECONDS as u128) as u64
+    }
+
+    fn is_tiered_fee(&self, payment_token: &EgldOrEsdtTokenIdentifier) -> bool {
+        let (base_token, _) = self.resolve_fee_base_token(payment_token);
+        let fee_mapper = self.token_fee(&base_token);
+
+        !fee_mapper.is_empty() && matches!(fee_mapper.get(), FeeType::Tiered { .. })
+    }
+
+    fn get_fee_tier_index(&self, tiers: &ManagedVec<FeeTier<Self::Api>>, volume: u64) -> usize {
+        let mut tier_index = 0;
+        for (index, tier) in tiers.iter().enumerate() {
+            if tier.min_volume > volume {
+                break;
+            }
+            tier_index = index;
+        }
+
+        tier_index
+    }
+
+    fn are_fee_tiers_valid(&self, tiers: &ManagedVec<FeeTier<Self::Api>>) -> bool {
+        if tiers.is_empty() || tiers.get(0).min_volume != 0 {
+            return false;
+        }
+
+        let mut previous_min_volume = 0;
+        for tier in tiers.iter().skip(1) {
+            if tier.min_volume <= previous_min_volume {
+                return false;
             }
+            previous_min_volume = tier.min_volume;
         }
+
+        true
     }
 
     fn subtract_fee_same_token(
@@ -155,18 +610,14 @@ pub trait FeeCommonHelpersModule:
             INVALID_TOKEN_PROVIDED_FOR_FEE
         );
 
-        let mut total_fee = args.per_transfer * args.total_transfers as u32;
-        if let OptionalValue::Some(gas_limit) = args.opt_gas_limit {
-            total_fee += args.per_gas * gas_limit;
-        }
-
+        let total_fee = args.total_fee;
         let mut payment = args.payment;
         require!(total_fee <= payment.amount, PAYMENT_DOES_NOT_COVER_FEE);
 
         payment.amount -= &total_fee;
 
         FinalPayment {
-            fee: EsdtTokenPayment::new(payment.token_identifier.clone(), 0, total_fee),
+            fee: EgldOrEsdtTokenPayment::new(payment.token_identifier.clone(), 0, total_fee),
             remaining_tokens: payment,
         }
     }
@@ -175,6 +626,12 @@ pub trait FeeCommonHelpersModule:
         if !self.is_valid_token_id(&fee_struct.base_token) {
             return Some(INVALID_TOKEN_ID);
         }
+        if !self
+            .fee_payment_token_base(&fee_struct.base_token)
+            .is_empty()
+        {
+            return Some(FEE_PAYMENT_TOKEN_ALREADY_USED);
+        }
 
         match &fee_struct.fee_type {
             FeeType::None => return Some(INVALID_FEE_TYPE),
@@ -183,15 +640,59 @@ pub trait FeeCommonHelpersModule:
                     return Some(INVALID_FEE);
                 }
             }
+            FeeType::Percentage {
+                token,
+                basis_points,
+                min,
+                max,
+            }
+            | FeeType::FixedPlusPercentage {
+                token,
+                basis_points,
+                min,
+                max,
+                ..
+            } => {
+                if &fee_struct.base_token != token {
+                    return Some(INVALID_FEE);
+                }
+                if *basis_points > TOTAL_PERCENTAGE {
+                    return Some(INVALID_FEE_BASIS_POINTS);
+                }
+                if min > max {
+                    return Some(MIN_FEE_ABOVE_MAX_FEE);
+                }
+            }
+            FeeType::Tiered { token, tiers } => {
+                if &fee_struct.base_token != token {
+                    return Some(INVALID_FEE);
+                }
+                if !self.are_fee_tiers_valid(tiers) {
+                    return Some(INVALID_FEE_TIERS);
+                }
+            }
         };
 
-        self.fee_enabled().set(true);
+        self.active_fee_tokens()
+            .insert(fee_struct.base_token.clone());
         self.token_fee(&fee_struct.base_token)
             .set(fee_struct.fee_type.clone());
 
         None
     }
 
+    /// `tokenFee` entries can't be iterated, so the upgrade receives the tokens that had a fee
+    /// set. Each of them becomes an active fee token and the legacy global flag is cleared.
+    fn migrate_active_fee_tokens(&self, fee_tokens: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
+        for fee_token in fee_tokens {
+            if !self.token_fee(&fee_token).is_empty() {
+                self.active_fee_tokens().insert(fee_token);
+            }
+        }
+
+        self.legacy_fee_enabled().clear();
+    }
+
     fn init_fee_market(
         &self,
         esdt_safe_address: ManagedAddress,
@@ -200,13 +701,10 @@ pub trait FeeCommonHelpersModule:
         self.require_sc_address(&esdt_safe_address);
         self.esdt_safe_address().set(esdt_safe_address);
 
-        match fee {
-            Some(fee_struct) => {
-                if let Some(err_msg) = self.set_fee_in_storage(&fee_struct) {
-                    sc_panic!(err_msg);
-                }
+        if let Some(fee_struct) = fee {
+            if let Some(err_msg) = self.set_fee_in_storage(&fee_struct) {
+                sc_panic!(err_msg);
             }
-            None => self.fee_enabled().set(false),
         }
     }
 }
@@ -1,5 +1,5 @@
 use error_messages::ONLY_ESDT_SAFE_CALLER;
-use structs::fee::FeeType;
+use structs::fee::{AddressPercentagePair, FeeType, VolumeWindow};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -13,7 +13,11 @@ pub trait FeeCommonStorageModule {
     }
 
     fn is_fee_enabled(&self) -> bool {
-        self.fee_enabled().get()
+        !self.active_fee_tokens().is_empty()
+    }
+
+    fn is_fee_token_active(&self, token_id: &EgldOrEsdtTokenIdentifier<Self::Api>) -> bool {
+        self.active_fee_tokens().contains(token_id)
     }
 
     #[view(getTokenFee)]
@@ -23,18 +27,109 @@ pub trait FeeCommonStorageModule {
         token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
     ) -> SingleValueMapper<FeeType<Self::Api>>;
 
+    /// Base tokens with a fee set. Accepted payment tokens only resolve to their base token.
+    #[view(getActiveFeeTokens)]
+    #[storage_mapper("activeFeeTokens")]
+    fn active_fee_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
+
+    /// Global switch of fee markets deployed before fees were activated per token, only cleared
+    /// by the `upgrade()` migration.
     #[storage_mapper("feeEnabledFlag")]
-    fn fee_enabled(&self) -> SingleValueMapper<bool>;
+    fn legacy_fee_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getFeePaymentTokenRates)]
+    #[storage_mapper("feePaymentTokenRates")]
+    fn fee_payment_token_rates(
+        &self,
+        base_token: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier<Self::Api>, BigUint>;
+
+    #[storage_mapper("feePaymentTokenBase")]
+    fn fee_payment_token_base(
+        &self,
+        payment_token: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> SingleValueMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
 
     #[view(getUsersWhitelist)]
     #[storage_mapper("usersWhitelist")]
     fn users_whitelist(&self) -> UnorderedSetMapper<ManagedAddress>;
 
+    #[view(getFeeExemptDestinations)]
+    #[storage_mapper("feeExemptDestinations")]
+    fn fee_exempt_destinations(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getFeeExemptTokens)]
+    #[storage_mapper("feeExemptTokens")]
+    fn fee_exempt_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
+
     #[storage_mapper("accFees")]
-    fn accumulated_fees(&self, token_id: &EsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+    fn accumulated_fees(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
 
     #[storage_mapper("tokensForFees")]
-    fn tokens_for_fees(&self) -> UnorderedSetMapper<EsdtTokenIdentifier>;
+    fn tokens_for_fees(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
+
+    #[view(getClaimableFees)]
+    #[storage_mapper("claimableFees")]
+    fn claimable_fees(
+        &self,
+        recipient: &ManagedAddress,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier<Self::Api>, BigUint>;
+
+    #[view(getFeeSplit)]
+    #[storage_mapper("feeSplit")]
+    fn fee_split(&self) -> SingleValueMapper<ManagedVec<AddressPercentagePair<Self::Api>>>;
+
+    #[view(getDistributionInterval)]
+    #[storage_mapper("distributionInterval")]
+    fn distribution_interval(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLastDistributionBlock)]
+    #[storage_mapper("lastDistributionBlock")]
+    fn last_distribution_block(&self) -> SingleValueMapper<u64>;
+
+    #[view(getTotalFeesCollected)]
+    #[storage_mapper("totalFeesCollected")]
+    fn total_fees_collected(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalFeesDistributed)]
+    #[storage_mapper("totalFeesDistributed")]
+    fn total_fees_distributed(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getRecipientFeesDistributed)]
+    #[storage_mapper("recipientFeesDistributed")]
+    fn recipient_fees_distributed(
+        &self,
+        recipient: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getEpochFeesCollected)]
+    #[storage_mapper("epochFeesCollected")]
+    fn epoch_fees_collected(
+        &self,
+        epoch: u64,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getEpochFeesDistributed)]
+    #[storage_mapper("epochFeesDistributed")]
+    fn epoch_fees_distributed(
+        &self,
+        epoch: u64,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("userVolume")]
+    fn user_volume(&self, user: &ManagedAddress) -> SingleValueMapper<VolumeWindow>;
 
     #[storage_mapper("esdtSafeAddress")]
     fn esdt_safe_address(&self) -> SingleValueMapper<ManagedAddress>;
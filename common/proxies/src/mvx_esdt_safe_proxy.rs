@@ -75,12 +75,16 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
-    pub fn upgrade(
+    pub fn upgrade<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<EgldOrEsdtTokenIdentifier<Env::Api>, EsdtTokenType, usize>>>,
+    >(
         self,
+        token_registry_backfill: Arg0,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_upgrade()
+            .argument(&token_registry_backfill)
             .original_result()
     }
 }
@@ -123,6 +127,22 @@ where
             .original_result()
     }
 
+    pub fn patch_esdt_safe_config<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<structs::configs::PatchEsdtSafeConfigOperation<Env::Api>>,
+    >(
+        self,
+        hash_of_hashes: Arg0,
+        patch_config_operation: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("patchEsdtSafeConfig")
+            .argument(&hash_of_hashes)
+            .argument(&patch_config_operation)
+            .original_result()
+    }
+
     pub fn switch_pause_status<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<structs::configs::PauseStatusOperation>,
@@ -176,6 +196,85 @@ where
             .original_result()
     }
 
+    pub fn deposit_with_refund_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<MultiValue3<u64, ManagedBuffer<Env::Api>, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>>>,
+    >(
+        self,
+        to: Arg0,
+        refund_address: Arg1,
+        opt_transfer_data: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositWithRefundAddress")
+            .argument(&to)
+            .argument(&refund_address)
+            .argument(&opt_transfer_data)
+            .original_result()
+    }
+
+    pub fn deposit_multi<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, structs::operation::MultiDepositEntry<Env::Api>>>,
+    >(
+        self,
+        entries: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositMulti")
+            .argument(&entries)
+            .original_result()
+    }
+
+    pub fn deposit_multi_with_refund_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, structs::operation::MultiDepositEntry<Env::Api>>>,
+    >(
+        self,
+        refund_address: Arg0,
+        entries: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositMultiWithRefundAddress")
+            .argument(&refund_address)
+            .argument(&entries)
+            .original_result()
+    }
+
+    /// Quotes the fee of a deposit in `fee_token`. Takes the `transfers` to bridge, without the 
+    /// fee payment, instead of their count, since percentage and exempt token fees depend on 
+    /// the amounts and tokens. 
+    pub fn get_fee_quote<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedVec<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        fee_token: Arg0,
+        transfers: Arg1,
+        gas_limit: Arg2,
+        opt_caller: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, structs::fee::FeeQuote<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeQuote")
+            .argument(&fee_token)
+            .argument(&transfers)
+            .argument(&gas_limit)
+            .argument(&opt_caller)
+            .original_result()
+    }
+
+    pub fn get_esdt_safe_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, structs::configs::EsdtSafeConfig<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEsdtSafeConfig")
+            .original_result()
+    }
+
     pub fn execute_operations<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<structs::operation::Operation<Env::Api>>,
@@ -281,16 +380,417 @@ where
             .original_result()
     }
 
-    pub fn deposited_tokens_amount<
+    pub fn get_deposited_tokens_amount<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
     >(
         self,
         token_identifier: Arg0,
+        token_nonce: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("getDepositedTokensAmount")
             .argument(&token_identifier)
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    pub fn set_token_freeze_status<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<structs::configs::SetTokenFreezeOperation<Env::Api>>,
+    >(
+        self,
+        hash_of_hashes: Arg0,
+        set_token_freeze_operation: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTokenFreezeStatus")
+            .argument(&hash_of_hashes)
+            .argument(&set_token_freeze_operation)
+            .original_result()
+    }
+
+    pub fn frozen_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFrozenTokens")
+            .original_result()
+    }
+
+    pub fn set_inbound_policy_setup_phase<
+        Arg0: ProxyArg<OptionalValue<structs::configs::InboundPolicy<Env::Api>>>,
+    >(
+        self,
+        opt_inbound_policy: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setInboundPolicySetupPhase")
+            .argument(&opt_inbound_policy)
+            .original_result()
+    }
+
+    pub fn set_inbound_policy<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<structs::configs::SetInboundPolicyOperation<Env::Api>>,
+    >(
+        self,
+        hash_of_hashes: Arg0,
+        set_inbound_policy_operation: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setInboundPolicy")
+            .argument(&hash_of_hashes)
+            .argument(&set_inbound_policy_operation)
+            .original_result()
+    }
+
+    pub fn get_inbound_policy(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<structs::configs::InboundPolicy<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInboundPolicy")
+            .original_result()
+    }
+
+    pub fn fund_issue_pool(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fundIssuePool")
+            .original_result()
+    }
+
+    pub fn set_issue_cost<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<structs::configs::SetIssueCostOperation<Env::Api>>,
+    >(
+        self,
+        hash_of_hashes: Arg0,
+        set_issue_cost_operation: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setIssueCost")
+            .argument(&hash_of_hashes)
+            .argument(&set_issue_cost_operation)
+            .original_result()
+    }
+
+    pub fn get_issue_cost(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIssueCost")
+            .original_result()
+    }
+
+    pub fn get_issue_pool_available_balance(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIssuePoolAvailableBalance")
+            .original_result()
+    }
+
+    /// Starts empty, also for upgraded safes, whose EGLD balance may hold bridged EGLD. Only 
+    /// `fundIssuePool` adds to it. 
+    pub fn issue_pool_balance(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIssuePoolBalance")
+            .original_result()
+    }
+
+    pub fn issue_pool_reserved(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIssuePoolReservedBalance")
+            .original_result()
+    }
+
+    pub fn issue_pool_contribution<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        funder: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIssuePoolContribution")
+            .argument(&funder)
+            .original_result()
+    }
+
+    /// Pauses the safe for good and moves the escrowed balances of the listed tokens, and the 
+    /// unit kept for burn mechanism nonces, to the successor registered in the sovereign forge, 
+    /// which has to live in the same shard. 
+    ///  
+    /// ESDT local roles are not handed over. The successor can only mint and burn the sovereign 
+    /// wrappers, the native token and the burn mechanism tokens once their managers grant it 
+    /// those roles through the ESDT system SC (`setSpecialRole`, `transferNFTCreateRole`). 
+    /// This safe manages the tokens it issued and has no endpoint for that hand-over, so it 
+    /// takes an upgrade of this safe before the successor can bridge those tokens. 
+    pub fn migrate_safe<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<structs::configs::MigrateSafeOperation<Env::Api>>,
+    >(
+        self,
+        hash_of_hashes: Arg0,
+        migrate_safe_operation: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("migrateSafe")
+            .argument(&hash_of_hashes)
+            .argument(&migrate_safe_operation)
+            .original_result()
+    }
+
+    pub fn get_migration_state_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMigrationStateCount")
+            .original_result()
+    }
+
+    /// Pages are zero-based and hold at most `MAX_MIGRATION_PAGE_SIZE` entries. 
+    pub fn export_migration_state<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from_index: Arg0,
+        page_size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, structs::MigrationEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("exportMigrationState")
+            .argument(&from_index)
+            .argument(&page_size)
+            .original_result()
+    }
+
+    pub fn set_predecessor_safe<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        predecessor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPredecessorSafe")
+            .argument(&predecessor)
+            .original_result()
+    }
+
+    pub fn receive_migrated_funds(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("receiveMigratedFunds")
+            .original_result()
+    }
+
+    /// Pages can be imported again, entries that are already imported are left as they are. 
+    pub fn import_migration_state<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from_index: Arg0,
+        page_size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("importMigrationState")
+            .argument(&from_index)
+            .argument(&page_size)
+            .original_result()
+    }
+
+    pub fn is_safe_migrated(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isSafeMigrated")
+            .original_result()
+    }
+
+    pub fn successor_safe_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSuccessorSafeAddress")
+            .original_result()
+    }
+
+    pub fn predecessor_safe_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPredecessorSafeAddress")
+            .original_result()
+    }
+
+    /// Index right after the furthest entry imported from the predecessor. 
+    pub fn migration_import_index(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMigrationImportIndex")
+            .original_result()
+    }
+
+    pub fn update_nft_metadata<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<structs::configs::UpdateNftMetadataOperation<Env::Api>>,
+    >(
+        self,
+        hash_of_hashes: Arg0,
+        update_nft_metadata_operation: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateNftMetadata")
+            .argument(&hash_of_hashes)
+            .argument(&update_nft_metadata_operation)
+            .original_result()
+    }
+
+    pub fn get_registered_tokens_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRegisteredTokensCount")
+            .original_result()
+    }
+
+    /// Pages are zero-based and hold at most `MAX_REGISTRY_PAGE_SIZE` entries. 
+    pub fn get_registered_tokens<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from_index: Arg0,
+        page_size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, structs::TokenRegistryEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRegisteredTokens")
+            .argument(&from_index)
+            .argument(&page_size)
+            .original_result()
+    }
+
+    pub fn get_nft_nonce_mappings_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNftNonceMappingsCount")
+            .original_result()
+    }
+
+    pub fn get_nft_nonce_mappings<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from_index: Arg0,
+        page_size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, structs::NftNonceMapping<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNftNonceMappings")
+            .argument(&from_index)
+            .argument(&page_size)
+            .original_result()
+    }
+
+    /// The reserve is the escrowed balance for lock mechanism tokens, the deposited amount plus 
+    /// the kept unit for burn mechanism tokens and the outstanding minted supply for sovereign wrappers. 
+    /// All amounts are for the given MultiversX token nonce. 
+    pub fn get_token_reserves<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        token_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, structs::TokenReserves<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenReserves")
+            .argument(&token_id)
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    pub fn total_deposited<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        token_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalDeposited")
+            .argument(&token_id)
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    pub fn total_executed<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        token_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalExecuted")
+            .argument(&token_id)
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    pub fn total_refunded<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        token_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalRefunded")
+            .argument(&token_id)
+            .argument(&token_nonce)
             .original_result()
     }
 
@@ -138,6 +138,30 @@ where
             .original_result()
     }
 
+    /// Should match the issue cost of the MultiversX safe, which refunds that amount when 
+    /// the registration fails. 
+    pub fn set_issue_cost<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        issue_cost: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setIssueCost")
+            .argument(&issue_cost)
+            .original_result()
+    }
+
+    pub fn get_issue_cost(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIssueCost")
+            .original_result()
+    }
+
     pub fn deposit<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<OptionalValue<MultiValue3<u64, ManagedBuffer<Env::Api>, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>>>,
@@ -153,6 +177,95 @@ where
             .original_result()
     }
 
+    pub fn deposit_with_refund_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<MultiValue3<u64, ManagedBuffer<Env::Api>, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>>>,
+    >(
+        self,
+        to: Arg0,
+        refund_address: Arg1,
+        opt_transfer_data: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositWithRefundAddress")
+            .argument(&to)
+            .argument(&refund_address)
+            .argument(&opt_transfer_data)
+            .original_result()
+    }
+
+    pub fn deposit_multi<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, structs::operation::MultiDepositEntry<Env::Api>>>,
+    >(
+        self,
+        entries: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositMulti")
+            .argument(&entries)
+            .original_result()
+    }
+
+    pub fn deposit_multi_with_refund_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, structs::operation::MultiDepositEntry<Env::Api>>>,
+    >(
+        self,
+        refund_address: Arg0,
+        entries: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositMultiWithRefundAddress")
+            .argument(&refund_address)
+            .argument(&entries)
+            .original_result()
+    }
+
+    /// Quotes the fee of a deposit in `fee_token`. Takes the `transfers` to bridge, without the 
+    /// fee payment, instead of their count, since percentage and exempt token fees depend on 
+    /// the amounts and tokens. 
+    pub fn get_fee_quote<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedVec<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        fee_token: Arg0,
+        transfers: Arg1,
+        gas_limit: Arg2,
+        opt_caller: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, structs::fee::FeeQuote<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeQuote")
+            .argument(&fee_token)
+            .argument(&transfers)
+            .argument(&gas_limit)
+            .argument(&opt_caller)
+            .original_result()
+    }
+
+    /// The NFT is only shown to the safe so its current metadata can be read, and is sent back 
+    /// to the caller. The emitted event is picked up as an `UpdateNftMetadataOperation`. 
+    pub fn sync_nft_metadata(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("syncNftMetadata")
+            .original_result()
+    }
+
+    pub fn get_esdt_safe_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, structs::configs::EsdtSafeConfig<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEsdtSafeConfig")
+            .original_result()
+    }
+
     pub fn sovereign_to_multiversx_token_id_mapper<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
@@ -69,12 +69,16 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
-    pub fn upgrade(
+    pub fn upgrade<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
         self,
+        fee_tokens: Arg0,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_upgrade()
+            .argument(&fee_tokens)
             .original_result()
     }
 }
@@ -114,6 +118,38 @@ where
             .original_result()
     }
 
+    pub fn add_fee_exemptions<
+        Arg0: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+        Arg1: ProxyArg<ManagedVec<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        destinations: Arg0,
+        tokens: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addFeeExemptions")
+            .argument(&destinations)
+            .argument(&tokens)
+            .original_result()
+    }
+
+    pub fn remove_fee_exemptions<
+        Arg0: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+        Arg1: ProxyArg<ManagedVec<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        destinations: Arg0,
+        tokens: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeFeeExemptions")
+            .argument(&destinations)
+            .argument(&tokens)
+            .original_result()
+    }
+
     pub fn token_fee<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
@@ -127,6 +163,29 @@ where
             .original_result()
     }
 
+    /// Base tokens with a fee set. Accepted payment tokens only resolve to their base token. 
+    pub fn active_fee_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActiveFeeTokens")
+            .original_result()
+    }
+
+    pub fn fee_payment_token_rates<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        base_token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeePaymentTokenRates")
+            .argument(&base_token)
+            .original_result()
+    }
+
     pub fn users_whitelist(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
@@ -136,6 +195,138 @@ where
             .original_result()
     }
 
+    pub fn fee_exempt_destinations(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeExemptDestinations")
+            .original_result()
+    }
+
+    pub fn fee_exempt_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeExemptTokens")
+            .original_result()
+    }
+
+    pub fn claimable_fees<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        recipient: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimableFees")
+            .argument(&recipient)
+            .original_result()
+    }
+
+    pub fn fee_split(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, structs::fee::AddressPercentagePair<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeSplit")
+            .original_result()
+    }
+
+    pub fn distribution_interval(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDistributionInterval")
+            .original_result()
+    }
+
+    pub fn last_distribution_block(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastDistributionBlock")
+            .original_result()
+    }
+
+    pub fn total_fees_collected<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalFeesCollected")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn total_fees_distributed<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalFeesDistributed")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn recipient_fees_distributed<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        recipient: Arg0,
+        token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRecipientFeesDistributed")
+            .argument(&recipient)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn epoch_fees_collected<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        epoch: Arg0,
+        token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEpochFeesCollected")
+            .argument(&epoch)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn epoch_fees_distributed<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        epoch: Arg0,
+        token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEpochFeesDistributed")
+            .argument(&epoch)
+            .argument(&token_id)
+            .original_result()
+    }
+
     pub fn distribute_fees<
         Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, usize>>>,
     >(
@@ -149,6 +340,35 @@ where
             .original_result()
     }
 
+    pub fn allocate_fees<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, usize>>>,
+    >(
+        self,
+        address_percentage_pairs: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("allocateFees")
+            .argument(&address_percentage_pairs)
+            .original_result()
+    }
+
+    pub fn set_fee_split<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, usize>>>,
+    >(
+        self,
+        distribution_interval: Arg0,
+        address_percentage_pairs: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFeeSplit")
+            .argument(&distribution_interval)
+            .argument(&address_percentage_pairs)
+            .original_result()
+    }
+
     pub fn remove_fee<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
@@ -175,21 +395,137 @@ where
             .original_result()
     }
 
+    pub fn set_fee_payment_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        base_token: Arg0,
+        payment_token: Arg1,
+        rate: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFeePaymentToken")
+            .argument(&base_token)
+            .argument(&payment_token)
+            .argument(&rate)
+            .original_result()
+    }
+
+    pub fn remove_fee_payment_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        base_token: Arg0,
+        payment_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeFeePaymentToken")
+            .argument(&base_token)
+            .argument(&payment_token)
+            .original_result()
+    }
+
     pub fn subtract_fee<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<usize>,
-        Arg2: ProxyArg<OptionalValue<u64>>,
+        Arg1: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+        Arg2: ProxyArg<ManagedVec<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>>,
+        Arg3: ProxyArg<OptionalValue<u64>>,
     >(
         self,
         original_caller: Arg0,
-        total_transfers: Arg1,
-        opt_gas_limit: Arg2,
+        destinations: Arg1,
+        transfers: Arg2,
+        opt_gas_limit: Arg3,
     ) -> TxTypedCall<Env, From, To, (), Gas, structs::fee::FinalPayment<Env::Api>> {
         self.wrapped_tx
             .raw_call("subtractFee")
             .argument(&original_caller)
-            .argument(&total_transfers)
+            .argument(&destinations)
+            .argument(&transfers)
             .argument(&opt_gas_limit)
             .original_result()
     }
+
+    /// Claims the allocated fees of the caller for `token_ids`, or for every token when none is given. 
+    pub fn claim_fees<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        token_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimFees")
+            .argument(&token_ids)
+            .original_result()
+    }
+
+    pub fn trigger_distribution(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("triggerDistribution")
+            .original_result()
+    }
+
+    /// Returns the fee `subtractFee` would charge in `fee_token`. `opt_caller` is needed for 
+    /// whitelisted callers and tiered fees. Exempt destinations are not taken into account. 
+    pub fn get_fee_quote<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedVec<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        fee_token: Arg0,
+        transfers: Arg1,
+        gas_limit: Arg2,
+        opt_caller: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, structs::fee::FeeQuote<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeQuote")
+            .argument(&fee_token)
+            .argument(&transfers)
+            .argument(&gas_limit)
+            .argument(&opt_caller)
+            .original_result()
+    }
+
+    /// Number of transfers the user paid a tiered fee for, decayed over the volume window. 
+    pub fn get_user_volume<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserVolume")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Returns `None` when the fee paid with `token_id` is not tiered. 
+    pub fn get_user_fee_tier<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserFeeTier")
+            .argument(&user)
+            .argument(&token_id)
+            .original_result()
+    }
 }
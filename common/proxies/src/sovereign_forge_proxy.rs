@@ -186,6 +186,19 @@ where
             .original_result()
     }
 
+    pub fn esdt_safe_successor<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        chain_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEsdtSafeSuccessor")
+            .argument(&chain_id)
+            .original_result()
+    }
+
     pub fn trusted_tokens(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
@@ -239,6 +252,19 @@ where
             .original_result()
     }
 
+    pub fn register_esdt_safe_successor<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        successor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerEsdtSafeSuccessor")
+            .argument(&successor)
+            .original_result()
+    }
+
     pub fn update_esdt_safe_config<
         Arg0: ProxyArg<structs::configs::EsdtSafeConfig<Env::Api>>,
     >(
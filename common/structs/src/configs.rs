@@ -3,7 +3,7 @@ use multiversx_sc::api::CryptoApi;
 use crate::{
     aliases::{GasLimit, TxNonce},
     generate_hash::GenerateHash,
-    DEFAULT_MAX_TX_GAS_LIMIT,
+    EsdtInfo, DEFAULT_MAX_TX_GAS_LIMIT,
 };
 
 multiversx_sc::imports!();
@@ -114,6 +114,49 @@ pub struct PauseStatusOperation {
 }
 impl<A: CryptoApi> GenerateHash<A> for PauseStatusOperation {}
 
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug, PartialEq,
+)]
+pub struct SetTokenFreezeOperation<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub frozen: bool,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for SetTokenFreezeOperation<A> {}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, PartialEq)]
+pub struct SetIssueCostOperation<M: ManagedTypeApi> {
+    pub issue_cost: BigUint<M>,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for SetIssueCostOperation<A> {}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct NftMetadata<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub royalties: u64,
+    pub hash: ManagedBuffer<M>,
+    pub attributes: ManagedBuffer<M>,
+    pub uris: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+/// `token_id` and `token_nonce` identify the NFT on the sovereign chain.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct UpdateNftMetadataOperation<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub metadata: NftMetadata<M>,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for UpdateNftMetadataOperation<A> {}
+
 #[type_abi]
 #[derive(
     TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug, PartialEq,
@@ -123,6 +166,24 @@ pub struct MaxBridgedAmount<M: ManagedTypeApi> {
     pub amount: BigUint<M>,
 }
 
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug, PartialEq,
+)]
+pub struct MinBridgedAmount<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug, PartialEq,
+)]
+pub struct AllowedTarget<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub endpoints: ManagedVec<M, ManagedBuffer<M>>,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct UpdateEsdtSafeConfigOperation<M: ManagedTypeApi> {
@@ -132,6 +193,91 @@ pub struct UpdateEsdtSafeConfigOperation<M: ManagedTypeApi> {
 
 impl<A: CryptoApi> GenerateHash<A> for UpdateEsdtSafeConfigOperation<A> {}
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, PartialEq)]
+pub enum EsdtSafeConfigPatch<M: ManagedTypeApi> {
+    AddTokenToWhitelist {
+        token_id: EgldOrEsdtTokenIdentifier<M>,
+    },
+    RemoveTokenFromWhitelist {
+        token_id: EgldOrEsdtTokenIdentifier<M>,
+    },
+    AddTokenToBlacklist {
+        token_id: EgldOrEsdtTokenIdentifier<M>,
+    },
+    RemoveTokenFromBlacklist {
+        token_id: EgldOrEsdtTokenIdentifier<M>,
+    },
+    AddBannedEndpoint {
+        endpoint_name: ManagedBuffer<M>,
+    },
+    RemoveBannedEndpoint {
+        endpoint_name: ManagedBuffer<M>,
+    },
+    AddBlacklistedAddress {
+        address: ManagedAddress<M>,
+    },
+    RemoveBlacklistedAddress {
+        address: ManagedAddress<M>,
+    },
+    SetMaxBridgedAmount {
+        max_bridged_amount: MaxBridgedAmount<M>,
+    },
+    RemoveMaxBridgedAmount {
+        token_id: EgldOrEsdtTokenIdentifier<M>,
+    },
+    SetMinBridgedAmount {
+        min_bridged_amount: MinBridgedAmount<M>,
+    },
+    RemoveMinBridgedAmount {
+        token_id: EgldOrEsdtTokenIdentifier<M>,
+    },
+    AddAllowedTargetEndpoint {
+        address: ManagedAddress<M>,
+        endpoint_name: ManagedBuffer<M>,
+    },
+    RemoveAllowedTargetEndpoint {
+        address: ManagedAddress<M>,
+        endpoint_name: ManagedBuffer<M>,
+    },
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PatchEsdtSafeConfigOperation<M: ManagedTypeApi> {
+    pub patch: EsdtSafeConfigPatch<M>,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for PatchEsdtSafeConfigOperation<A> {}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct InboundPolicy<M: ManagedTypeApi> {
+    pub token_blacklist: ManagedVec<M, EgldOrEsdtTokenIdentifier<M>>,
+    pub address_blacklist: ManagedVec<M, ManagedAddress<M>>,
+    pub max_bridged_token_amounts: ManagedVec<M, MaxBridgedAmount<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct SetInboundPolicyOperation<M: ManagedTypeApi> {
+    pub opt_inbound_policy: Option<InboundPolicy<M>>,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for SetInboundPolicyOperation<A> {}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MigrateSafeOperation<M: ManagedTypeApi> {
+    pub successor: ManagedAddress<M>,
+    pub tokens: ManagedVec<M, EsdtInfo<M>>,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for MigrateSafeOperation<A> {}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct EsdtSafeConfig<M: ManagedTypeApi> {
@@ -141,6 +287,8 @@ pub struct EsdtSafeConfig<M: ManagedTypeApi> {
     pub banned_endpoints: ManagedVec<M, ManagedBuffer<M>>,
     pub address_blacklist: ManagedVec<M, ManagedAddress<M>>,
     pub max_bridged_token_amounts: ManagedVec<M, MaxBridgedAmount<M>>,
+    pub min_bridged_token_amounts: ManagedVec<M, MinBridgedAmount<M>>,
+    pub allowed_targets: ManagedVec<M, AllowedTarget<M>>,
 }
 
 impl<A: CryptoApi> GenerateHash<A> for EsdtSafeConfig<A> {}
@@ -155,9 +303,12 @@ impl<M: ManagedTypeApi> EsdtSafeConfig<M> {
             banned_endpoints: ManagedVec::new(),
             address_blacklist: ManagedVec::new(),
             max_bridged_token_amounts: ManagedVec::new(),
+            min_bridged_token_amounts: ManagedVec::new(),
+            allowed_targets: ManagedVec::new(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         token_whitelist: ManagedVec<M, EgldOrEsdtTokenIdentifier<M>>,
         token_blacklist: ManagedVec<M, EgldOrEsdtTokenIdentifier<M>>,
@@ -165,6 +316,8 @@ impl<M: ManagedTypeApi> EsdtSafeConfig<M> {
         banned_endpoints: ManagedVec<M, ManagedBuffer<M>>,
         deposit_blacklist: ManagedVec<M, ManagedAddress<M>>,
         max_bridged_token_amounts: ManagedVec<M, MaxBridgedAmount<M>>,
+        min_bridged_token_amounts: ManagedVec<M, MinBridgedAmount<M>>,
+        allowed_targets: ManagedVec<M, AllowedTarget<M>>,
     ) -> Self {
         EsdtSafeConfig {
             token_whitelist,
@@ -173,6 +326,36 @@ impl<M: ManagedTypeApi> EsdtSafeConfig<M> {
             banned_endpoints,
             address_blacklist: deposit_blacklist,
             max_bridged_token_amounts,
+            min_bridged_token_amounts,
+            allowed_targets,
         }
     }
 }
+
+/// Baseline layout of the single-value `crossChainConfig` entry, which was replaced by dedicated
+/// mappers per field. Only decoded by the `upgrade()` migration of safes still holding it.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct LegacyEsdtSafeConfig<M: ManagedTypeApi> {
+    pub token_whitelist: ManagedVec<M, EgldOrEsdtTokenIdentifier<M>>,
+    pub token_blacklist: ManagedVec<M, EgldOrEsdtTokenIdentifier<M>>,
+    pub max_tx_gas_limit: GasLimit,
+    pub banned_endpoints: ManagedVec<M, ManagedBuffer<M>>,
+    pub address_blacklist: ManagedVec<M, ManagedAddress<M>>,
+    pub max_bridged_token_amounts: ManagedVec<M, MaxBridgedAmount<M>>,
+}
+
+impl<M: ManagedTypeApi> From<LegacyEsdtSafeConfig<M>> for EsdtSafeConfig<M> {
+    fn from(legacy_config: LegacyEsdtSafeConfig<M>) -> Self {
+        EsdtSafeConfig::new(
+            legacy_config.token_whitelist,
+            legacy_config.token_blacklist,
+            legacy_config.max_tx_gas_limit,
+            legacy_config.banned_endpoints,
+            legacy_config.address_blacklist,
+            legacy_config.max_bridged_token_amounts,
+            ManagedVec::new(),
+            ManagedVec::new(),
+        )
+    }
+}
@@ -1,9 +1,6 @@
 use multiversx_sc::api::CryptoApi;
 
-use crate::{
-    aliases::{GasLimit, TxNonce},
-    generate_hash::GenerateHash,
-};
+use crate::{aliases::TxNonce, generate_hash::GenerateHash};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -17,6 +14,44 @@ pub enum FeeType<M: ManagedTypeApi> {
         per_transfer: BigUint<M>,
         per_gas: BigUint<M>,
     },
+    /// `basis_points` of every bridged amount, clamped to `[min, max]` per transfer.
+    Percentage {
+        token: EgldOrEsdtTokenIdentifier<M>,
+        basis_points: usize,
+        min: BigUint<M>,
+        max: BigUint<M>,
+    },
+    FixedPlusPercentage {
+        token: EgldOrEsdtTokenIdentifier<M>,
+        per_transfer: BigUint<M>,
+        per_gas: BigUint<M>,
+        basis_points: usize,
+        min: BigUint<M>,
+        max: BigUint<M>,
+    },
+    /// Fixed rates picked by the caller's transfer count, decayed over the volume window.
+    /// `tiers` are sorted by `min_volume`, starting from `0`.
+    Tiered {
+        token: EgldOrEsdtTokenIdentifier<M>,
+        tiers: ManagedVec<M, FeeTier<M>>,
+    },
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug, PartialEq,
+)]
+pub struct FeeTier<M: ManagedTypeApi> {
+    pub min_volume: u64,
+    pub per_transfer: BigUint<M>,
+    pub per_gas: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Clone)]
+pub struct VolumeWindow {
+    pub last_update: u64,
+    pub volume: u64,
 }
 
 #[type_abi]
@@ -37,6 +72,26 @@ pub struct RemoveUsersFromWhitelistOperation<M: ManagedTypeApi> {
 
 impl<A: CryptoApi> GenerateHash<A> for RemoveUsersFromWhitelistOperation<A> {}
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, Clone)]
+pub struct AddFeeExemptionsOperation<M: ManagedTypeApi> {
+    pub destinations: ManagedVec<M, ManagedAddress<M>>,
+    pub tokens: ManagedVec<M, EgldOrEsdtTokenIdentifier<M>>,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for AddFeeExemptionsOperation<A> {}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedDecode, Clone)]
+pub struct RemoveFeeExemptionsOperation<M: ManagedTypeApi> {
+    pub destinations: ManagedVec<M, ManagedAddress<M>>,
+    pub tokens: ManagedVec<M, EgldOrEsdtTokenIdentifier<M>>,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for RemoveFeeExemptionsOperation<A> {}
+
 #[type_abi]
 #[derive(TopDecode, TopEncode, NestedEncode, NestedDecode, Clone)]
 pub struct RemoveFeeOperation<M: ManagedTypeApi> {
@@ -55,6 +110,29 @@ pub struct SetFeeOperation<M: ManagedTypeApi> {
 
 impl<A: CryptoApi> GenerateHash<A> for SetFeeOperation<A> {}
 
+/// `rate` is the amount of `payment_token` charged for one unit of `base_token`, scaled by
+/// `FEE_RATE_PRECISION`.
+#[type_abi]
+#[derive(TopDecode, TopEncode, NestedEncode, NestedDecode, Clone)]
+pub struct SetFeePaymentTokenOperation<M: ManagedTypeApi> {
+    pub base_token: EgldOrEsdtTokenIdentifier<M>,
+    pub payment_token: EgldOrEsdtTokenIdentifier<M>,
+    pub rate: BigUint<M>,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for SetFeePaymentTokenOperation<A> {}
+
+#[type_abi]
+#[derive(TopDecode, TopEncode, NestedEncode, NestedDecode, Clone)]
+pub struct RemoveFeePaymentTokenOperation<M: ManagedTypeApi> {
+    pub base_token: EgldOrEsdtTokenIdentifier<M>,
+    pub payment_token: EgldOrEsdtTokenIdentifier<M>,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for RemoveFeePaymentTokenOperation<A> {}
+
 #[type_abi]
 #[derive(TopDecode, TopEncode, NestedEncode, NestedDecode, Clone)]
 pub struct FeeStruct<M: ManagedTypeApi> {
@@ -68,8 +146,15 @@ impl<A: CryptoApi> GenerateHash<A> for EgldOrEsdtTokenIdentifier<A> {}
 #[type_abi]
 #[derive(TopEncode, TopDecode)]
 pub struct FinalPayment<M: ManagedTypeApi> {
-    pub fee: EsdtTokenPayment<M>,
-    pub remaining_tokens: EsdtTokenPayment<M>,
+    pub fee: EgldOrEsdtTokenPayment<M>,
+    pub remaining_tokens: EgldOrEsdtTokenPayment<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct FeeQuote<M: ManagedTypeApi> {
+    pub fee: EgldOrEsdtTokenPayment<M>,
+    pub accepted_tokens: ManagedVec<M, EgldOrEsdtTokenIdentifier<M>>,
 }
 
 #[type_abi]
@@ -81,6 +166,26 @@ pub struct DistributeFeesOperation<M: ManagedTypeApi> {
 
 impl<A: CryptoApi> GenerateHash<A> for DistributeFeesOperation<A> {}
 
+#[type_abi]
+#[derive(TopDecode, TopEncode, NestedEncode, NestedDecode, Clone)]
+pub struct AllocateFeesOperation<M: ManagedTypeApi> {
+    pub pairs: ManagedVec<M, AddressPercentagePair<M>>,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for AllocateFeesOperation<A> {}
+
+/// Standing split used by `triggerDistribution` once `distribution_interval` blocks have passed.
+#[type_abi]
+#[derive(TopDecode, TopEncode, NestedEncode, NestedDecode, Clone)]
+pub struct SetFeeSplitOperation<M: ManagedTypeApi> {
+    pub pairs: ManagedVec<M, AddressPercentagePair<M>>,
+    pub distribution_interval: u64,
+    pub nonce: TxNonce,
+}
+
+impl<A: CryptoApi> GenerateHash<A> for SetFeeSplitOperation<A> {}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, ManagedVecItem)]
 pub struct AddressPercentagePair<M: ManagedTypeApi> {
@@ -92,9 +197,6 @@ impl<A: CryptoApi> GenerateHash<A> for AddressPercentagePair<A> {}
 
 pub struct SubtractPaymentArguments<M: ManagedTypeApi> {
     pub fee_token: EgldOrEsdtTokenIdentifier<M>,
-    pub per_transfer: BigUint<M>,
-    pub per_gas: BigUint<M>,
-    pub payment: EsdtTokenPayment<M>,
-    pub total_transfers: usize,
-    pub opt_gas_limit: OptionalValue<GasLimit>,
+    pub total_fee: BigUint<M>,
+    pub payment: EgldOrEsdtTokenPayment<M>,
 }
@@ -44,6 +44,71 @@ pub struct EsdtInfo<M: ManagedTypeApi> {
     pub token_nonce: u64,
 }
 
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy, PartialEq, Debug,
+)]
+pub enum TokenMechanism {
+    Lock,
+    Burn,
+}
+
+/// `registration_nonce` is 0 for entries that were not created by a register token operation.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct TokenRegistryEntry<M: ManagedTypeApi> {
+    pub sov_token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub mvx_token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub token_type: EsdtTokenType,
+    pub num_decimals: usize,
+    pub registration_nonce: TxNonce,
+    pub mechanism: TokenMechanism,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct NftNonceMapping<M: ManagedTypeApi> {
+    pub sov_esdt_info: EsdtInfo<M>,
+    pub mvx_esdt_info: EsdtInfo<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum MigrationStep {
+    Paused,
+    EscrowTransferred,
+    StateExported,
+    StateImported,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub enum MigrationEntry<M: ManagedTypeApi> {
+    TokenPair(TokenRegistryEntry<M>),
+    NftNonce(NftNonceMapping<M>),
+    BurnMechanismToken(EgldOrEsdtTokenIdentifier<M>),
+    /// `kept_nonce_unit` is moved to the successor with the escrow, see `keptNonceUnit`.
+    DepositedAmount {
+        esdt_info: EsdtInfo<M>,
+        amount: BigUint<M>,
+        kept_nonce_unit: bool,
+    },
+    NativeToken(EgldOrEsdtTokenIdentifier<M>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TokenReserves<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub mechanism: TokenMechanism,
+    pub is_sovereign_wrapper: bool,
+    pub reserve_amount: BigUint<M>,
+    pub total_deposited: BigUint<M>,
+    pub total_executed: BigUint<M>,
+    pub total_refunded: BigUint<M>,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode)]
 pub struct ValidatorInfo<M: ManagedTypeApi> {
@@ -72,6 +137,7 @@ pub struct ValidatorData<M: ManagedTypeApi> {
     pub bls_key: ManagedBuffer<M>,
 }
 
+/// `data` must stay the last field, see `OperationData`.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode)]
 pub struct RegisterTokenOperation<M: ManagedTypeApi> {
@@ -1,5 +1,11 @@
 use aliases::{GasLimit, OptionalValueTransferDataTuple, TxId};
-use multiversx_sc::api::CryptoApi;
+use multiversx_sc::{
+    api::CryptoApi,
+    codec::{
+        top_decode_from_nested_or_handle_err, top_encode_from_nested, DecodeErrorHandler,
+        EncodeErrorHandler, NestedDecodeInput, NestedEncodeOutput, TopDecodeInput, TopEncodeOutput,
+    },
+};
 
 use crate::{
     aliases::{self, EventPaymentTuple, TransferDataTuple},
@@ -10,6 +16,7 @@ use crate::{
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+/// `data` must stay the last field, see `OperationData`.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct Operation<M: ManagedTypeApi> {
@@ -87,12 +94,29 @@ impl<M: ManagedTypeApi> From<TransferDataTuple<M>> for TransferData<M> {
     }
 }
 
+/// `payment_index` points into the deposited payments, after the fee payment was taken out.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct MultiDepositEntry<M: ManagedTypeApi> {
+    pub to: ManagedAddress<M>,
+    pub payment_index: usize,
+    pub opt_transfer_data: Option<TransferData<M>>,
+}
+
+/// `opt_refund_address` and `opt_destination_chain_id` are trailing extensions of the original
+/// layout. When both are `None` neither is encoded, so legacy operations and events keep their
+/// encoding and hash. `opt_refund_address` is encoded as a regular option (`None` included) when
+/// either is set, `opt_destination_chain_id` only when it is set. Decoders read each of them only
+/// while input is left and treat them as `None` at the end of the input, so `OperationData` must be
+/// the last field of any encoded type holding it, and such a type may only be top-level encoded.
+#[type_abi]
+#[derive(ManagedVecItem, Clone)]
 pub struct OperationData<M: ManagedTypeApi> {
     pub op_nonce: TxId,
     pub op_sender: ManagedAddress<M>,
     pub opt_transfer_data: Option<TransferData<M>>,
+    pub opt_refund_address: Option<ManagedAddress<M>>,
+    pub opt_destination_chain_id: Option<ManagedBuffer<M>>,
 }
 
 impl<M: ManagedTypeApi> OperationData<M> {
@@ -106,7 +130,100 @@ impl<M: ManagedTypeApi> OperationData<M> {
             op_nonce,
             op_sender,
             opt_transfer_data,
+            opt_refund_address: None,
+            opt_destination_chain_id: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_refund_address(mut self, opt_refund_address: Option<ManagedAddress<M>>) -> Self {
+        self.opt_refund_address = opt_refund_address;
+        self
+    }
+
+    #[inline]
+    pub fn with_destination_chain_id(
+        mut self,
+        opt_destination_chain_id: Option<ManagedBuffer<M>>,
+    ) -> Self {
+        self.opt_destination_chain_id = opt_destination_chain_id;
+        self
+    }
+
+    /// Failed executions are refunded here, falling back to the depositor.
+    pub fn refund_address(&self) -> &ManagedAddress<M> {
+        self.opt_refund_address.as_ref().unwrap_or(&self.op_sender)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedEncode for OperationData<M> {
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.op_nonce.dep_encode_or_handle_err(dest, h)?;
+        self.op_sender.dep_encode_or_handle_err(dest, h)?;
+        self.opt_transfer_data.dep_encode_or_handle_err(dest, h)?;
+        if self.opt_refund_address.is_some() || self.opt_destination_chain_id.is_some() {
+            self.opt_refund_address.dep_encode_or_handle_err(dest, h)?;
+        }
+        if self.opt_destination_chain_id.is_some() {
+            self.opt_destination_chain_id
+                .dep_encode_or_handle_err(dest, h)?;
         }
+
+        Ok(())
+    }
+}
+
+impl<M: ManagedTypeApi> NestedDecode for OperationData<M> {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let op_nonce = TxId::dep_decode_or_handle_err(input, h)?;
+        let op_sender = ManagedAddress::dep_decode_or_handle_err(input, h)?;
+        let opt_transfer_data = Option::dep_decode_or_handle_err(input, h)?;
+        let opt_refund_address = if input.is_depleted() {
+            None
+        } else {
+            Option::dep_decode_or_handle_err(input, h)?
+        };
+        let opt_destination_chain_id = if input.is_depleted() {
+            None
+        } else {
+            Option::dep_decode_or_handle_err(input, h)?
+        };
+
+        Ok(OperationData {
+            op_nonce,
+            op_sender,
+            opt_transfer_data,
+            opt_refund_address,
+            opt_destination_chain_id,
+        })
+    }
+}
+
+impl<M: ManagedTypeApi> TopEncode for OperationData<M> {
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        top_encode_from_nested(self, output, h)
+    }
+}
+
+impl<M: ManagedTypeApi> TopDecode for OperationData<M> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        top_decode_from_nested_or_handle_err(input, h)
     }
 }
 
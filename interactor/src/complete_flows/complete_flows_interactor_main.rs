@@ -9,14 +9,12 @@ use common_interactor::{
 };
 use common_test_setup::base_setup::init::ExpectedLogs;
 use common_test_setup::constants::{
-    DEPOSIT_EVENT, INTERACTOR_WORKING_DIR, MULTI_ESDT_NFT_TRANSFER_EVENT, SHARD_1,
-    SOVEREIGN_RECEIVER_ADDRESS, TOKEN_DISPLAY_NAME, TOKEN_TICKER,
+    INTERACTOR_WORKING_DIR, SOVEREIGN_RECEIVER_ADDRESS, TOKEN_DISPLAY_NAME, TOKEN_TICKER,
 };
 use common_test_setup::log;
 use cross_chain::DEFAULT_ISSUE_COST;
 use error_messages::EXPECTED_MAPPED_TOKEN;
 use multiversx_sc::api::{ESDT_LOCAL_MINT_FUNC_NAME, ESDT_NFT_CREATE_FUNC_NAME};
-use multiversx_sc::chain_core::EGLD_000000_TOKEN_IDENTIFIER;
 use multiversx_sc_snippets::imports::*;
 use multiversx_sc_snippets::multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::sha256;
 use structs::fee::FeeStruct;
@@ -261,26 +259,8 @@ impl CompleteFlowInteract {
         token: EsdtTokenInfo,
         expected_logs: Vec<ExpectedLogs<'static>>,
     ) -> EsdtTokenInfo {
-        let expected_deposit_logs = if config.shard == SHARD_1 {
-            vec![
-                log!(MULTI_ESDT_NFT_TRANSFER_EVENT, topics: [EGLD_000000_TOKEN_IDENTIFIER]),
-                log!(DEPOSIT_EVENT, topics: [DEPOSIT_EVENT]),
-            ]
-        } else {
-            vec![]
-        };
-
-        self.deposit_in_mvx_esdt_safe(
-            SOVEREIGN_RECEIVER_ADDRESS.to_address(),
-            config.shard,
-            OptionalValue::None,
-            ManagedVec::from_single_item(EgldOrEsdtTokenPayment::egld_payment(
-                DEFAULT_ISSUE_COST.into(),
-            )),
-            None,
-            Some(expected_deposit_logs),
-        )
-        .await;
+        self.fund_issue_pool_in_mvx_esdt_safe(config.shard, DEFAULT_ISSUE_COST.into())
+            .await;
 
         let token_id = self
             .register_sovereign_token(config.shard, token.clone())
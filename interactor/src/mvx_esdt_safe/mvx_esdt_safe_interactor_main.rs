@@ -124,7 +124,7 @@ impl MvxEsdtSafeInteract {
             .from(caller)
             .gas(90_000_000u64)
             .typed(MvxEsdtSafeProxy)
-            .upgrade()
+            .upgrade(MultiValueEncoded::new())
             .code(MVX_ESDT_SAFE_CODE_PATH)
             .code_metadata(CodeMetadata::UPGRADEABLE)
             .returns(ReturnsResultUnmanaged)
@@ -202,7 +202,7 @@ impl MvxEsdtSafeInteract {
             .query()
             .to(mvx_esdt_safe_address)
             .typed(MvxEsdtSafeProxy)
-            .deposited_tokens_amount(token_id)
+            .get_deposited_tokens_amount(token_id, 0u64)
             .returns(ReturnsResult)
             .run()
             .await;
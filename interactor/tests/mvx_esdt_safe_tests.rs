@@ -317,6 +317,86 @@ async fn test_deposit_endpoint_banned_no_fee() {
         .await;
 }
 
+/// ### TEST
+/// M-ESDT_DEP_OK
+///
+/// ### ACTION
+/// Call 'deposit()' with an empty config, then again after filling the token blacklist, banned endpoints and address blacklist
+///
+/// ### EXPECTED
+/// The gas used by both deposits stays within 1%, since the checks no longer scan the configured lists
+#[tokio::test]
+#[serial]
+#[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
+async fn test_deposit_gas_independent_of_config_list_sizes() {
+    let mut chain_interactor = MvxEsdtSafeInteract::new(Config::chain_simulator_config()).await;
+
+    chain_interactor.remove_fee_wrapper(SHARD_1).await;
+
+    let first_token_id = chain_interactor.state.get_first_fungible_token_id();
+    let payments_vec = PaymentsVec::from(vec![EgldOrEsdtTokenPayment::<StaticApi>::new(
+        chain_interactor.state.get_first_fungible_token_identifier(),
+        0,
+        BigUint::from(TEN_TOKENS),
+    )]);
+    let balance_config = BalanceCheckConfig::new()
+        .shard(SHARD_1)
+        .token(Some(first_token_id))
+        .amount(TEN_TOKENS.into());
+
+    let gas_used_with_empty_lists = chain_interactor
+        .deposit_in_mvx_esdt_safe_and_get_gas_used(
+            SOVEREIGN_RECEIVER_ADDRESS.to_address(),
+            SHARD_1,
+            payments_vec.clone(),
+        )
+        .await;
+    chain_interactor
+        .check_balances_after_action(balance_config.clone())
+        .await;
+
+    let list_size = 50;
+    let config = EsdtSafeConfig {
+        token_blacklist: (0..list_size)
+            .map(|index| EgldOrEsdtTokenIdentifier::esdt(format!("BLK{index:04}-000000").as_str()))
+            .collect(),
+        banned_endpoints: (0..list_size)
+            .map(|index| ManagedBuffer::from(format!("bannedEndpoint{index}").as_str()))
+            .collect(),
+        address_blacklist: (0..list_size)
+            .map(|index| {
+                let mut address_bytes = [0u8; 32];
+                address_bytes[..4].copy_from_slice(&(index as u32).to_be_bytes());
+                ManagedAddress::new_from_bytes(&address_bytes)
+            })
+            .collect(),
+        ..EsdtSafeConfig::default_config()
+    };
+    chain_interactor
+        .update_configuration_after_setup_phase(SHARD_1, config, None)
+        .await;
+
+    let gas_used_with_full_lists = chain_interactor
+        .deposit_in_mvx_esdt_safe_and_get_gas_used(
+            SOVEREIGN_RECEIVER_ADDRESS.to_address(),
+            SHARD_1,
+            payments_vec,
+        )
+        .await;
+    chain_interactor
+        .check_balances_after_action(balance_config)
+        .await;
+
+    assert!(
+        gas_used_with_full_lists <= gas_used_with_empty_lists + gas_used_with_empty_lists / 100,
+        "deposit gas used: {gas_used_with_empty_lists} with empty lists, {gas_used_with_full_lists} with {list_size} entries per list"
+    );
+
+    chain_interactor
+        .update_configuration_after_setup_phase(SHARD_1, EsdtSafeConfig::default_config(), None)
+        .await;
+}
+
 /// ### TEST
 /// M-ESDT_DEP_OK
 ///
@@ -1,6 +1,6 @@
 use error_messages::{
-    BURN_MECHANISM_NON_ESDT_TOKENS, ESDT_SAFE_STILL_PAUSED, LOCK_MECHANISM_NON_ESDT,
-    MINT_AND_BURN_ROLES_NOT_FOUND, SETUP_PHASE_ALREADY_COMPLETED,
+    BURN_MECHANISM_NON_ESDT_TOKENS, DEPOSIT_AMOUNT_NOT_ENOUGH, ESDT_SAFE_STILL_PAUSED,
+    LOCK_MECHANISM_NON_ESDT, MINT_AND_BURN_ROLES_NOT_FOUND, SETUP_PHASE_ALREADY_COMPLETED,
     TOKEN_ALREADY_REGISTERED_WITH_BURN_MECHANISM, TOKEN_ID_IS_NOT_TRUSTED,
     TOKEN_NOT_REGISTERED_WITH_BURN_MECHANISM,
 };
@@ -9,6 +9,7 @@ use multiversx_sc_modules::pause;
 use structs::{
     configs::{SetBurnMechanismOperation, SetLockMechanismOperation},
     generate_hash::GenerateHash,
+    TokenMechanism,
 };
 
 #[multiversx_sc::module]
@@ -33,11 +34,8 @@ pub trait BridgingMechanism:
             TOKEN_ALREADY_REGISTERED_WITH_BURN_MECHANISM
         );
         let esdt_identifier = token_id.clone().unwrap_esdt();
-        let token_esdt_roles = self.blockchain().get_esdt_local_roles(&esdt_identifier);
-
         require!(
-            token_esdt_roles.contains(EsdtLocalRoleFlags::MINT)
-                && token_esdt_roles.contains(EsdtLocalRoleFlags::BURN),
+            self.has_burn_mechanism_roles(&esdt_identifier),
             MINT_AND_BURN_ROLES_NOT_FOUND
         );
 
@@ -49,6 +47,7 @@ pub trait BridgingMechanism:
         );
 
         burn_mechanism_tokens_mapper.insert(token_id.clone());
+        self.update_token_registry_mechanism(&token_id, TokenMechanism::Burn);
         let sc_balance = self
             .blockchain()
             .get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(esdt_identifier.clone()), 0);
@@ -60,7 +59,7 @@ pub trait BridgingMechanism:
                 .esdt_local_burn(&esdt_identifier, 0, &sc_balance)
                 .sync_call();
 
-            self.deposited_tokens_amount(&token_id).set(sc_balance);
+            self.deposited_tokens_amount(&token_id, 0).set(sc_balance);
         }
     }
 
@@ -107,11 +106,7 @@ pub trait BridgingMechanism:
         }
 
         let esdt_identifier = set_burn_mechanism_operation.token_id.clone().unwrap_esdt();
-        let token_esdt_roles = self.blockchain().get_esdt_local_roles(&esdt_identifier);
-
-        if !(token_esdt_roles.contains(EsdtLocalRoleFlags::MINT)
-            && token_esdt_roles.contains(EsdtLocalRoleFlags::BURN))
-        {
+        if !self.has_burn_mechanism_roles(&esdt_identifier) {
             self.complete_operation(
                 &hash_of_hashes,
                 &operation_hash,
@@ -135,6 +130,10 @@ pub trait BridgingMechanism:
         }
 
         burn_mechanism_tokens_mapper.insert(set_burn_mechanism_operation.token_id.clone());
+        self.update_token_registry_mechanism(
+            &set_burn_mechanism_operation.token_id,
+            TokenMechanism::Burn,
+        );
         let sc_balance = self
             .blockchain()
             .get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(esdt_identifier.clone()), 0);
@@ -149,7 +148,7 @@ pub trait BridgingMechanism:
             return;
         }
 
-        self.deposited_tokens_amount(&set_burn_mechanism_operation.token_id)
+        self.deposited_tokens_amount(&set_burn_mechanism_operation.token_id, 0)
             .set(sc_balance);
         self.complete_operation(&hash_of_hashes, &operation_hash, None);
     }
@@ -168,8 +167,9 @@ pub trait BridgingMechanism:
         require!(token_id.is_esdt(), LOCK_MECHANISM_NON_ESDT);
 
         self.burn_mechanism_tokens().swap_remove(&token_id);
+        self.update_token_registry_mechanism(&token_id, TokenMechanism::Lock);
 
-        let deposited_amount = self.deposited_tokens_amount(&token_id).get();
+        let deposited_amount = self.deposited_tokens_amount(&token_id, 0).get();
 
         if deposited_amount != 0 {
             self.tx()
@@ -178,7 +178,8 @@ pub trait BridgingMechanism:
                 .esdt_local_mint(token_id.clone().unwrap_esdt(), 0, &deposited_amount)
                 .sync_call();
 
-            self.deposited_tokens_amount(&token_id).set(BigUint::zero());
+            self.deposited_tokens_amount(&token_id, 0)
+                .set(BigUint::zero());
         }
     }
 
@@ -225,9 +226,13 @@ pub trait BridgingMechanism:
         }
 
         burn_mechanism_tokens_mapper.swap_remove(&set_lock_mechanism_operation.token_id);
+        self.update_token_registry_mechanism(
+            &set_lock_mechanism_operation.token_id,
+            TokenMechanism::Lock,
+        );
 
         let deposited_amount = self
-            .deposited_tokens_amount(&set_lock_mechanism_operation.token_id)
+            .deposited_tokens_amount(&set_lock_mechanism_operation.token_id, 0)
             .get();
 
         if deposited_amount == 0 {
@@ -242,11 +247,136 @@ pub trait BridgingMechanism:
             return;
         }
 
-        self.deposited_tokens_amount(&set_lock_mechanism_operation.token_id)
+        self.deposited_tokens_amount(&set_lock_mechanism_operation.token_id, 0)
             .set(BigUint::zero());
         self.complete_operation(&hash_of_hashes, &operation_hash, None);
     }
 
+    fn update_token_registry_mechanism(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        mechanism: TokenMechanism,
+    ) {
+        let registry_entry_mapper = self.token_registry_entry(token_id);
+        if !registry_entry_mapper.is_empty() {
+            registry_entry_mapper.update(|entry| entry.mechanism = mechanism);
+        }
+    }
+
+    #[inline]
+    fn is_burn_mechanism_set(&self, token_id: &EgldOrEsdtTokenIdentifier<Self::Api>) -> bool {
+        self.burn_mechanism_tokens().contains(token_id)
+    }
+
+    /// NFT collections can never hold the add quantity role, so they are left out of the burn mechanism.
+    fn has_burn_mechanism_roles(&self, token_id: &EsdtTokenIdentifier<Self::Api>) -> bool {
+        let token_esdt_roles = self.blockchain().get_esdt_local_roles(token_id);
+
+        (token_esdt_roles.contains(EsdtLocalRoleFlags::MINT)
+            && token_esdt_roles.contains(EsdtLocalRoleFlags::BURN))
+            || (token_esdt_roles.contains(EsdtLocalRoleFlags::NFT_ADD_QUANTITY)
+                && token_esdt_roles.contains(EsdtLocalRoleFlags::NFT_BURN))
+    }
+
+    /// Nonces held by the contract can not be enumerated when the mechanism is switched,
+    /// so their balances are converted the first time they are bridged back.
+    fn settle_mechanism_switch_for_nonce(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+    ) -> Result<(), ManagedBuffer> {
+        if token_nonce == 0 || !token_id.is_esdt() {
+            return Ok(());
+        }
+
+        if self.is_burn_mechanism_set(token_id) {
+            let mut unconverted_amount = self.blockchain().get_sc_balance(token_id, token_nonce);
+            if self.kept_nonce_unit(token_id, token_nonce).get() {
+                unconverted_amount -= 1u32;
+            }
+            if unconverted_amount == 0 {
+                return Ok(());
+            }
+
+            self.burn_keeping_nonce_unit(token_id, token_nonce, &unconverted_amount)?;
+        } else {
+            // Under the lock mechanism the kept unit is escrowed like any other unit.
+            self.kept_nonce_unit(token_id, token_nonce).clear();
+
+            let deposited_mapper = self.deposited_tokens_amount(token_id, token_nonce);
+            let deposited_amount = deposited_mapper.get();
+            if deposited_amount == 0 {
+                return Ok(());
+            }
+
+            self.try_esdt_local_mint(
+                &token_id.clone().unwrap_esdt(),
+                token_nonce,
+                &deposited_amount,
+            )?;
+            deposited_mapper.clear();
+        }
+
+        Ok(())
+    }
+
+    /// `ESDTNFTAddQuantity` fails once the contract no longer holds a nonce, so a single unit of
+    /// each nonce is kept instead of burned. The deposited amount only counts burned units, the
+    /// kept unit is tracked by `keptNonceUnit`.
+    fn burn_keeping_nonce_unit(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+        amount: &BigUint,
+    ) -> Result<(), ManagedBuffer> {
+        let mut burn_amount = amount.clone();
+        let kept_unit_mapper = self.kept_nonce_unit(token_id, token_nonce);
+        if token_nonce != 0 && !kept_unit_mapper.get() {
+            kept_unit_mapper.set(true);
+            burn_amount -= 1u32;
+        }
+
+        if burn_amount > 0 {
+            self.try_esdt_local_burn(&token_id.clone().unwrap_esdt(), token_nonce, &burn_amount)?;
+        }
+        self.deposited_tokens_amount(token_id, token_nonce)
+            .update(|deposited| *deposited += burn_amount);
+
+        Ok(())
+    }
+
+    /// Mints `amount` back out of the deposited amount. Only bridging back the last unit of a
+    /// nonce releases the kept unit, the next deposit of the nonce keeps a new one.
+    fn mint_releasing_nonce_unit(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+        amount: &BigUint,
+    ) -> Result<(), ManagedBuffer> {
+        let deposited_mapper = self.deposited_tokens_amount(token_id, token_nonce);
+        let kept_unit_mapper = self.kept_nonce_unit(token_id, token_nonce);
+        let deposited_amount = deposited_mapper.get();
+
+        let mut mint_amount = amount.clone();
+        let releases_kept_unit = mint_amount > deposited_amount;
+        if releases_kept_unit {
+            if !kept_unit_mapper.get() || mint_amount != &deposited_amount + 1u32 {
+                return Err(DEPOSIT_AMOUNT_NOT_ENOUGH.into());
+            }
+            mint_amount -= 1u32;
+        }
+
+        if mint_amount > 0 {
+            self.try_esdt_local_mint(&token_id.clone().unwrap_esdt(), token_nonce, &mint_amount)?;
+        }
+        deposited_mapper.update(|deposited| *deposited -= mint_amount);
+        if releases_kept_unit {
+            kept_unit_mapper.clear();
+        }
+
+        Ok(())
+    }
+
     #[storage_mapper_from_address("trustedTokens")]
     fn trusted_tokens(
         &self,
@@ -259,10 +389,47 @@ pub trait BridgingMechanism:
     #[storage_mapper("burnMechanismTokens")]
     fn burn_mechanism_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
 
+    /// Fungible amounts keep the storage key they had before nonce-based tokens could use the
+    /// burn mechanism, so upgraded safes do not lose them.
+    fn deposited_tokens_amount(
+        &self,
+        token_identifier: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+    ) -> SingleValueMapper<BigUint> {
+        if token_nonce == 0 {
+            return self.fungible_deposited_tokens_amount(token_identifier);
+        }
+
+        self.nonce_deposited_tokens_amount(token_identifier, token_nonce)
+    }
+
     #[view(getDepositedTokensAmount)]
+    fn get_deposited_tokens_amount(
+        &self,
+        token_identifier: EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+    ) -> BigUint {
+        self.deposited_tokens_amount(&token_identifier, token_nonce)
+            .get()
+    }
+
     #[storage_mapper("depositedTokensAmount")]
-    fn deposited_tokens_amount(
+    fn fungible_deposited_tokens_amount(
         &self,
         token_identifier: &EgldOrEsdtTokenIdentifier<Self::Api>,
     ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("depositedNonceTokensAmount")]
+    fn nonce_deposited_tokens_amount(
+        &self,
+        token_identifier: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("keptNonceUnit")]
+    fn kept_nonce_unit(
+        &self,
+        token_identifier: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+    ) -> SingleValueMapper<bool>;
 }
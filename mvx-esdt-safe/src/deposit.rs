@@ -1,9 +1,18 @@
+use error_messages::{SAFE_ALREADY_MIGRATED, TOKEN_FROZEN};
 multiversx_sc::imports!();
-use structs::aliases::{EventPaymentTuple, OptionalValueTransferDataTuple};
+use proxies::mvx_fee_market_proxy::MvxFeeMarketProxy;
+use structs::{
+    aliases::{EventPaymentTuple, GasLimit, OptionalValueTransferDataTuple, PaymentsVec},
+    fee::FeeQuote,
+    operation::MultiDepositEntry,
+};
 
 #[multiversx_sc::module]
 pub trait DepositModule:
     crate::bridging_mechanism::BridgingMechanism
+    + crate::token_freeze::TokenFreezeModule
+    + crate::reserves::ReservesModule
+    + crate::token_registry::TokenRegistryModule
     + common_utils::CommonUtilsModule
     + setup_phase::SetupPhaseModule
     + cross_chain::deposit_common::DepositCommonModule
@@ -20,16 +29,79 @@ pub trait DepositModule:
         opt_transfer_data: OptionalValueTransferDataTuple<Self::Api>,
     ) {
         self.require_setup_complete();
-        self.deposit_common(to, opt_transfer_data, |payment| {
+        self.require_safe_not_migrated();
+        self.deposit_common(to, opt_transfer_data, None, |payment| {
             self.process_payment(payment)
         });
     }
 
+    #[payable]
+    #[endpoint(depositWithRefundAddress)]
+    fn deposit_with_refund_address(
+        &self,
+        to: ManagedAddress,
+        refund_address: ManagedAddress,
+        opt_transfer_data: OptionalValueTransferDataTuple<Self::Api>,
+    ) {
+        self.require_setup_complete();
+        self.require_safe_not_migrated();
+        self.deposit_common(to, opt_transfer_data, Some(refund_address), |payment| {
+            self.process_payment(payment)
+        });
+    }
+
+    #[payable]
+    #[endpoint(depositMulti)]
+    fn deposit_multi(&self, entries: MultiValueEncoded<MultiDepositEntry<Self::Api>>) {
+        self.require_setup_complete();
+        self.require_safe_not_migrated();
+        self.deposit_multi_common(entries, None, |payment| self.process_payment(payment));
+    }
+
+    #[payable]
+    #[endpoint(depositMultiWithRefundAddress)]
+    fn deposit_multi_with_refund_address(
+        &self,
+        refund_address: ManagedAddress,
+        entries: MultiValueEncoded<MultiDepositEntry<Self::Api>>,
+    ) {
+        self.require_setup_complete();
+        self.require_safe_not_migrated();
+        self.deposit_multi_common(entries, Some(refund_address), |payment| {
+            self.process_payment(payment)
+        });
+    }
+
+    /// Quotes the fee of a deposit in `fee_token`. Takes the `transfers` to bridge, without the
+    /// fee payment, instead of their count, since percentage and exempt token fees depend on
+    /// the amounts and tokens.
+    #[view(getFeeQuote)]
+    fn get_fee_quote(
+        &self,
+        fee_token: EgldOrEsdtTokenIdentifier,
+        transfers: PaymentsVec<Self::Api>,
+        gas_limit: GasLimit,
+        opt_caller: OptionalValue<ManagedAddress>,
+    ) -> FeeQuote<Self::Api> {
+        self.tx()
+            .to(self.fee_market_address().get())
+            .typed(MvxFeeMarketProxy)
+            .get_fee_quote(fee_token, transfers, gas_limit, opt_caller)
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+    }
+
+    fn require_safe_not_migrated(&self) {
+        require!(!self.safe_migrated().get(), SAFE_ALREADY_MIGRATED);
+    }
+
     fn process_payment(
         &self,
         payment: &EgldOrEsdtTokenPayment<Self::Api>,
     ) -> EventPaymentTuple<Self::Api> {
         let token_identifier = payment.token_identifier.clone();
+        require!(!self.is_token_frozen(&token_identifier), TOKEN_FROZEN);
+
         let mut token_data = if token_identifier.is_egld() {
             EsdtTokenData::default()
         } else {
@@ -42,6 +114,7 @@ pub trait DepositModule:
         };
 
         token_data.amount = payment.amount.clone();
+        self.track_deposited_amount(&token_identifier, payment.token_nonce, &payment.amount);
 
         let token_mapper = self.multiversx_to_sovereign_token_id_mapper(&token_identifier);
         if !token_mapper.is_empty() || self.is_native_token(&token_identifier) {
@@ -59,18 +132,16 @@ pub trait DepositModule:
             );
             MultiValue3::from((sov_token_id.clone(), sov_token_nonce, token_data))
         } else {
-            if self.is_fungible(&token_data.token_type)
-                && self.burn_mechanism_tokens().contains(&token_identifier)
-            {
-                let esdt_id = token_identifier.clone().unwrap_esdt();
-                self.tx()
-                    .to(ToSelf)
-                    .typed(UserBuiltinProxy)
-                    .esdt_local_burn(esdt_id, payment.token_nonce, payment.amount.clone())
-                    .sync_call();
+            self.add_deposited_token_registry_entry(&token_identifier, token_data.token_type);
 
-                self.deposited_tokens_amount(&token_identifier)
-                    .update(|amount| *amount += payment.amount.clone());
+            if self.is_burn_mechanism_set(&token_identifier) {
+                if let Err(error_message) = self.burn_keeping_nonce_unit(
+                    &token_identifier,
+                    payment.token_nonce,
+                    &payment.amount,
+                ) {
+                    sc_panic!(error_message);
+                }
             }
 
             MultiValue3::from((token_identifier.clone(), payment.token_nonce, token_data))
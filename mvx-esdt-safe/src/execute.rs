@@ -1,9 +1,10 @@
 use cross_chain::MAX_GAS_PER_TRANSACTION;
 use error_messages::{
-    CREATE_ESDT_FAILED, DEPOSIT_AMOUNT_NOT_ENOUGH, ESDT_SAFE_STILL_PAUSED, GAS_LIMIT_TOO_HIGH,
-    NOTHING_TO_TRANSFER, TOKEN_NOT_REGISTERED,
+    CREATE_ESDT_FAILED, ESDT_SAFE_STILL_PAUSED, GAS_LIMIT_TOO_HIGH, NOTHING_TO_TRANSFER,
+    SAFE_ALREADY_MIGRATED, SC_CALL_TARGET_NOT_ALLOWED, TOKEN_FROZEN, TOKEN_NOT_REGISTERED,
 };
 use multiversx_sc_modules::only_admin;
+use proxies::mvx_esdt_safe_proxy::MvxEsdtSafeProxy;
 use structs::{
     aliases::GasLimit,
     generate_hash::GenerateHash,
@@ -13,11 +14,18 @@ use structs::{
 multiversx_sc::imports!();
 const CALLBACK_GAS: GasLimit = 10_000_000; // Increase if not enough
 const ESDT_TRANSACTION_GAS: GasLimit = 5_000_000;
+const ROUTED_DEPOSIT_GAS: GasLimit = 30_000_000; // Increase if not enough
 
 #[multiversx_sc::module]
 pub trait ExecuteModule:
     crate::bridging_mechanism::BridgingMechanism
+    + crate::token_freeze::TokenFreezeModule
+    + crate::inbound_policy::InboundPolicyModule
+    + crate::reserves::ReservesModule
     + crate::register_token::RegisterTokenModule
+    + crate::token_registry::TokenRegistryModule
+    + crate::issue_pool::IssuePoolModule
+    + crate::routing::RoutingModule
     + common_utils::CommonUtilsModule
     + setup_phase::SetupPhaseModule
     + custom_events::CustomEventsModule
@@ -38,19 +46,33 @@ pub trait ExecuteModule:
             self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
             return;
         }
-        if self.is_paused() {
-            let refund_result = self.refund_transfers(&operation.tokens, &operation);
-            self.complete_operation(
-                &hash_of_hashes,
-                &operation_hash,
-                Some(self.merge_error_if_any(ESDT_SAFE_STILL_PAUSED.into(), refund_result)),
-            );
+        if let Err(err_msg) = self.check_operation_allowed(&operation) {
+            self.refund_unprocessed_operation(&operation);
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(err_msg));
             return;
         }
 
+        let opt_destination_safe = match operation.data.opt_destination_chain_id.as_ref() {
+            Some(destination_chain_id) => {
+                match self.get_destination_esdt_safe(destination_chain_id) {
+                    Ok(destination_safe) => Some(destination_safe),
+                    Err(err_msg) => {
+                        self.refund_unprocessed_operation(&operation);
+                        self.complete_operation(&hash_of_hashes, &operation_hash, Some(err_msg));
+                        return;
+                    }
+                }
+            }
+            None => None,
+        };
+
         if operation.tokens.is_empty() {
-            if let Err(err_msg) = self.execute_sc_call(&hash_of_hashes, &operation_hash, &operation)
-            {
+            if let Err(err_msg) = self.execute_sc_call(
+                &hash_of_hashes,
+                &operation_hash,
+                &operation,
+                &opt_destination_safe,
+            ) {
                 self.complete_operation(&hash_of_hashes, &operation_hash, Some(err_msg));
             }
             return;
@@ -69,6 +91,7 @@ pub trait ExecuteModule:
             &operation_hash,
             &operation,
             &minted_operation_tokens,
+            &opt_destination_safe,
         ) {
             let refund_result = self.refund_transfers(&minted_operation_tokens, &operation);
             self.complete_operation(
@@ -80,10 +103,34 @@ pub trait ExecuteModule:
         }
     }
 
+    fn check_operation_allowed(
+        &self,
+        operation: &Operation<Self::Api>,
+    ) -> Result<(), ManagedBuffer> {
+        if self.safe_migrated().get() {
+            return Err(SAFE_ALREADY_MIGRATED.into());
+        }
+        if self.is_paused() {
+            return Err(ESDT_SAFE_STILL_PAUSED.into());
+        }
+        if self.has_frozen_token(operation) {
+            return Err(TOKEN_FROZEN.into());
+        }
+
+        self.check_inbound_policy(operation)
+    }
+
     fn process_operation_payments(
         &self,
         operation: &Operation<Self::Api>,
     ) -> Result<ManagedVec<OperationEsdtPayment<Self::Api>>, ManagedBuffer> {
+        for operation_token in operation.tokens.iter() {
+            self.settle_mechanism_switch_for_nonce(
+                &operation_token.token_identifier,
+                operation_token.token_nonce,
+            )?;
+        }
+
         let mut output_payments = ManagedVec::new();
         for operation_token in operation.tokens.iter() {
             let processing_result = match self.get_mvx_token_id(&operation_token) {
@@ -97,7 +144,14 @@ pub trait ExecuteModule:
                 Err(err_msg) => return Err(err_msg),
             };
             match processing_result {
-                Ok(payment) => output_payments.push(payment),
+                Ok(payment) => {
+                    self.track_executed_amount(
+                        &payment.token_identifier,
+                        payment.token_nonce,
+                        &payment.token_data.amount,
+                    );
+                    output_payments.push(payment);
+                }
                 Err(err_msg) => {
                     let refund_result = self.refund_transfers(&output_payments, operation);
                     return Err(self.merge_error_if_any(err_msg, refund_result));
@@ -135,20 +189,12 @@ pub trait ExecuteModule:
         &self,
         operation_token: &OperationEsdtPayment<Self::Api>,
     ) -> Result<OperationEsdtPayment<Self::Api>, ManagedBuffer> {
-        if self.is_burn_mechanism_set(operation_token) {
-            let deposited_mapper = self.deposited_tokens_amount(&operation_token.token_identifier);
-            let deposited_amount = deposited_mapper.get();
-
-            if operation_token.token_data.amount > deposited_amount {
-                return Err(DEPOSIT_AMOUNT_NOT_ENOUGH.into());
-            }
-
-            self.try_esdt_local_mint(
-                &operation_token.token_identifier.clone().unwrap_esdt(),
-                0,
+        if self.is_burn_mechanism_set(&operation_token.token_identifier) {
+            self.mint_releasing_nonce_unit(
+                &operation_token.token_identifier,
+                operation_token.token_nonce,
                 &operation_token.token_data.amount,
             )?;
-            deposited_mapper.update(|amount| *amount -= operation_token.token_data.amount.clone());
         }
 
         Ok(operation_token.clone())
@@ -230,6 +276,7 @@ pub trait ExecuteModule:
         operation_hash: &ManagedBuffer,
         operation: &Operation<Self::Api>,
         output_payments: &ManagedVec<OperationEsdtPayment<Self::Api>>,
+        opt_destination_safe: &Option<ManagedAddress>,
     ) -> Result<(), ManagedBuffer> {
         let payment_tokens: ManagedVec<Self::Api, EgldOrEsdtTokenPayment<Self::Api>> =
             output_payments
@@ -237,9 +284,21 @@ pub trait ExecuteModule:
                 .map(|token| token.clone().into())
                 .collect();
 
+        if let Some(destination_safe) = opt_destination_safe {
+            return self.route_to_destination_safe(
+                hash_of_hashes,
+                operation_hash,
+                operation,
+                destination_safe,
+                output_payments,
+                payment_tokens,
+            );
+        }
+
         match &operation.data.opt_transfer_data {
             Some(transfer_data) => {
                 self.validate_transfer_data(transfer_data)?;
+                self.validate_sc_call_target(&operation.to, transfer_data)?;
                 let args = ManagedArgBuffer::from(transfer_data.args.clone());
 
                 self.tx()
@@ -281,13 +340,26 @@ pub trait ExecuteModule:
         hash_of_hashes: &ManagedBuffer,
         operation_hash: &ManagedBuffer,
         operation: &Operation<Self::Api>,
+        opt_destination_safe: &Option<ManagedAddress>,
     ) -> Result<(), ManagedBuffer> {
+        if let Some(destination_safe) = opt_destination_safe {
+            return self.route_to_destination_safe(
+                hash_of_hashes,
+                operation_hash,
+                operation,
+                destination_safe,
+                &ManagedVec::new(),
+                ManagedVec::new(),
+            );
+        }
+
         let transfer_data = match operation.data.opt_transfer_data.as_ref() {
             Some(data) => data,
             None => return Err(NOTHING_TO_TRANSFER.into()),
         };
 
         self.validate_transfer_data(transfer_data)?;
+        self.validate_sc_call_target(&operation.to, transfer_data)?;
 
         let args = ManagedArgBuffer::from(transfer_data.args.clone());
 
@@ -308,6 +380,64 @@ pub trait ExecuteModule:
         Ok(())
     }
 
+    /// Deposits the processed payments into the destination sovereign's safe on behalf of
+    /// the receiver, keeping the operation's refund address. The destination safe applies its
+    /// own mapping and fee rules, its fee is taken out of the routed payments.
+    fn route_to_destination_safe(
+        &self,
+        hash_of_hashes: &ManagedBuffer,
+        operation_hash: &ManagedBuffer,
+        operation: &Operation<Self::Api>,
+        destination_safe: &ManagedAddress,
+        output_payments: &ManagedVec<OperationEsdtPayment<Self::Api>>,
+        payment_tokens: ManagedVec<Self::Api, EgldOrEsdtTokenPayment<Self::Api>>,
+    ) -> Result<(), ManagedBuffer> {
+        let gas_limit = operation
+            .data
+            .opt_transfer_data
+            .as_ref()
+            .map_or(0, |transfer_data| transfer_data.gas_limit);
+        let payment_tokens = self.add_destination_fee_payment(
+            destination_safe,
+            &operation.to,
+            payment_tokens,
+            gas_limit,
+        )?;
+
+        let opt_transfer_data = match &operation.data.opt_transfer_data {
+            Some(transfer_data) => {
+                self.validate_transfer_data(transfer_data)?;
+                OptionalValue::Some(MultiValue3::from((
+                    transfer_data.gas_limit,
+                    transfer_data.function.clone(),
+                    MultiValueEncoded::from(transfer_data.args.clone()),
+                )))
+            }
+            None => OptionalValue::None,
+        };
+
+        self.tx()
+            .to(destination_safe)
+            .typed(MvxEsdtSafeProxy)
+            .deposit_with_refund_address(
+                &operation.to,
+                operation.data.refund_address(),
+                opt_transfer_data,
+            )
+            .payment(payment_tokens)
+            .gas(ROUTED_DEPOSIT_GAS)
+            .callback(<Self as ExecuteModule>::callbacks(self).execute(
+                hash_of_hashes,
+                operation_hash,
+                operation,
+                output_payments,
+            ))
+            .gas_for_callback(CALLBACK_GAS)
+            .register_promise();
+
+        Ok(())
+    }
+
     #[promises_callback]
     fn execute(
         &self,
@@ -342,33 +472,61 @@ pub trait ExecuteModule:
         let mut burn_errors = ManagedVec::new();
 
         for i in 0..output_payments.len() {
-            match self.burn_failed_transfer_token(&output_payments.get(i), &operation.tokens.get(i))
-            {
-                Ok(_) => {
-                    if self.is_burn_mechanism_set(&output_payments.get(i)) {
-                        self.deposited_tokens_amount(&output_payments.get(i).token_identifier)
-                            .update(|amount| {
-                                *amount += output_payments.get(i).token_data.amount.clone()
-                            });
-                    }
-                }
-                Err(err_msg) => {
-                    burn_errors.push(err_msg);
-                }
+            let output_payment = output_payments.get(i);
+            self.track_refunded_amount(
+                &output_payment.token_identifier,
+                output_payment.token_nonce,
+                &output_payment.token_data.amount,
+            );
+            let burn_result = if self.is_burn_mechanism_set(&output_payment.token_identifier) {
+                self.burn_refunded_burn_mechanism_token(&output_payment)
+            } else {
+                self.burn_failed_transfer_token(&output_payment, &operation.tokens.get(i))
+            };
+
+            if let Err(err_msg) = burn_result {
+                burn_errors.push(err_msg);
             }
         }
 
+        self.emit_refund_event(operation);
+
+        if !burn_errors.is_empty() {
+            return Err(self.combine_error_messages(&burn_errors));
+        }
+        Ok(())
+    }
+
+    /// Nothing was minted for an operation rejected before processing its payments,
+    /// so the tokens are only sent back to the sender.
+    fn refund_unprocessed_operation(&self, operation: &Operation<Self::Api>) {
+        if operation.tokens.is_empty() {
+            return;
+        }
+
+        self.emit_refund_event(operation);
+    }
+
+    fn emit_refund_event(&self, operation: &Operation<Self::Api>) {
         let sc_address = self.blockchain().get_sc_address();
         let tx_nonce = self.get_current_and_increment_tx_nonce();
         self.deposit_event(
-            &operation.data.op_sender,
+            operation.data.refund_address(),
             &operation.map_tokens_to_multi_value_encoded(),
-            OperationData::new(tx_nonce, sc_address.clone(), None),
+            OperationData::new(tx_nonce, sc_address, None),
         );
+    }
+
+    fn burn_refunded_burn_mechanism_token(
+        &self,
+        output_payment: &OperationEsdtPayment<Self::Api>,
+    ) -> Result<(), ManagedBuffer> {
+        self.burn_keeping_nonce_unit(
+            &output_payment.token_identifier,
+            output_payment.token_nonce,
+            &output_payment.token_data.amount,
+        )?;
 
-        if !burn_errors.is_empty() {
-            return Err(self.combine_error_messages(&burn_errors));
-        }
         Ok(())
     }
 
@@ -450,14 +608,6 @@ pub trait ExecuteModule:
         esdt_info_mapper.get().token_nonce
     }
 
-    #[inline]
-    fn is_burn_mechanism_set(&self, operation_token: &OperationEsdtPayment<Self::Api>) -> bool {
-        self.is_fungible(&operation_token.token_data.token_type)
-            && self
-                .burn_mechanism_tokens()
-                .contains(&operation_token.token_identifier)
-    }
-
     fn validate_transfer_data(
         &self,
         transfer_data: &TransferData<Self::Api>,
@@ -468,4 +618,16 @@ pub trait ExecuteModule:
 
         Ok(())
     }
+
+    fn validate_sc_call_target(
+        &self,
+        to: &ManagedAddress,
+        transfer_data: &TransferData<Self::Api>,
+    ) -> Result<(), ManagedBuffer> {
+        if !self.is_sc_call_target_allowed(to, &transfer_data.function) {
+            return Err(SC_CALL_TARGET_NOT_ALLOWED.into());
+        }
+
+        Ok(())
+    }
 }
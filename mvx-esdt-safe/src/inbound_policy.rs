@@ -0,0 +1,167 @@
+use error_messages::{
+    EXECUTION_OVER_MAX_AMOUNT, INBOUND_TOKEN_BLACKLISTED, RECEIVER_IS_BLACKLISTED,
+    SETUP_PHASE_ALREADY_COMPLETED, SETUP_PHASE_NOT_COMPLETED,
+};
+use multiversx_sc::imports::*;
+use structs::{
+    configs::{InboundPolicy, MaxBridgedAmount, SetInboundPolicyOperation},
+    generate_hash::GenerateHash,
+    operation::Operation,
+};
+
+/// Inbound executions follow the outbound `EsdtSafeConfig` lists unless a separate policy is set.
+#[multiversx_sc::module]
+pub trait InboundPolicyModule:
+    cross_chain::storage::CrossChainStorage
+    + cross_chain::execute_common::ExecuteCommonModule
+    + setup_phase::SetupPhaseModule
+    + common_utils::CommonUtilsModule
+    + custom_events::CustomEventsModule
+{
+    #[only_owner]
+    #[endpoint(setInboundPolicySetupPhase)]
+    fn set_inbound_policy_setup_phase(
+        &self,
+        opt_inbound_policy: OptionalValue<InboundPolicy<Self::Api>>,
+    ) {
+        require!(
+            !self.is_setup_phase_complete(),
+            SETUP_PHASE_ALREADY_COMPLETED
+        );
+
+        self.store_inbound_policy(opt_inbound_policy.into_option());
+    }
+
+    #[endpoint(setInboundPolicy)]
+    fn set_inbound_policy(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        set_inbound_policy_operation: SetInboundPolicyOperation<Self::Api>,
+    ) {
+        let operation_hash = set_inbound_policy_operation.generate_hash();
+        if let Some(lock_operation_error) = self.lock_operation_hash_wrapper(
+            &hash_of_hashes,
+            &operation_hash,
+            set_inbound_policy_operation.nonce,
+        ) {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+
+        self.store_inbound_policy(set_inbound_policy_operation.opt_inbound_policy);
+        self.complete_operation(&hash_of_hashes, &operation_hash, None);
+    }
+
+    fn store_inbound_policy(&self, opt_inbound_policy: Option<InboundPolicy<Self::Api>>) {
+        let mut token_blacklist = self.inbound_token_blacklist();
+        let mut address_blacklist = self.inbound_address_blacklist();
+        let mut max_bridged_token_amounts = self.inbound_max_bridged_token_amounts();
+        token_blacklist.clear();
+        address_blacklist.clear();
+        max_bridged_token_amounts.clear();
+
+        let Some(inbound_policy) = opt_inbound_policy else {
+            self.separate_inbound_policy().clear();
+            return;
+        };
+
+        token_blacklist.extend(
+            inbound_policy
+                .token_blacklist
+                .iter()
+                .map(|token| token.clone()),
+        );
+        address_blacklist.extend(
+            inbound_policy
+                .address_blacklist
+                .iter()
+                .map(|address| address.clone()),
+        );
+        for max_bridged_amount in inbound_policy.max_bridged_token_amounts.iter() {
+            max_bridged_token_amounts.insert(
+                max_bridged_amount.token_id.clone(),
+                max_bridged_amount.amount.clone(),
+            );
+        }
+        self.separate_inbound_policy().set(true);
+    }
+
+    #[view(getInboundPolicy)]
+    fn get_inbound_policy(&self) -> OptionalValue<InboundPolicy<Self::Api>> {
+        if !self.separate_inbound_policy().get() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(InboundPolicy {
+            token_blacklist: self.inbound_token_blacklist().iter().collect(),
+            address_blacklist: self.inbound_address_blacklist().iter().collect(),
+            max_bridged_token_amounts: self
+                .inbound_max_bridged_token_amounts()
+                .iter()
+                .map(|(token_id, amount)| MaxBridgedAmount { token_id, amount })
+                .collect(),
+        })
+    }
+
+    fn check_inbound_policy(&self, operation: &Operation<Self::Api>) -> Result<(), ManagedBuffer> {
+        let separate_policy = self.separate_inbound_policy().get();
+
+        let receiver_blacklisted = if separate_policy {
+            self.inbound_address_blacklist().contains(&operation.to)
+        } else {
+            self.address_blacklist().contains(&operation.to)
+        };
+        if receiver_blacklisted {
+            return Err(RECEIVER_IS_BLACKLISTED.into());
+        }
+
+        for operation_token in operation.tokens.iter() {
+            let mvx_token_id = self.resolve_mvx_token_id(&operation_token.token_identifier);
+
+            let (token_blacklisted, opt_max_amount) = if separate_policy {
+                (
+                    self.inbound_token_blacklist().contains(&mvx_token_id),
+                    self.inbound_max_bridged_token_amounts().get(&mvx_token_id),
+                )
+            } else {
+                (
+                    self.token_blacklist().contains(&mvx_token_id),
+                    self.max_bridged_token_amounts().get(&mvx_token_id),
+                )
+            };
+
+            if token_blacklisted {
+                return Err(INBOUND_TOKEN_BLACKLISTED.into());
+            }
+            if let Some(max_amount) = opt_max_amount {
+                if operation_token.token_data.amount > max_amount {
+                    return Err(EXECUTION_OVER_MAX_AMOUNT.into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[storage_mapper("separateInboundPolicy")]
+    fn separate_inbound_policy(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("inboundTokenBlacklist")]
+    fn inbound_token_blacklist(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
+
+    #[storage_mapper("inboundAddressBlacklist")]
+    fn inbound_address_blacklist(&self) -> UnorderedSetMapper<ManagedAddress<Self::Api>>;
+
+    #[storage_mapper("inboundMaxBridgedTokenAmounts")]
+    fn inbound_max_bridged_token_amounts(
+        &self,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier<Self::Api>, BigUint<Self::Api>>;
+}
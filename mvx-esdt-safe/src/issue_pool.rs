@@ -0,0 +1,121 @@
+use cross_chain::DEFAULT_ISSUE_COST;
+use error_messages::{
+    INVALID_ISSUE_COST, ISSUE_POOL_FUNDING_AMOUNT_ZERO, SETUP_PHASE_NOT_COMPLETED,
+};
+use multiversx_sc::imports::*;
+use structs::{configs::SetIssueCostOperation, generate_hash::GenerateHash};
+
+#[multiversx_sc::module]
+pub trait IssuePoolModule:
+    cross_chain::storage::CrossChainStorage
+    + cross_chain::execute_common::ExecuteCommonModule
+    + setup_phase::SetupPhaseModule
+    + common_utils::CommonUtilsModule
+    + custom_events::CustomEventsModule
+{
+    #[payable("EGLD")]
+    #[endpoint(fundIssuePool)]
+    fn fund_issue_pool(&self) {
+        let amount = self.call_value().egld().clone_value();
+        require!(amount > 0u64, ISSUE_POOL_FUNDING_AMOUNT_ZERO);
+
+        let funder = self.blockchain().get_caller();
+        self.issue_pool_balance()
+            .update(|balance| *balance += &amount);
+        self.issue_pool_contribution(&funder)
+            .update(|contribution| *contribution += &amount);
+
+        self.issue_pool_funded_event(&funder, &amount);
+    }
+
+    #[endpoint(setIssueCost)]
+    fn set_issue_cost(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        set_issue_cost_operation: SetIssueCostOperation<Self::Api>,
+    ) {
+        let operation_hash = set_issue_cost_operation.generate_hash();
+        if let Some(lock_operation_error) = self.lock_operation_hash_wrapper(
+            &hash_of_hashes,
+            &operation_hash,
+            set_issue_cost_operation.nonce,
+        ) {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+        if set_issue_cost_operation.issue_cost == 0u64 {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(INVALID_ISSUE_COST.into()),
+            );
+            return;
+        }
+
+        self.issue_cost().set(set_issue_cost_operation.issue_cost);
+        self.complete_operation(&hash_of_hashes, &operation_hash, None);
+    }
+
+    #[view(getIssueCost)]
+    fn get_issue_cost(&self) -> BigUint {
+        let issue_cost_mapper = self.issue_cost();
+        if issue_cost_mapper.is_empty() {
+            return BigUint::from(DEFAULT_ISSUE_COST);
+        }
+
+        issue_cost_mapper.get()
+    }
+
+    #[view(getIssuePoolAvailableBalance)]
+    fn get_issue_pool_available_balance(&self) -> BigUint {
+        self.issue_pool_balance().get() - self.issue_pool_reserved().get()
+    }
+
+    fn can_cover_issue_cost(&self) -> bool {
+        self.get_issue_pool_available_balance() >= self.get_issue_cost()
+    }
+
+    fn reserve_issue_cost(&self) -> BigUint {
+        let issue_cost = self.get_issue_cost();
+        self.issue_pool_reserved()
+            .update(|reserved| *reserved += &issue_cost);
+
+        issue_cost
+    }
+
+    fn release_issue_cost(&self, issue_cost: &BigUint) {
+        self.issue_pool_reserved()
+            .update(|reserved| *reserved -= issue_cost);
+    }
+
+    fn consume_issue_cost(&self, issue_cost: &BigUint) {
+        self.release_issue_cost(issue_cost);
+        self.issue_pool_balance()
+            .update(|balance| *balance -= issue_cost);
+    }
+
+    #[storage_mapper("issueCost")]
+    fn issue_cost(&self) -> SingleValueMapper<BigUint>;
+
+    /// Starts empty, also for upgraded safes, whose EGLD balance may hold bridged EGLD. Only
+    /// `fundIssuePool` adds to it.
+    #[view(getIssuePoolBalance)]
+    #[storage_mapper("issuePoolBalance")]
+    fn issue_pool_balance(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getIssuePoolReservedBalance)]
+    #[storage_mapper("issuePoolReserved")]
+    fn issue_pool_reserved(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getIssuePoolContribution)]
+    #[storage_mapper("issuePoolContribution")]
+    fn issue_pool_contribution(&self, funder: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
@@ -2,20 +2,31 @@
 
 use error_messages::{
     ADDRESS_NOT_VALID_SC_ADDRESS, FEE_MARKET_NOT_SET, NATIVE_TOKEN_NOT_REGISTERED,
-    SETUP_PHASE_ALREADY_COMPLETED, SETUP_PHASE_NOT_COMPLETED,
+    SAFE_ALREADY_MIGRATED, SETUP_PHASE_ALREADY_COMPLETED, SETUP_PHASE_NOT_COMPLETED,
 };
 
 use multiversx_sc::imports::*;
 use multiversx_sc_modules::{only_admin, pause};
 use structs::{
-    configs::{EsdtSafeConfig, PauseStatusOperation, UpdateEsdtSafeConfigOperation},
+    configs::{
+        EsdtSafeConfig, PatchEsdtSafeConfigOperation, PauseStatusOperation,
+        UpdateEsdtSafeConfigOperation,
+    },
     generate_hash::GenerateHash,
 };
 
 pub mod bridging_mechanism;
 pub mod deposit;
 pub mod execute;
+pub mod inbound_policy;
+pub mod issue_pool;
+pub mod migration;
+pub mod nft_metadata;
 pub mod register_token;
+pub mod reserves;
+pub mod routing;
+pub mod token_freeze;
+pub mod token_registry;
 
 #[multiversx_sc::contract]
 pub trait MvxEsdtSafe:
@@ -24,6 +35,14 @@ pub trait MvxEsdtSafe:
     + execute::ExecuteModule
     + register_token::RegisterTokenModule
     + bridging_mechanism::BridgingMechanism
+    + token_freeze::TokenFreezeModule
+    + inbound_policy::InboundPolicyModule
+    + issue_pool::IssuePoolModule
+    + migration::MigrationModule
+    + nft_metadata::NftMetadataModule
+    + token_registry::TokenRegistryModule
+    + reserves::ReservesModule
+    + routing::RoutingModule
     + cross_chain::deposit_common::DepositCommonModule
     + custom_events::CustomEventsModule
     + cross_chain::storage::CrossChainStorage
@@ -53,12 +72,20 @@ pub trait MvxEsdtSafe:
             ADDRESS_NOT_VALID_SC_ADDRESS
         );
         self.sovereign_forge_address().set(sovereign_forge_address);
-        self.esdt_safe_config().set(new_config);
+        self.store_esdt_safe_config(new_config);
         self.set_paused(true);
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(
+        &self,
+        token_registry_backfill: MultiValueEncoded<
+            MultiValue3<EgldOrEsdtTokenIdentifier, EsdtTokenType, usize>,
+        >,
+    ) {
+        self.migrate_legacy_esdt_safe_config();
+        self.backfill_token_registry(token_registry_backfill);
+    }
 
     #[only_owner]
     #[endpoint(updateEsdtSafeConfigSetupPhase)]
@@ -72,7 +99,7 @@ pub trait MvxEsdtSafe:
             sc_panic!(error_message);
         }
 
-        self.esdt_safe_config().set(new_config);
+        self.store_esdt_safe_config(new_config);
     }
 
     #[endpoint(updateEsdtSafeConfig)]
@@ -108,12 +135,48 @@ pub trait MvxEsdtSafe:
             );
             return;
         } else {
-            self.esdt_safe_config()
-                .set(update_config_operation.esdt_safe_config);
+            self.store_esdt_safe_config(update_config_operation.esdt_safe_config);
             self.complete_operation(&hash_of_hashes, &config_hash, None);
         }
     }
 
+    #[endpoint(patchEsdtSafeConfig)]
+    fn patch_esdt_safe_config(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        patch_config_operation: PatchEsdtSafeConfigOperation<Self::Api>,
+    ) {
+        let operation_hash = patch_config_operation.generate_hash();
+        if let Some(lock_operation_error) = self.lock_operation_hash_wrapper(
+            &hash_of_hashes,
+            &operation_hash,
+            patch_config_operation.nonce,
+        ) {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+
+        if let Some(error_message) = self.apply_esdt_safe_config_patch(patch_config_operation.patch)
+        {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(ManagedBuffer::from(error_message)),
+            );
+            return;
+        }
+
+        self.complete_operation(&hash_of_hashes, &operation_hash, None);
+    }
+
     #[endpoint(pauseContract)]
     fn switch_pause_status(
         &self,
@@ -138,6 +201,15 @@ pub trait MvxEsdtSafe:
             return;
         }
 
+        if !pause_status_operation.status && self.safe_migrated().get() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SAFE_ALREADY_MIGRATED.into()),
+            );
+            return;
+        }
+
         self.set_paused(pause_status_operation.status);
         self.complete_operation(&hash_of_hashes, &operation_hash, None);
     }
@@ -0,0 +1,382 @@
+use error_messages::{
+    CALLER_NOT_PREDECESSOR, CALLER_NOT_SUCCESSOR, NATIVE_TOKEN_ALREADY_REGISTERED,
+    PREDECESSOR_ALREADY_SET, PREDECESSOR_NOT_SET, SAFE_ALREADY_MIGRATED, SAFE_NOT_MIGRATED,
+    SETUP_PHASE_ALREADY_COMPLETED, SETUP_PHASE_NOT_COMPLETED, SUCCESSOR_IN_OTHER_SHARD,
+    SUCCESSOR_NOT_REGISTERED,
+};
+use multiversx_sc::imports::*;
+use multiversx_sc_modules::{only_admin, pause};
+use proxies::mvx_esdt_safe_proxy::MvxEsdtSafeProxy;
+use structs::{
+    configs::MigrateSafeOperation, generate_hash::GenerateHash, EsdtInfo, MigrationEntry,
+    MigrationStep, NftNonceMapping,
+};
+
+const MAX_MIGRATION_PAGE_SIZE: usize = 50;
+
+#[multiversx_sc::module]
+pub trait MigrationModule:
+    crate::register_token::RegisterTokenModule
+    + crate::token_registry::TokenRegistryModule
+    + crate::issue_pool::IssuePoolModule
+    + crate::bridging_mechanism::BridgingMechanism
+    + cross_chain::storage::CrossChainStorage
+    + cross_chain::deposit_common::DepositCommonModule
+    + cross_chain::execute_common::ExecuteCommonModule
+    + setup_phase::SetupPhaseModule
+    + common_utils::CommonUtilsModule
+    + custom_events::CustomEventsModule
+    + pause::PauseModule
+    + only_admin::OnlyAdminModule
+{
+    /// Pauses the safe for good and moves the escrowed balances of the listed tokens, and the
+    /// unit kept for burn mechanism nonces, to the successor registered in the sovereign forge,
+    /// which has to live in the same shard.
+    ///
+    /// ESDT local roles are not handed over. The successor can only mint and burn the sovereign
+    /// wrappers, the native token and the burn mechanism tokens once their managers grant it
+    /// those roles through the ESDT system SC (`setSpecialRole`, `transferNFTCreateRole`).
+    /// This safe manages the tokens it issued and has no endpoint for that hand-over, so it
+    /// takes an upgrade of this safe before the successor can bridge those tokens.
+    #[endpoint(migrateSafe)]
+    fn migrate_safe(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        migrate_safe_operation: MigrateSafeOperation<Self::Api>,
+    ) {
+        let operation_hash = migrate_safe_operation.generate_hash();
+        if let Some(lock_operation_error) = self.lock_operation_hash_wrapper(
+            &hash_of_hashes,
+            &operation_hash,
+            migrate_safe_operation.nonce,
+        ) {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+        if self.safe_migrated().get() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SAFE_ALREADY_MIGRATED.into()),
+            );
+            return;
+        }
+
+        let successor = migrate_safe_operation.successor;
+        let registered_successor_mapper = self.registered_esdt_safe_successor(
+            self.sovereign_forge_address().get(),
+            &self.sov_token_prefix().get(),
+        );
+        if registered_successor_mapper.is_empty() || registered_successor_mapper.get() != successor
+        {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SUCCESSOR_NOT_REGISTERED.into()),
+            );
+            return;
+        }
+        if self.blockchain().get_shard_of_address(&successor)
+            != self
+                .blockchain()
+                .get_shard_of_address(&self.blockchain().get_sc_address())
+        {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SUCCESSOR_IN_OTHER_SHARD.into()),
+            );
+            return;
+        }
+
+        self.successor_safe_address().set(&successor);
+        self.safe_migrated().set(true);
+        self.set_paused(true);
+        self.migration_progress_event(MigrationStep::Paused, &successor, 0);
+
+        let mut escrowed_payments = ManagedVec::<Self::Api, EgldOrEsdtTokenPayment>::new();
+        for esdt_info in migrate_safe_operation.tokens.iter() {
+            let token_id = &esdt_info.token_identifier;
+            let token_nonce = esdt_info.token_nonce;
+
+            if self.is_burn_mechanism_set(token_id) {
+                let has_kept_unit = self.kept_nonce_unit(token_id, token_nonce).get();
+                if has_kept_unit || self.deposited_tokens_amount(token_id, token_nonce).get() != 0 {
+                    self.migrated_deposited_amounts().insert(esdt_info.clone());
+                }
+                if has_kept_unit {
+                    escrowed_payments.push(EgldOrEsdtTokenPayment::new(
+                        token_id.clone(),
+                        token_nonce,
+                        BigUint::from(1u32),
+                    ));
+                }
+                continue;
+            }
+
+            let sc_balance = self.blockchain().get_sc_balance(token_id, token_nonce);
+            if sc_balance != 0 {
+                escrowed_payments.push(EgldOrEsdtTokenPayment::new(
+                    token_id.clone(),
+                    token_nonce,
+                    sc_balance,
+                ));
+            }
+        }
+
+        let transferred_payments = escrowed_payments.len();
+        if transferred_payments != 0 {
+            self.tx()
+                .to(&successor)
+                .typed(MvxEsdtSafeProxy)
+                .receive_migrated_funds()
+                .payment(&escrowed_payments)
+                .sync_call();
+        }
+        self.migration_progress_event(
+            MigrationStep::EscrowTransferred,
+            &successor,
+            transferred_payments,
+        );
+
+        self.complete_operation(&hash_of_hashes, &operation_hash, None);
+    }
+
+    #[view(getMigrationStateCount)]
+    fn get_migration_state_count(&self) -> usize {
+        self.registered_token_pairs().len()
+            + self.registered_nft_nonces().len()
+            + self.burn_mechanism_tokens().len()
+            + self.migrated_deposited_amounts().len()
+            + usize::from(!self.native_token().is_empty())
+    }
+
+    /// Pages are zero-based and hold at most `MAX_MIGRATION_PAGE_SIZE` entries.
+    #[endpoint(exportMigrationState)]
+    fn export_migration_state(
+        &self,
+        from_index: usize,
+        page_size: usize,
+    ) -> MultiValueEncoded<MigrationEntry<Self::Api>> {
+        let successor_mapper = self.successor_safe_address();
+        require!(!successor_mapper.is_empty(), SAFE_NOT_MIGRATED);
+
+        let successor = successor_mapper.get();
+        require!(
+            self.blockchain().get_caller() == successor,
+            CALLER_NOT_SUCCESSOR
+        );
+
+        let total = self.get_migration_state_count();
+        let start = from_index.min(total);
+        let end = start + page_size.min(MAX_MIGRATION_PAGE_SIZE).min(total - start);
+
+        let mut entries = MultiValueEncoded::new();
+        for index in start..end {
+            entries.push(self.get_migration_entry(index));
+        }
+
+        self.migration_progress_event(MigrationStep::StateExported, &successor, end - start);
+
+        entries
+    }
+
+    #[only_admin]
+    #[endpoint(setPredecessorSafe)]
+    fn set_predecessor_safe(&self, predecessor: ManagedAddress) {
+        require!(
+            !self.is_setup_phase_complete(),
+            SETUP_PHASE_ALREADY_COMPLETED
+        );
+        self.require_sc_address(&predecessor);
+
+        let predecessor_mapper = self.predecessor_safe_address();
+        require!(predecessor_mapper.is_empty(), PREDECESSOR_ALREADY_SET);
+
+        predecessor_mapper.set(predecessor);
+    }
+
+    #[payable]
+    #[endpoint(receiveMigratedFunds)]
+    fn receive_migrated_funds(&self) {
+        let predecessor_mapper = self.predecessor_safe_address();
+        require!(!predecessor_mapper.is_empty(), PREDECESSOR_NOT_SET);
+        require!(
+            self.blockchain().get_caller() == predecessor_mapper.get(),
+            CALLER_NOT_PREDECESSOR
+        );
+    }
+
+    /// Pages can be imported again, entries that are already imported are left as they are.
+    #[only_admin]
+    #[endpoint(importMigrationState)]
+    fn import_migration_state(&self, from_index: usize, page_size: usize) {
+        require!(
+            !self.is_setup_phase_complete(),
+            SETUP_PHASE_ALREADY_COMPLETED
+        );
+
+        let predecessor_mapper = self.predecessor_safe_address();
+        require!(!predecessor_mapper.is_empty(), PREDECESSOR_NOT_SET);
+
+        let predecessor = predecessor_mapper.get();
+        let entries = self
+            .tx()
+            .to(&predecessor)
+            .typed(MvxEsdtSafeProxy)
+            .export_migration_state(from_index, page_size)
+            .returns(ReturnsResult)
+            .sync_call();
+
+        let mut imported_entries = 0;
+        for entry in entries.into_iter() {
+            self.import_migration_entry(entry);
+            imported_entries += 1;
+        }
+        self.migration_import_index().update(|import_index| {
+            *import_index = (*import_index).max(from_index + imported_entries)
+        });
+
+        self.migration_progress_event(MigrationStep::StateImported, &predecessor, imported_entries);
+    }
+
+    fn get_migration_entry(&self, index: usize) -> MigrationEntry<Self::Api> {
+        let mut index = index;
+
+        let registered_token_pairs = self.registered_token_pairs();
+        if index < registered_token_pairs.len() {
+            let sov_token_id = registered_token_pairs.get_by_index(index + 1);
+            return MigrationEntry::TokenPair(self.token_registry_entry(&sov_token_id).get());
+        }
+        index -= registered_token_pairs.len();
+
+        let registered_nft_nonces = self.registered_nft_nonces();
+        if index < registered_nft_nonces.len() {
+            let mvx_esdt_info = registered_nft_nonces.get_by_index(index + 1);
+            let sov_esdt_info = self
+                .multiversx_to_sovereign_esdt_info_mapper(
+                    &mvx_esdt_info.token_identifier,
+                    mvx_esdt_info.token_nonce,
+                )
+                .get();
+            return MigrationEntry::NftNonce(NftNonceMapping {
+                sov_esdt_info,
+                mvx_esdt_info,
+            });
+        }
+        index -= registered_nft_nonces.len();
+
+        let burn_mechanism_tokens = self.burn_mechanism_tokens();
+        if index < burn_mechanism_tokens.len() {
+            return MigrationEntry::BurnMechanismToken(
+                burn_mechanism_tokens.get_by_index(index + 1),
+            );
+        }
+        index -= burn_mechanism_tokens.len();
+
+        let migrated_deposited_amounts = self.migrated_deposited_amounts();
+        if index < migrated_deposited_amounts.len() {
+            let esdt_info = migrated_deposited_amounts.get_by_index(index + 1);
+            let amount = self
+                .deposited_tokens_amount(&esdt_info.token_identifier, esdt_info.token_nonce)
+                .get();
+            let kept_nonce_unit = self
+                .kept_nonce_unit(&esdt_info.token_identifier, esdt_info.token_nonce)
+                .get();
+            return MigrationEntry::DepositedAmount {
+                esdt_info,
+                amount,
+                kept_nonce_unit,
+            };
+        }
+
+        MigrationEntry::NativeToken(self.native_token().get())
+    }
+
+    fn import_migration_entry(&self, entry: MigrationEntry<Self::Api>) {
+        match entry {
+            MigrationEntry::TokenPair(registry_entry) => {
+                // MultiversX tokens are recorded under their own identifier and have no mapping.
+                if registry_entry.sov_token_id != registry_entry.mvx_token_id {
+                    self.set_corresponding_token_ids(
+                        &registry_entry.sov_token_id,
+                        &registry_entry.mvx_token_id,
+                    );
+                }
+                self.registered_token_pairs()
+                    .insert(registry_entry.sov_token_id.clone());
+                self.token_registry_entry(&registry_entry.sov_token_id)
+                    .set(registry_entry);
+            }
+            MigrationEntry::NftNonce(nft_nonce_mapping) => {
+                self.update_esdt_info_mappers(
+                    &nft_nonce_mapping.sov_esdt_info.token_identifier,
+                    nft_nonce_mapping.sov_esdt_info.token_nonce,
+                    &nft_nonce_mapping.mvx_esdt_info.token_identifier,
+                    nft_nonce_mapping.mvx_esdt_info.token_nonce,
+                );
+            }
+            MigrationEntry::BurnMechanismToken(token_id) => {
+                self.burn_mechanism_tokens().insert(token_id);
+            }
+            MigrationEntry::DepositedAmount {
+                esdt_info,
+                amount,
+                kept_nonce_unit,
+            } => {
+                self.deposited_tokens_amount(&esdt_info.token_identifier, esdt_info.token_nonce)
+                    .set(amount);
+                self.kept_nonce_unit(&esdt_info.token_identifier, esdt_info.token_nonce)
+                    .set(kept_nonce_unit);
+            }
+            MigrationEntry::NativeToken(native_token_id) => {
+                let native_token_mapper = self.native_token();
+                if !native_token_mapper.is_empty() {
+                    require!(
+                        native_token_mapper.get() == native_token_id,
+                        NATIVE_TOKEN_ALREADY_REGISTERED
+                    );
+                    return;
+                }
+
+                native_token_mapper.set(native_token_id);
+            }
+        }
+    }
+
+    #[storage_mapper_from_address("esdtSafeSuccessor")]
+    fn registered_esdt_safe_successor(
+        &self,
+        sc_address: ManagedAddress,
+        chain_id: &ManagedBuffer,
+    ) -> SingleValueMapper<ManagedAddress, ManagedAddress>;
+
+    #[view(isSafeMigrated)]
+    fn is_safe_migrated(&self) -> bool {
+        self.safe_migrated().get()
+    }
+
+    #[view(getSuccessorSafeAddress)]
+    #[storage_mapper("successorSafeAddress")]
+    fn successor_safe_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getPredecessorSafeAddress)]
+    #[storage_mapper("predecessorSafeAddress")]
+    fn predecessor_safe_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Index right after the furthest entry imported from the predecessor.
+    #[view(getMigrationImportIndex)]
+    #[storage_mapper("migrationImportIndex")]
+    fn migration_import_index(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("migratedDepositedAmounts")]
+    fn migrated_deposited_amounts(&self) -> UnorderedSetMapper<EsdtInfo<Self::Api>>;
+}
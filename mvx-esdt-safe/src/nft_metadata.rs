@@ -0,0 +1,111 @@
+use error_messages::{
+    ESDT_SAFE_STILL_PAUSED, NFT_NONCE_NOT_REGISTERED, SETUP_PHASE_NOT_COMPLETED,
+    TOKEN_NOT_REGISTERED, UPDATE_NFT_METADATA_FAILED,
+};
+use multiversx_sc::imports::*;
+use structs::{
+    configs::{NftMetadata, UpdateNftMetadataOperation},
+    generate_hash::GenerateHash,
+};
+
+#[multiversx_sc::module]
+pub trait NftMetadataModule:
+    cross_chain::storage::CrossChainStorage
+    + cross_chain::execute_common::ExecuteCommonModule
+    + setup_phase::SetupPhaseModule
+    + common_utils::CommonUtilsModule
+    + custom_events::CustomEventsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    #[endpoint(updateNftMetadata)]
+    fn update_nft_metadata(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        update_nft_metadata_operation: UpdateNftMetadataOperation<Self::Api>,
+    ) {
+        let operation_hash = update_nft_metadata_operation.generate_hash();
+        if let Some(lock_operation_error) = self.lock_operation_hash_wrapper(
+            &hash_of_hashes,
+            &operation_hash,
+            update_nft_metadata_operation.nonce,
+        ) {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+        if self.is_paused() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(ESDT_SAFE_STILL_PAUSED.into()),
+            );
+            return;
+        }
+
+        let sov_token_id = &update_nft_metadata_operation.token_id;
+        let mvx_token_id_mapper = self.sovereign_to_multiversx_token_id_mapper(sov_token_id);
+        if mvx_token_id_mapper.is_empty() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(TOKEN_NOT_REGISTERED.into()),
+            );
+            return;
+        }
+
+        let mvx_esdt_info_mapper = self.sovereign_to_multiversx_esdt_info_mapper(
+            sov_token_id,
+            update_nft_metadata_operation.token_nonce,
+        );
+        if mvx_esdt_info_mapper.is_empty() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(NFT_NONCE_NOT_REGISTERED.into()),
+            );
+            return;
+        }
+
+        let result = self.try_esdt_metadata_update(
+            &mvx_token_id_mapper.get().unwrap_esdt(),
+            mvx_esdt_info_mapper.get().token_nonce,
+            update_nft_metadata_operation.metadata,
+        );
+
+        self.complete_operation(&hash_of_hashes, &operation_hash, result.err());
+    }
+
+    fn try_esdt_metadata_update(
+        &self,
+        token_id: &EsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+        metadata: NftMetadata<Self::Api>,
+    ) -> Result<(), ManagedBuffer> {
+        let result = self
+            .tx()
+            .to(ToSelf)
+            .typed(UserBuiltinProxy)
+            .esdt_metadata_update(
+                token_id,
+                token_nonce,
+                metadata.name,
+                metadata.royalties,
+                metadata.hash,
+                &metadata.attributes,
+                metadata.uris,
+            )
+            .returns(ReturnsHandledOrError::new())
+            .sync_call_fallible();
+
+        result.map_err(|error_code| {
+            self.format_error(UPDATE_NFT_METADATA_FAILED, token_id.clone(), error_code)
+        })
+    }
+}
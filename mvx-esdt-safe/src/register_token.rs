@@ -1,4 +1,5 @@
-use cross_chain::{DEFAULT_ISSUE_COST, REGISTER_GAS};
+use crate::token_registry::NATIVE_TOKEN_DECIMALS;
+use cross_chain::REGISTER_GAS;
 use error_messages::{
     ESDT_SAFE_STILL_PAUSED, INVALID_PREFIX_FOR_REGISTER, NATIVE_TOKEN_ALREADY_REGISTERED,
     NOT_ENOUGH_EGLD_FOR_REGISTER, SETUP_PHASE_ALREADY_COMPLETED, TOKEN_ALREADY_REGISTERED,
@@ -21,6 +22,9 @@ pub trait RegisterTokenModule:
     + multiversx_sc_modules::pause::PauseModule
     + setup_phase::SetupPhaseModule
     + only_admin::OnlyAdminModule
+    + crate::issue_pool::IssuePoolModule
+    + crate::token_registry::TokenRegistryModule
+    + crate::bridging_mechanism::BridgingMechanism
 {
     #[endpoint(registerToken)]
     fn register_sovereign_token(
@@ -39,8 +43,8 @@ pub trait RegisterTokenModule:
         }
         if self.is_paused() {
             self.deposit_event(
-                &register_token_operation.data.op_sender.clone(),
-                &self.create_issue_cost_event_payment_tuple(),
+                register_token_operation.data.refund_address(),
+                &self.create_issue_cost_event_payment_tuple(self.get_issue_cost()),
                 register_token_operation.data.clone(),
             );
             self.complete_operation(
@@ -51,14 +55,10 @@ pub trait RegisterTokenModule:
             return;
         }
 
-        if self
-            .blockchain()
-            .get_balance(&self.blockchain().get_sc_address())
-            < DEFAULT_ISSUE_COST
-        {
+        if !self.can_cover_issue_cost() {
             self.deposit_event(
-                &register_token_operation.data.op_sender.clone(),
-                &self.create_issue_cost_event_payment_tuple(),
+                register_token_operation.data.refund_address(),
+                &self.create_issue_cost_event_payment_tuple(self.get_issue_cost()),
                 register_token_operation.data.clone(),
             );
             self.complete_operation(
@@ -70,8 +70,8 @@ pub trait RegisterTokenModule:
         }
         if self.is_sov_token_id_registered(&register_token_operation.token_id) {
             self.deposit_event(
-                &register_token_operation.data.op_sender.clone(),
-                &self.create_issue_cost_event_payment_tuple(),
+                register_token_operation.data.refund_address(),
+                &self.create_issue_cost_event_payment_tuple(self.get_issue_cost()),
                 register_token_operation.data.clone(),
             );
             self.complete_operation(
@@ -86,8 +86,8 @@ pub trait RegisterTokenModule:
             &self.sov_token_prefix().get(),
         ) {
             self.deposit_event(
-                &register_token_operation.data.op_sender.clone(),
-                &self.create_issue_cost_event_payment_tuple(),
+                register_token_operation.data.refund_address(),
+                &self.create_issue_cost_event_payment_tuple(self.get_issue_cost()),
                 register_token_operation.data.clone(),
             );
             self.complete_operation(
@@ -123,7 +123,7 @@ pub trait RegisterTokenModule:
                 name,
                 ticker,
                 EsdtTokenType::Fungible,
-                18,
+                NATIVE_TOKEN_DECIMALS,
             )
             .gas(REGISTER_GAS)
             .callback(self.callbacks().native_token_issue_callback())
@@ -140,22 +140,25 @@ pub trait RegisterTokenModule:
         let token_ticker = args.token_ticker.clone();
         let token_type = args.token_type;
         let num_decimals = args.num_decimals;
+        let issue_cost = self.reserve_issue_cost();
 
         self.tx()
             .to(ESDTSystemSCAddress)
             .typed(ESDTSystemSCProxy)
             .issue_and_set_all_roles(
-                BigUint::from(DEFAULT_ISSUE_COST),
+                issue_cost.clone(),
                 token_display_name,
                 token_ticker,
                 token_type,
                 num_decimals,
             )
             .gas(REGISTER_GAS)
-            .callback(
-                self.callbacks()
-                    .register_token(&args, hash_of_hashes, token_hash),
-            )
+            .callback(self.callbacks().register_token(
+                &args,
+                hash_of_hashes,
+                token_hash,
+                issue_cost,
+            ))
             .register_promise();
     }
 
@@ -165,17 +168,22 @@ pub trait RegisterTokenModule:
         token_to_register: &RegisterTokenOperation<Self::Api>,
         hash_of_hashes: ManagedBuffer,
         token_hash: ManagedBuffer,
+        issue_cost: BigUint,
         #[call_result] result: ManagedAsyncCallResult<EgldOrEsdtTokenIdentifier<Self::Api>>,
     ) {
         match result {
             ManagedAsyncCallResult::Ok(mvx_token_id) => {
+                self.consume_issue_cost(&issue_cost);
                 self.set_corresponding_token_ids(&token_to_register.token_id, &mvx_token_id);
+                self.add_token_registry_entry(token_to_register, &mvx_token_id);
                 self.complete_operation(&hash_of_hashes, &token_hash, None);
             }
             ManagedAsyncCallResult::Err(error) => {
-                let tokens = self.create_issue_cost_event_payment_tuple();
+                self.release_issue_cost(&issue_cost);
+                // The cost reserved at issue time, `setIssueCost` may have changed it since.
+                let tokens = self.create_issue_cost_event_payment_tuple(issue_cost);
                 self.deposit_event(
-                    &token_to_register.data.op_sender.clone(),
+                    token_to_register.data.refund_address(),
                     &tokens,
                     token_to_register.data.clone(),
                 );
@@ -191,7 +199,14 @@ pub trait RegisterTokenModule:
     ) {
         match result {
             ManagedAsyncCallResult::Ok(native_token_id) => {
-                self.native_token().set(native_token_id);
+                self.native_token().set(&native_token_id);
+                self.set_token_registry_entry(
+                    &native_token_id,
+                    &native_token_id,
+                    EsdtTokenType::Fungible,
+                    NATIVE_TOKEN_DECIMALS,
+                    0,
+                );
             }
             ManagedAsyncCallResult::Err(error) => {
                 sc_panic!(
@@ -213,6 +228,20 @@ pub trait RegisterTokenModule:
             .set(sov_token_id);
     }
 
+    fn add_token_registry_entry(
+        &self,
+        registered_token: &RegisterTokenOperation<Self::Api>,
+        mvx_token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) {
+        self.set_token_registry_entry(
+            &registered_token.token_id,
+            mvx_token_id,
+            registered_token.token_type,
+            registered_token.num_decimals,
+            registered_token.data.op_nonce,
+        );
+    }
+
     fn update_esdt_info_mappers(
         &self,
         sov_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
@@ -231,14 +260,19 @@ pub trait RegisterTokenModule:
                 token_identifier: sov_id.clone(),
                 token_nonce: sov_nonce,
             });
+        self.registered_nft_nonces().insert(EsdtInfo {
+            token_identifier: mvx_id.clone(),
+            token_nonce: new_nft_nonce,
+        });
     }
 
     #[allow(clippy::field_reassign_with_default)]
     fn create_issue_cost_event_payment_tuple(
         &self,
+        issue_cost: BigUint,
     ) -> MultiValueEncoded<Self::Api, EventPaymentTuple<Self::Api>> {
         let mut token_data = EsdtTokenData::default();
-        token_data.amount = DEFAULT_ISSUE_COST.into();
+        token_data.amount = issue_cost;
 
         MultiValueEncoded::from_iter([MultiValue3((
             EGLD_000000_TOKEN_IDENTIFIER.into(),
@@ -0,0 +1,121 @@
+use multiversx_sc::imports::*;
+use structs::{TokenMechanism, TokenReserves};
+
+#[multiversx_sc::module]
+pub trait ReservesModule:
+    crate::bridging_mechanism::BridgingMechanism
+    + cross_chain::storage::CrossChainStorage
+    + cross_chain::execute_common::ExecuteCommonModule
+    + setup_phase::SetupPhaseModule
+    + common_utils::CommonUtilsModule
+    + custom_events::CustomEventsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// The reserve is the escrowed balance for lock mechanism tokens, the deposited amount plus
+    /// the kept unit for burn mechanism tokens and the outstanding minted supply for sovereign wrappers.
+    /// All amounts are for the given MultiversX token nonce.
+    #[view(getTokenReserves)]
+    fn get_token_reserves(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+    ) -> TokenReserves<Self::Api> {
+        let total_deposited = self.total_deposited(&token_id, token_nonce).get();
+        let total_executed = self.total_executed(&token_id, token_nonce).get();
+        let total_refunded = self.total_refunded(&token_id, token_nonce).get();
+
+        let is_sovereign_wrapper = !self
+            .multiversx_to_sovereign_token_id_mapper(&token_id)
+            .is_empty()
+            || self.is_native_token(&token_id);
+
+        let (mechanism, reserve_amount) = if is_sovereign_wrapper {
+            let burned = &total_deposited + &total_refunded;
+            let minted_supply = if total_executed > burned {
+                &total_executed - &burned
+            } else {
+                BigUint::zero()
+            };
+            (TokenMechanism::Burn, minted_supply)
+        } else if self.is_burn_mechanism_set(&token_id) {
+            let mut deposited_amount = self.deposited_tokens_amount(&token_id, token_nonce).get();
+            if self.kept_nonce_unit(&token_id, token_nonce).get() {
+                deposited_amount += 1u32;
+            }
+            (TokenMechanism::Burn, deposited_amount)
+        } else {
+            (
+                TokenMechanism::Lock,
+                self.blockchain().get_sc_balance(&token_id, token_nonce),
+            )
+        };
+
+        TokenReserves {
+            token_id,
+            token_nonce,
+            mechanism,
+            is_sovereign_wrapper,
+            reserve_amount,
+            total_deposited,
+            total_executed,
+            total_refunded,
+        }
+    }
+
+    #[inline]
+    fn track_deposited_amount(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+        amount: &BigUint<Self::Api>,
+    ) {
+        self.total_deposited(token_id, token_nonce)
+            .update(|total| *total += amount);
+    }
+
+    #[inline]
+    fn track_executed_amount(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+        amount: &BigUint<Self::Api>,
+    ) {
+        self.total_executed(token_id, token_nonce)
+            .update(|total| *total += amount);
+    }
+
+    #[inline]
+    fn track_refunded_amount(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+        amount: &BigUint<Self::Api>,
+    ) {
+        self.total_refunded(&self.resolve_mvx_token_id(token_id), token_nonce)
+            .update(|total| *total += amount);
+    }
+
+    #[view(getTotalDeposited)]
+    #[storage_mapper("totalDeposited")]
+    fn total_deposited(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalExecuted)]
+    #[storage_mapper("totalExecuted")]
+    fn total_executed(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalRefunded)]
+    #[storage_mapper("totalRefunded")]
+    fn total_refunded(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+}
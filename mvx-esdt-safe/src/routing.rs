@@ -0,0 +1,125 @@
+use error_messages::{
+    DESTINATION_ESDT_SAFE_NOT_FOUND, DESTINATION_FEE_NOT_COVERED, INVALID_DESTINATION_CHAIN,
+};
+use multiversx_sc::imports::*;
+use multiversx_sc_modules::pause;
+use proxies::mvx_fee_market_proxy::MvxFeeMarketProxy;
+use structs::{
+    aliases::{GasLimit, PaymentsVec},
+    forge::{ContractInfo, ScArray},
+};
+
+#[multiversx_sc::module]
+pub trait RoutingModule:
+    crate::bridging_mechanism::BridgingMechanism
+    + cross_chain::storage::CrossChainStorage
+    + cross_chain::execute_common::ExecuteCommonModule
+    + cross_chain::deposit_common::DepositCommonModule
+    + setup_phase::SetupPhaseModule
+    + common_utils::CommonUtilsModule
+    + custom_events::CustomEventsModule
+    + pause::PauseModule
+{
+    fn get_destination_esdt_safe(
+        &self,
+        destination_chain_id: &ManagedBuffer,
+    ) -> Result<ManagedAddress, ManagedBuffer> {
+        if destination_chain_id == &self.sov_token_prefix().get() {
+            return Err(INVALID_DESTINATION_CHAIN.into());
+        }
+
+        self.destination_sovereign_contracts(
+            self.sovereign_forge_address().get(),
+            destination_chain_id,
+        )
+        .iter()
+        .find(|contract_info| contract_info.id == ScArray::ESDTSafe)
+        .map(|contract_info| contract_info.address)
+        .ok_or_else(|| DESTINATION_ESDT_SAFE_NOT_FOUND.into())
+    }
+
+    /// The destination safe's fee is quoted through its fee market for this safe as depositor
+    /// and paid out of the first routed fungible payment in one of the fee market's fee tokens.
+    /// Percentage fees are quoted on the amounts before the fee is taken out, so they can be
+    /// slightly above the charged fee. The destination fee market refunds the rest to this safe.
+    fn add_destination_fee_payment(
+        &self,
+        destination_safe: &ManagedAddress,
+        receiver: &ManagedAddress,
+        payments: PaymentsVec<Self::Api>,
+        gas_limit: GasLimit,
+    ) -> Result<PaymentsVec<Self::Api>, ManagedBuffer> {
+        let destination_fee_market = self.destination_fee_market_address(destination_safe.clone());
+        if destination_fee_market.is_empty() {
+            return Ok(payments);
+        }
+
+        let fee_market_address = destination_fee_market.get();
+        if !self.is_fee_required_by_fee_market(
+            fee_market_address.clone(),
+            &payments,
+            &ManagedVec::from_single_item(receiver.clone()),
+        ) {
+            return Ok(payments);
+        }
+
+        let active_fee_tokens = self.external_active_fee_tokens(fee_market_address.clone());
+        let Some(fee_source_index) = payments.iter().position(|payment| {
+            payment.token_nonce == 0 && active_fee_tokens.contains(&payment.token_identifier)
+        }) else {
+            return Err(DESTINATION_FEE_NOT_COVERED.into());
+        };
+
+        let fee_source = payments.get(fee_source_index).clone();
+        let fee_quote = self
+            .tx()
+            .to(&fee_market_address)
+            .typed(MvxFeeMarketProxy)
+            .get_fee_quote(
+                fee_source.token_identifier.clone(),
+                payments.clone(),
+                gas_limit,
+                OptionalValue::Some(self.blockchain().get_sc_address()),
+            )
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+
+        // A zero quote still has the destination safe take the first payment as its fee.
+        let fee_amount = fee_quote.fee.amount;
+        if fee_amount == 0 || fee_amount >= fee_source.amount {
+            return Err(DESTINATION_FEE_NOT_COVERED.into());
+        }
+
+        let mut payments_with_fee = PaymentsVec::from_single_item(EgldOrEsdtTokenPayment::new(
+            fee_source.token_identifier.clone(),
+            0,
+            fee_amount.clone(),
+        ));
+        for (index, payment) in payments.iter().enumerate() {
+            if index == fee_source_index {
+                payments_with_fee.push(EgldOrEsdtTokenPayment::new(
+                    payment.token_identifier.clone(),
+                    0,
+                    &payment.amount - &fee_amount,
+                ));
+            } else {
+                payments_with_fee.push(payment.clone());
+            }
+        }
+
+        Ok(payments_with_fee)
+    }
+
+    #[storage_mapper_from_address("sovereignDeployedContracts")]
+    fn destination_sovereign_contracts(
+        &self,
+        sc_address: ManagedAddress,
+        chain_id: &ManagedBuffer,
+    ) -> UnorderedSetMapper<ContractInfo<Self::Api>, ManagedAddress>;
+
+    #[storage_mapper_from_address("feeMarketAddress")]
+    fn destination_fee_market_address(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> SingleValueMapper<ManagedAddress, ManagedAddress>;
+}
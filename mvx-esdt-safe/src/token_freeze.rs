@@ -0,0 +1,77 @@
+use error_messages::{SETUP_PHASE_NOT_COMPLETED, TOKEN_ALREADY_FROZEN, TOKEN_NOT_FROZEN};
+use multiversx_sc::imports::*;
+use structs::{
+    configs::SetTokenFreezeOperation, generate_hash::GenerateHash, operation::Operation,
+};
+
+#[multiversx_sc::module]
+pub trait TokenFreezeModule:
+    cross_chain::storage::CrossChainStorage
+    + cross_chain::execute_common::ExecuteCommonModule
+    + setup_phase::SetupPhaseModule
+    + common_utils::CommonUtilsModule
+    + custom_events::CustomEventsModule
+{
+    #[endpoint(setTokenFreezeStatus)]
+    fn set_token_freeze_status(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        set_token_freeze_operation: SetTokenFreezeOperation<Self::Api>,
+    ) {
+        let operation_hash = set_token_freeze_operation.generate_hash();
+        if let Some(lock_operation_error) = self.lock_operation_hash_wrapper(
+            &hash_of_hashes,
+            &operation_hash,
+            set_token_freeze_operation.nonce,
+        ) {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+
+        let mut frozen_tokens_mapper = self.frozen_tokens();
+        let token_id = &set_token_freeze_operation.token_id;
+        if set_token_freeze_operation.frozen {
+            if !frozen_tokens_mapper.insert(token_id.clone()) {
+                self.complete_operation(
+                    &hash_of_hashes,
+                    &operation_hash,
+                    Some(TOKEN_ALREADY_FROZEN.into()),
+                );
+                return;
+            }
+        } else if !frozen_tokens_mapper.swap_remove(token_id) {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(TOKEN_NOT_FROZEN.into()),
+            );
+            return;
+        }
+
+        self.complete_operation(&hash_of_hashes, &operation_hash, None);
+    }
+
+    #[inline]
+    fn is_token_frozen(&self, token_id: &EgldOrEsdtTokenIdentifier<Self::Api>) -> bool {
+        self.frozen_tokens().contains(token_id)
+    }
+
+    /// Tokens are frozen by their MultiversX identifier, so registered sovereign tokens are resolved first.
+    fn has_frozen_token(&self, operation: &Operation<Self::Api>) -> bool {
+        operation.tokens.iter().any(|operation_token| {
+            self.is_token_frozen(&self.resolve_mvx_token_id(&operation_token.token_identifier))
+        })
+    }
+
+    #[view(getFrozenTokens)]
+    #[storage_mapper("frozenTokens")]
+    fn frozen_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier<Self::Api>>;
+}
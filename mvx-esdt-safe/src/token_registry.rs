@@ -0,0 +1,171 @@
+use multiversx_sc::imports::*;
+use structs::{aliases::TxNonce, NftNonceMapping, TokenMechanism, TokenRegistryEntry};
+
+const MAX_REGISTRY_PAGE_SIZE: usize = 100;
+pub const NATIVE_TOKEN_DECIMALS: usize = 18;
+
+#[multiversx_sc::module]
+pub trait TokenRegistryModule:
+    crate::bridging_mechanism::BridgingMechanism
+    + cross_chain::storage::CrossChainStorage
+    + cross_chain::execute_common::ExecuteCommonModule
+    + setup_phase::SetupPhaseModule
+    + common_utils::CommonUtilsModule
+    + custom_events::CustomEventsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    #[view(getRegisteredTokensCount)]
+    fn get_registered_tokens_count(&self) -> usize {
+        self.registered_token_pairs().len()
+    }
+
+    /// Pages are zero-based and hold at most `MAX_REGISTRY_PAGE_SIZE` entries.
+    #[view(getRegisteredTokens)]
+    fn get_registered_tokens(
+        &self,
+        from_index: usize,
+        page_size: usize,
+    ) -> MultiValueEncoded<TokenRegistryEntry<Self::Api>> {
+        let registered_token_pairs = self.registered_token_pairs();
+        let (start, end) = self.page_bounds(registered_token_pairs.len(), from_index, page_size);
+
+        let mut entries = MultiValueEncoded::new();
+        for index in start..end {
+            let sov_token_id = registered_token_pairs.get_by_index(index + 1);
+            entries.push(self.token_registry_entry(&sov_token_id).get());
+        }
+
+        entries
+    }
+
+    #[view(getNftNonceMappingsCount)]
+    fn get_nft_nonce_mappings_count(&self) -> usize {
+        self.registered_nft_nonces().len()
+    }
+
+    #[view(getNftNonceMappings)]
+    fn get_nft_nonce_mappings(
+        &self,
+        from_index: usize,
+        page_size: usize,
+    ) -> MultiValueEncoded<NftNonceMapping<Self::Api>> {
+        let registered_nft_nonces = self.registered_nft_nonces();
+        let (start, end) = self.page_bounds(registered_nft_nonces.len(), from_index, page_size);
+
+        let mut mappings = MultiValueEncoded::new();
+        for index in start..end {
+            let mvx_esdt_info = registered_nft_nonces.get_by_index(index + 1);
+            let sov_esdt_info = self
+                .multiversx_to_sovereign_esdt_info_mapper(
+                    &mvx_esdt_info.token_identifier,
+                    mvx_esdt_info.token_nonce,
+                )
+                .get();
+            mappings.push(NftNonceMapping {
+                sov_esdt_info,
+                mvx_esdt_info,
+            });
+        }
+
+        mappings
+    }
+
+    fn set_token_registry_entry(
+        &self,
+        sov_token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        mvx_token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_type: EsdtTokenType,
+        num_decimals: usize,
+        registration_nonce: TxNonce,
+    ) {
+        self.registered_token_pairs().insert(sov_token_id.clone());
+        self.token_registry_entry(sov_token_id)
+            .set(TokenRegistryEntry {
+                sov_token_id: sov_token_id.clone(),
+                mvx_token_id: mvx_token_id.clone(),
+                token_type,
+                num_decimals,
+                registration_nonce,
+                mechanism: self.get_token_mechanism(mvx_token_id),
+            });
+    }
+
+    /// MultiversX tokens keep their identifier on the sovereign side and are recorded the first
+    /// time they are deposited. Their decimals can not be read on chain, so they are left at 0.
+    fn add_deposited_token_registry_entry(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        token_type: EsdtTokenType,
+    ) {
+        if self.registered_token_pairs().contains(token_id) {
+            return;
+        }
+
+        self.set_token_registry_entry(token_id, token_id, token_type, 0, 0);
+    }
+
+    /// Each backfilled entry is `(sovereign token id, token type, decimals)`. The MultiversX
+    /// token id is read from the existing mapping, or is the same id for MultiversX tokens.
+    fn backfill_token_registry(
+        &self,
+        entries: MultiValueEncoded<MultiValue3<EgldOrEsdtTokenIdentifier, EsdtTokenType, usize>>,
+    ) {
+        if !self.native_token().is_empty() {
+            let native_token_id = self.native_token().get();
+            if !self.registered_token_pairs().contains(&native_token_id) {
+                self.set_token_registry_entry(
+                    &native_token_id,
+                    &native_token_id,
+                    EsdtTokenType::Fungible,
+                    NATIVE_TOKEN_DECIMALS,
+                    0,
+                );
+            }
+        }
+
+        for entry in entries {
+            let (sov_token_id, token_type, num_decimals) = entry.into_tuple();
+            if self.registered_token_pairs().contains(&sov_token_id) {
+                continue;
+            }
+
+            let mvx_token_mapper = self.sovereign_to_multiversx_token_id_mapper(&sov_token_id);
+            let mvx_token_id = if mvx_token_mapper.is_empty() {
+                sov_token_id.clone()
+            } else {
+                mvx_token_mapper.get()
+            };
+            self.set_token_registry_entry(
+                &sov_token_id,
+                &mvx_token_id,
+                token_type,
+                num_decimals,
+                0,
+            );
+        }
+    }
+
+    /// Tokens issued for sovereign tokens and the native token are always burned on deposit.
+    fn get_token_mechanism(
+        &self,
+        mvx_token_id: &EgldOrEsdtTokenIdentifier<Self::Api>,
+    ) -> TokenMechanism {
+        if self.is_burn_mechanism_set(mvx_token_id)
+            || self.is_native_token(mvx_token_id)
+            || !self
+                .multiversx_to_sovereign_token_id_mapper(mvx_token_id)
+                .is_empty()
+        {
+            return TokenMechanism::Burn;
+        }
+
+        TokenMechanism::Lock
+    }
+
+    fn page_bounds(&self, total: usize, from_index: usize, page_size: usize) -> (usize, usize) {
+        let start = from_index.min(total);
+        let end = start + page_size.min(MAX_REGISTRY_PAGE_SIZE).min(total - start);
+
+        (start, end)
+    }
+}
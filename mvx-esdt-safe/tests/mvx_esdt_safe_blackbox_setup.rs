@@ -2,12 +2,15 @@ use common_test_setup::base_setup::init::ExpectedLogs;
 use common_test_setup::base_setup::init::{AccountSetup, BaseSetup};
 use common_test_setup::base_setup::log_validations::assert_expected_logs;
 use common_test_setup::constants::{
-    COMPLETE_SETUP_PHASE_ENDPOINT, DEPOSIT_EVENT, ESDT_SAFE_ADDRESS, EXECUTED_BRIDGE_OP_EVENT,
-    FEE_MARKET_ADDRESS, FEE_TOKEN, FIRST_TEST_TOKEN, FIRST_TOKEN_ID, HEADER_VERIFIER_ADDRESS,
-    MVX_ESDT_SAFE_CODE_PATH, NATIVE_TEST_TOKEN, ONE_HUNDRED_MILLION, OWNER_ADDRESS, OWNER_BALANCE,
-    SC_CALL_EVENT, SECOND_TEST_TOKEN, SECOND_TOKEN_ID, SOVEREIGN_FORGE_SC_ADDRESS,
-    SOVEREIGN_TOKEN_PREFIX, TRUSTED_TOKEN, UNPAUSE_CONTRACT_LOG, UPDATE_ESDT_SAFE_CONFIG_ENDPOINT,
-    USER_ADDRESS,
+    COMPLETE_SETUP_PHASE_ENDPOINT, DEPOSIT_EVENT, DEPOSIT_MULTI_ENDPOINT, DESTINATION_CHAIN_ID,
+    DESTINATION_ESDT_SAFE_ADDRESS, DESTINATION_FEE_MARKET_ADDRESS, ESDT_SAFE_ADDRESS,
+    EXECUTED_BRIDGE_OP_EVENT, FEE_MARKET_ADDRESS, FEE_MARKET_CODE_PATH, FEE_TOKEN,
+    FIRST_TEST_TOKEN, FIRST_TOKEN_ID, HEADER_VERIFIER_ADDRESS, MVX_ESDT_SAFE_CODE_PATH,
+    NATIVE_TEST_TOKEN, ONE_HUNDRED_MILLION, OWNER_ADDRESS, OWNER_BALANCE,
+    PATCH_ESDT_SAFE_CONFIG_ENDPOINT, SC_CALL_EVENT, SECOND_TEST_TOKEN, SECOND_TOKEN_ID,
+    SET_ISSUE_COST_ENDPOINT, SOVEREIGN_FORGE_SC_ADDRESS, SOVEREIGN_TOKEN_PREFIX,
+    SUCCESSOR_ESDT_SAFE_ADDRESS, TRUSTED_SFT_TOKEN, TRUSTED_TOKEN, UNPAUSE_CONTRACT_LOG,
+    UPDATE_ESDT_SAFE_CONFIG_ENDPOINT, UPDATE_NFT_METADATA_ENDPOINT, USER_ADDRESS,
 };
 use common_test_setup::log;
 use cross_chain::storage::CrossChainStorage;
@@ -22,9 +25,12 @@ use multiversx_sc::{
 use multiversx_sc_scenario::imports::*;
 use mvx_esdt_safe::MvxEsdtSafe;
 use proxies::mvx_esdt_safe_proxy::MvxEsdtSafeProxy;
+use proxies::mvx_fee_market_proxy::MvxFeeMarketProxy;
 use structs::configs::{
-    PauseStatusOperation, SetBurnMechanismOperation, SetLockMechanismOperation, SovereignConfig,
-    UpdateEsdtSafeConfigOperation,
+    InboundPolicy, MigrateSafeOperation, PatchEsdtSafeConfigOperation, PauseStatusOperation,
+    SetBurnMechanismOperation, SetIssueCostOperation, SetLockMechanismOperation,
+    SetTokenFreezeOperation, SovereignConfig, UpdateEsdtSafeConfigOperation,
+    UpdateNftMetadataOperation,
 };
 use structs::forge::ScArray;
 use structs::OperationHashStatus;
@@ -32,7 +38,7 @@ use structs::{
     aliases::{OptionalValueTransferDataTuple, PaymentsVec},
     configs::EsdtSafeConfig,
     fee::FeeStruct,
-    operation::Operation,
+    operation::{MultiDepositEntry, Operation},
     RegisterTokenOperation,
 };
 
@@ -56,6 +62,11 @@ impl MvxEsdtSafeTestState {
                     0u64,
                     ONE_HUNDRED_MILLION.into(),
                 ),
+                (
+                    TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+                    1u64,
+                    ONE_HUNDRED_MILLION.into(),
+                ),
             ]),
             egld_balance: Some(OWNER_BALANCE.into()),
         };
@@ -103,6 +114,13 @@ impl MvxEsdtSafeTestState {
                     EsdtLocalRole::Mint.name().to_string(),
                 ],
             )
+            .esdt_roles(
+                EsdtTokenIdentifier::from(TRUSTED_SFT_TOKEN),
+                vec![
+                    EsdtLocalRole::NftBurn.name().to_string(),
+                    EsdtLocalRole::NftAddQuantity.name().to_string(),
+                ],
+            )
             .esdt_roles(
                 EsdtTokenIdentifier::from(SECOND_TEST_TOKEN),
                 vec![
@@ -140,6 +158,7 @@ impl MvxEsdtSafeTestState {
             );
 
         self.common_setup.register_trusted_token(TRUSTED_TOKEN);
+        self.common_setup.register_trusted_token(TRUSTED_SFT_TOKEN);
 
         self.common_setup
             .world
@@ -182,6 +201,96 @@ impl MvxEsdtSafeTestState {
         self
     }
 
+    pub fn deploy_successor_safe(&mut self) -> &mut Self {
+        self.common_setup
+            .world
+            .account(SUCCESSOR_ESDT_SAFE_ADDRESS)
+            .nonce(1)
+            .code(MVX_ESDT_SAFE_CODE_PATH)
+            .owner(OWNER_ADDRESS)
+            .esdt_roles(
+                EsdtTokenIdentifier::from(TRUSTED_SFT_TOKEN),
+                vec![
+                    EsdtLocalRole::NftBurn.name().to_string(),
+                    EsdtLocalRole::NftAddQuantity.name().to_string(),
+                ],
+            );
+
+        self.common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SUCCESSOR_ESDT_SAFE_ADDRESS)
+            .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+                sc.init(
+                    OWNER_ADDRESS.to_managed_address(),
+                    SOVEREIGN_FORGE_SC_ADDRESS.to_managed_address(),
+                    SOVEREIGN_TOKEN_PREFIX.into(),
+                    OptionalValue::None,
+                );
+            });
+
+        self.common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SUCCESSOR_ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .set_predecessor_safe(ESDT_SAFE_ADDRESS)
+            .run();
+
+        self
+    }
+
+    /// The destination safe gets its own fee market, charging `fee` if given.
+    pub fn deploy_destination_safe(&mut self, fee: Option<FeeStruct<StaticApi>>) -> &mut Self {
+        self.common_setup
+            .world
+            .account(DESTINATION_ESDT_SAFE_ADDRESS)
+            .nonce(1)
+            .code(MVX_ESDT_SAFE_CODE_PATH)
+            .owner(OWNER_ADDRESS);
+
+        self.common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(MvxFeeMarketProxy)
+            .init(DESTINATION_ESDT_SAFE_ADDRESS, fee)
+            .code(FEE_MARKET_CODE_PATH)
+            .new_address(DESTINATION_FEE_MARKET_ADDRESS)
+            .run();
+
+        self.common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(DESTINATION_ESDT_SAFE_ADDRESS)
+            .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+                sc.init(
+                    OWNER_ADDRESS.to_managed_address(),
+                    SOVEREIGN_FORGE_SC_ADDRESS.to_managed_address(),
+                    DESTINATION_CHAIN_ID.into(),
+                    OptionalValue::None,
+                );
+                sc.native_token()
+                    .set(EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN));
+                sc.fee_market_address()
+                    .set(DESTINATION_FEE_MARKET_ADDRESS.to_managed_address());
+            });
+
+        self.common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(DESTINATION_ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .complete_setup_phase()
+            .run();
+
+        self
+    }
+
     pub fn update_esdt_safe_config_during_setup_phase(
         &mut self,
         new_config: EsdtSafeConfig<StaticApi>,
@@ -202,6 +311,26 @@ impl MvxEsdtSafeTestState {
             .assert_expected_error_message(result, expected_error_message);
     }
 
+    pub fn set_inbound_policy_during_setup_phase(
+        &mut self,
+        inbound_policy: InboundPolicy<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let result = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .set_inbound_policy_setup_phase(OptionalValue::Some(inbound_policy))
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(result, expected_error_message);
+    }
+
     pub fn switch_pause_status(
         &mut self,
         hash_of_hashes: &ManagedBuffer<StaticApi>,
@@ -222,6 +351,172 @@ impl MvxEsdtSafeTestState {
         assert_expected_logs(logs, expected_logs);
     }
 
+    pub fn patch_esdt_safe_config(
+        &mut self,
+        hash_of_hashes: &ManagedBuffer<StaticApi>,
+        patch_config_operation: PatchEsdtSafeConfigOperation<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let (result, logs) = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .patch_esdt_safe_config(hash_of_hashes, patch_config_operation)
+            .returns(ReturnsHandledOrError::new())
+            .returns(ReturnsLogs)
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(result, None);
+
+        let expected_logs = vec![
+            log!(PATCH_ESDT_SAFE_CONFIG_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: expected_error_message),
+        ];
+
+        assert_expected_logs(logs, expected_logs);
+    }
+
+    pub fn update_nft_metadata(
+        &mut self,
+        hash_of_hashes: &ManagedBuffer<StaticApi>,
+        operation: UpdateNftMetadataOperation<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let (result, logs) = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .update_nft_metadata(hash_of_hashes, operation)
+            .returns(ReturnsHandledOrError::new())
+            .returns(ReturnsLogs)
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(result, None);
+
+        let expected_logs = vec![
+            log!(UPDATE_NFT_METADATA_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: expected_error_message),
+        ];
+
+        assert_expected_logs(logs, expected_logs);
+    }
+
+    pub fn fund_issue_pool(
+        &mut self,
+        amount: BigUint<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let result = self
+            .common_setup
+            .world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .fund_issue_pool()
+            .egld(amount)
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(result, expected_error_message);
+    }
+
+    pub fn set_issue_cost(
+        &mut self,
+        hash_of_hashes: &ManagedBuffer<StaticApi>,
+        operation: SetIssueCostOperation<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let (result, logs) = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .set_issue_cost(hash_of_hashes, operation)
+            .returns(ReturnsHandledOrError::new())
+            .returns(ReturnsLogs)
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(result, None);
+
+        let expected_logs = vec![
+            log!(SET_ISSUE_COST_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: expected_error_message),
+        ];
+
+        assert_expected_logs(logs, expected_logs);
+    }
+
+    pub fn set_token_freeze_status(
+        &mut self,
+        hash_of_hashes: &ManagedBuffer<StaticApi>,
+        operation: SetTokenFreezeOperation<StaticApi>,
+        expected_logs: Vec<ExpectedLogs>,
+    ) {
+        let logs = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .set_token_freeze_status(hash_of_hashes, operation)
+            .returns(ReturnsLogs)
+            .run();
+
+        assert_expected_logs(logs, expected_logs);
+    }
+
+    pub fn migrate_safe(
+        &mut self,
+        hash_of_hashes: &ManagedBuffer<StaticApi>,
+        operation: MigrateSafeOperation<StaticApi>,
+        expected_logs: Vec<ExpectedLogs>,
+    ) {
+        let logs = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .migrate_safe(hash_of_hashes, operation)
+            .returns(ReturnsLogs)
+            .run();
+
+        assert_expected_logs(logs, expected_logs);
+    }
+
+    pub fn import_migration_state(
+        &mut self,
+        from_index: usize,
+        page_size: usize,
+        expected_error_message: Option<&str>,
+    ) {
+        let result = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SUCCESSOR_ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .import_migration_state(from_index, page_size)
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(result, expected_error_message);
+    }
+
     pub fn update_esdt_safe_config(
         &mut self,
         hash_of_hashes: &ManagedBuffer<StaticApi>,
@@ -391,6 +686,86 @@ impl MvxEsdtSafeTestState {
         }
     }
 
+    pub fn deposit_with_refund_address(
+        &mut self,
+        to: ManagedAddress<StaticApi>,
+        refund_address: ManagedAddress<StaticApi>,
+        opt_transfer_data: OptionalValueTransferDataTuple<StaticApi>,
+        payment: PaymentsVec<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let result = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .deposit_with_refund_address(to, refund_address, opt_transfer_data)
+            .payment(payment)
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(result, expected_error_message);
+    }
+
+    pub fn deposit_multi(
+        &mut self,
+        entries: Vec<MultiDepositEntry<StaticApi>>,
+        payment: PaymentsVec<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let (logs, result) = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .deposit_multi(MultiValueEncoded::from_iter(entries))
+            .payment(payment)
+            .returns(ReturnsLogs)
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(result, expected_error_message);
+
+        if expected_error_message.is_none() {
+            assert_expected_logs(
+                logs,
+                vec![log!(DEPOSIT_MULTI_ENDPOINT, topics: [DEPOSIT_EVENT])],
+            );
+        }
+    }
+
+    pub fn deposit_multi_with_refund_address(
+        &mut self,
+        refund_address: ManagedAddress<StaticApi>,
+        entries: Vec<MultiDepositEntry<StaticApi>>,
+        payment: PaymentsVec<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let result = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESDT_SAFE_ADDRESS)
+            .typed(MvxEsdtSafeProxy)
+            .deposit_multi_with_refund_address(
+                refund_address,
+                MultiValueEncoded::from_iter(entries),
+            )
+            .payment(payment)
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(result, expected_error_message);
+    }
+
     pub fn register_token(
         &mut self,
         register_token_args: RegisterTokenOperation<StaticApi>,
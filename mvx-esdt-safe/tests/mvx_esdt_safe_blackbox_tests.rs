@@ -1,37 +1,48 @@
 use common_test_setup::base_setup::init::ExpectedLogs;
 use common_test_setup::constants::{
-    CROWD_TOKEN_ID, DEPOSIT_EVENT, ESDT_SAFE_ADDRESS, EXECUTED_BRIDGE_OP_EVENT,
+    CROWD_TOKEN_ID, DEPOSIT_EVENT, DESTINATION_CHAIN_ID, DESTINATION_ESDT_SAFE_ADDRESS,
+    DESTINATION_FEE_MARKET_ADDRESS, ESDT_SAFE_ADDRESS, EXECUTED_BRIDGE_OP_EVENT,
     EXECUTE_BRIDGE_OPS_ENDPOINT, EXECUTE_OPERATION_ENDPOINT, FEE_MARKET_ADDRESS, FEE_TOKEN,
-    FIRST_TEST_TOKEN, FIRST_TOKEN_ID, HEADER_VERIFIER_ADDRESS, ISSUE_COST, NATIVE_TEST_TOKEN,
-    ONE_HUNDRED_MILLION, ONE_HUNDRED_THOUSAND, ONE_HUNDRED_TOKENS, OWNER_ADDRESS,
-    PAUSE_CONTRACT_LOG, PER_GAS, PER_TRANSFER, REGISTER_TOKEN_ENDPOINT, REGISTER_TOKEN_EVENT,
-    SECOND_TEST_TOKEN, SECOND_TOKEN_ID, SOVEREIGN_RECEIVER_ADDRESS, SOV_FIRST_TOKEN_ID,
-    SOV_SECOND_TOKEN_ID, SOV_TOKEN, TESTING_SC_ADDRESS, TESTING_SC_ENDPOINT, TRUSTED_TOKEN,
-    USER_ADDRESS, WRONG_ENDPOINT_NAME,
+    FIRST_TEST_TOKEN, FIRST_TOKEN_ID, HEADER_VERIFIER_ADDRESS, ISSUE_COST, MIGRATE_SAFE_ENDPOINT,
+    NATIVE_TEST_TOKEN, ONE_HUNDRED_MILLION, ONE_HUNDRED_THOUSAND, ONE_HUNDRED_TOKENS,
+    OTHER_SHARD_SUCCESSOR_ADDRESS, OWNER_ADDRESS, PAUSE_CONTRACT_LOG, PER_GAS, PER_TRANSFER,
+    REGISTER_TOKEN_ENDPOINT, REGISTER_TOKEN_EVENT, SECOND_TEST_TOKEN, SECOND_TOKEN_ID,
+    SET_TOKEN_FREEZE_STATUS_LOG, SOVEREIGN_RECEIVER_ADDRESS, SOVEREIGN_TOKEN_PREFIX,
+    SOV_FIRST_TOKEN_ID, SOV_SECOND_TOKEN_ID, SOV_TOKEN, SUCCESSOR_ESDT_SAFE_ADDRESS,
+    TESTING_SC_ADDRESS, TESTING_SC_ENDPOINT, TRUSTED_SFT_TOKEN, TRUSTED_TOKEN, USER_ADDRESS,
+    WRONG_ENDPOINT_NAME,
 };
 use common_test_setup::log;
+use cross_chain::deposit_common::DepositCommonModule;
 use cross_chain::storage::CrossChainStorage;
+use cross_chain::LibCommon;
 use cross_chain::{DEFAULT_ISSUE_COST, MAX_GAS_PER_TRANSACTION};
 use error_messages::{
     BANNED_ENDPOINT_NAME, CALLER_IS_BLACKLISTED, CALLER_NOT_FROM_CURRENT_SOVEREIGN,
-    CURRENT_OPERATION_NOT_REGISTERED, DEPOSIT_AMOUNT_NOT_ENOUGH, DEPOSIT_OVER_MAX_AMOUNT,
-    ERR_EMPTY_PAYMENTS, ESDT_SAFE_STILL_PAUSED, GAS_LIMIT_TOO_HIGH, INVALID_FUNCTION_NOT_FOUND,
-    INVALID_PREFIX_FOR_REGISTER, INVALID_TYPE, MAX_GAS_LIMIT_PER_TX_EXCEEDED,
-    MINT_AND_BURN_ROLES_NOT_FOUND, NATIVE_TOKEN_ALREADY_REGISTERED, NATIVE_TOKEN_NOT_REGISTERED,
-    NOTHING_TO_TRANSFER, NOT_ENOUGH_EGLD_FOR_REGISTER, PAYMENT_DOES_NOT_COVER_FEE,
-    SETUP_PHASE_NOT_COMPLETED, TOKEN_ID_IS_NOT_TRUSTED, TOO_MANY_TOKENS,
+    CURRENT_OPERATION_NOT_REGISTERED, DEPOSIT_AMOUNT_NOT_ENOUGH, DEPOSIT_ENTRIES_PAYMENTS_MISMATCH,
+    DEPOSIT_OVER_MAX_AMOUNT, DEPOSIT_UNDER_MIN_AMOUNT, DESTINATION_ESDT_SAFE_NOT_FOUND,
+    DESTINATION_FEE_NOT_COVERED, ERR_EMPTY_PAYMENTS, ESDT_SAFE_STILL_PAUSED,
+    EXECUTION_OVER_MAX_AMOUNT, GAS_LIMIT_TOO_HIGH, INVALID_FUNCTION_NOT_FOUND, INVALID_ISSUE_COST,
+    INVALID_PAYMENT_INDEX, INVALID_PREFIX_FOR_REGISTER, INVALID_REFUND_ADDRESS, INVALID_TYPE,
+    ISSUE_POOL_FUNDING_AMOUNT_ZERO, MAX_GAS_LIMIT_PER_TX_EXCEEDED, MINT_AND_BURN_ROLES_NOT_FOUND,
+    MIN_BRIDGED_AMOUNT_ABOVE_MAX, NATIVE_TOKEN_ALREADY_REGISTERED, NATIVE_TOKEN_NOT_REGISTERED,
+    NFT_NONCE_NOT_REGISTERED, NOTHING_TO_TRANSFER, NOT_ENOUGH_EGLD_FOR_REGISTER,
+    PAYMENT_DOES_NOT_COVER_FEE, RECEIVER_IS_BLACKLISTED, SAFE_ALREADY_MIGRATED, SAFE_NOT_MIGRATED,
+    SC_CALL_TARGET_NOT_ALLOWED, SETUP_PHASE_NOT_COMPLETED, SUCCESSOR_IN_OTHER_SHARD,
+    TARGET_ENDPOINT_NOT_ALLOWED, TOKEN_FROZEN, TOKEN_ID_IS_NOT_TRUSTED, TOKEN_NOT_ACCEPTED_AS_FEE,
+    TOKEN_NOT_WHITELISTED, TOO_MANY_TOKENS,
 };
 use header_verifier::storage::HeaderVerifierStorageModule;
-use multiversx_sc::chain_core::EGLD_000000_TOKEN_IDENTIFIER;
+use multiversx_sc::codec::{top_encode_to_vec_u8_or_panic, TopDecode};
 use multiversx_sc::types::{
     EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, MultiEgldOrEsdtPayment, MultiValueEncoded,
-    ReturnsHandledOrError,
+    ReturnsHandledOrError, ReturnsResult,
 };
 use multiversx_sc::{
     imports::{MultiValue3, OptionalValue},
     types::{
         BigUint, EsdtTokenData, EsdtTokenIdentifier, EsdtTokenPayment, EsdtTokenType,
-        ManagedBuffer, ManagedVec, TestTokenIdentifier,
+        ManagedAddress, ManagedBuffer, ManagedVec, TestTokenIdentifier,
     },
 };
 use multiversx_sc_modules::pause::PauseModule;
@@ -39,12 +50,25 @@ use multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::sha256;
 use multiversx_sc_scenario::ScenarioTxRun;
 use multiversx_sc_scenario::{api::StaticApi, ScenarioTxWhitebox};
 use mvx_esdt_safe::bridging_mechanism::BridgingMechanism;
+use mvx_esdt_safe::execute::ExecuteModule;
+use mvx_esdt_safe::inbound_policy::InboundPolicyModule;
+use mvx_esdt_safe::issue_pool::IssuePoolModule;
+use mvx_esdt_safe::migration::MigrationModule;
+use mvx_esdt_safe::register_token::RegisterTokenModule;
+use mvx_esdt_safe::reserves::ReservesModule;
+use mvx_esdt_safe::token_freeze::TokenFreezeModule;
+use mvx_esdt_safe::token_registry::TokenRegistryModule;
+use mvx_esdt_safe::MvxEsdtSafe;
 use mvx_esdt_safe_blackbox_setup::MvxEsdtSafeTestState;
 use proxies::mvx_esdt_safe_proxy::MvxEsdtSafeProxy;
+use proxies::mvx_fee_market_proxy::MvxFeeMarketProxy;
 use setup_phase::SetupPhaseModule;
 use structs::configs::{
-    MaxBridgedAmount, PauseStatusOperation, SetBurnMechanismOperation, SetLockMechanismOperation,
-    SovereignConfig, UpdateEsdtSafeConfigOperation,
+    AllowedTarget, EsdtSafeConfigPatch, InboundPolicy, LegacyEsdtSafeConfig, MaxBridgedAmount,
+    MigrateSafeOperation, MinBridgedAmount, NftMetadata, PatchEsdtSafeConfigOperation,
+    PauseStatusOperation, SetBurnMechanismOperation, SetIssueCostOperation,
+    SetLockMechanismOperation, SetTokenFreezeOperation, SovereignConfig,
+    UpdateEsdtSafeConfigOperation, UpdateNftMetadataOperation,
 };
 use structs::fee::{FeeStruct, FeeType};
 use structs::forge::ScArray;
@@ -53,9 +77,11 @@ use structs::operation::TransferData;
 use structs::{
     aliases::PaymentsVec,
     configs::EsdtSafeConfig,
-    operation::{Operation, OperationData, OperationEsdtPayment},
+    operation::{MultiDepositEntry, Operation, OperationData, OperationEsdtPayment},
+};
+use structs::{
+    EsdtInfo, OperationHashStatus, RegisterTokenOperation, TokenMechanism, DEFAULT_MAX_TX_GAS_LIMIT,
 };
-use structs::{OperationHashStatus, RegisterTokenOperation};
 mod mvx_esdt_safe_blackbox_setup;
 
 /// ### TEST
@@ -127,17 +153,9 @@ fn test_register_token_invalid_type() {
     let bitmap = state.common_setup.full_bitmap(1);
     let epoch = 0;
 
-    let payment =
-        EgldOrEsdtTokenPayment::new(EGLD_000000_TOKEN_IDENTIFIER.into(), 0u64, ISSUE_COST.into());
-
     let signature = state.deploy_and_complete_setup_phase(&hash_of_hashes);
 
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        ManagedVec::from_single_item(payment),
-        None,
-    );
+    state.fund_issue_pool(ISSUE_COST.into(), None);
 
     state.common_setup.register_operation(
         OWNER_ADDRESS,
@@ -197,17 +215,9 @@ fn test_register_token_invalid_type_with_prefix() {
     let bitmap = state.common_setup.full_bitmap(1);
     let epoch = 0;
 
-    let payment =
-        EgldOrEsdtTokenPayment::new(EGLD_000000_TOKEN_IDENTIFIER.into(), 0u64, ISSUE_COST.into());
-
     let signature = state.deploy_and_complete_setup_phase(&hash_of_hashes);
 
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        ManagedVec::from_single_item(payment),
-        None,
-    );
+    state.fund_issue_pool(ISSUE_COST.into(), None);
 
     state.common_setup.register_operation(
         OWNER_ADDRESS,
@@ -321,16 +331,9 @@ fn test_register_token_fungible_token() {
 
     let epoch = 0;
 
-    let payment = EgldOrEsdtTokenPayment::egld_payment(ISSUE_COST.into());
-
     let signature = state.deploy_and_complete_setup_phase(&hash_of_hashes);
 
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        ManagedVec::from_single_item(payment),
-        None,
-    );
+    state.fund_issue_pool(ISSUE_COST.into(), None);
 
     state.common_setup.register_operation(
         OWNER_ADDRESS,
@@ -381,15 +384,7 @@ fn test_register_token_nonfungible_token() {
 
     let signature = state.deploy_and_complete_setup_phase(&hash_of_hashes);
 
-    let payment =
-        EgldOrEsdtTokenPayment::new(EGLD_000000_TOKEN_IDENTIFIER.into(), 0u64, ISSUE_COST.into());
-
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        ManagedVec::from_single_item(payment),
-        None,
-    );
+    state.fund_issue_pool(ISSUE_COST.into(), None);
 
     state.common_setup.register_operation(
         OWNER_ADDRESS,
@@ -745,6 +740,95 @@ fn test_deposit_max_bridged_amount_exceeded() {
         .check_account_multiple_esdts(ESDT_SAFE_ADDRESS.to_address(), tokens_vec);
 }
 
+/// ### TEST
+/// M-ESDT_DEP_FAIL
+///
+/// ### ACTION
+/// Call 'deposit()' with an amount under the min bridged amount
+///
+/// ### EXPECTED
+/// Error DEPOSIT_UNDER_MIN_AMOUNT
+#[test]
+fn test_deposit_under_min_bridged_amount() {
+    let mut state = MvxEsdtSafeTestState::new();
+
+    let config = EsdtSafeConfig {
+        min_bridged_token_amounts: ManagedVec::from(vec![MinBridgedAmount {
+            token_id: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            amount: BigUint::from(ONE_HUNDRED_THOUSAND),
+        }]),
+        ..EsdtSafeConfig::default_config()
+    };
+
+    state
+        .common_setup
+        .deploy_mvx_esdt_safe(OptionalValue::Some(config));
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            sc.native_token()
+                .set(EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN));
+        });
+
+    state
+        .common_setup
+        .deploy_fee_market(None, ESDT_SAFE_ADDRESS);
+    state.common_setup.deploy_testing_sc();
+    state.set_fee_market_address(FEE_MARKET_ADDRESS);
+    state.complete_setup_phase();
+
+    let payments_vec = PaymentsVec::from(vec![EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(FIRST_TEST_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND - 1),
+    )]);
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        payments_vec,
+        Some(DEPOSIT_UNDER_MIN_AMOUNT),
+    );
+
+    state.common_setup.check_account_multiple_esdts(
+        ESDT_SAFE_ADDRESS.to_address(),
+        vec![MultiValue3::from((FIRST_TEST_TOKEN, 0u64, BigUint::zero()))],
+    );
+}
+
+/// ### TEST
+/// M-ESDT_DEPLOY_FAIL
+///
+/// ### ACTION
+/// Call 'update_configuration()' with a min bridged amount above the max bridged amount
+///
+/// ### EXPECTED
+/// Error MIN_BRIDGED_AMOUNT_ABOVE_MAX
+#[test]
+fn test_update_config_min_bridged_amount_above_max() {
+    let mut state = MvxEsdtSafeTestState::new();
+
+    state.deploy_contract_with_roles(None);
+
+    let config = EsdtSafeConfig {
+        max_bridged_token_amounts: ManagedVec::from(vec![MaxBridgedAmount {
+            token_id: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            amount: BigUint::from(ONE_HUNDRED_THOUSAND),
+        }]),
+        min_bridged_token_amounts: ManagedVec::from(vec![MinBridgedAmount {
+            token_id: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            amount: BigUint::from(ONE_HUNDRED_THOUSAND + 1),
+        }]),
+        ..EsdtSafeConfig::default_config()
+    };
+
+    state.update_esdt_safe_config_during_setup_phase(config, Some(MIN_BRIDGED_AMOUNT_ABOVE_MAX));
+}
+
 /// ### TEST
 /// M-ESDT_DEP_FAIL
 ///
@@ -827,6 +911,81 @@ fn test_deposit_endpoint_banned() {
         .check_account_multiple_esdts(ESDT_SAFE_ADDRESS.to_address(), tokens_vec);
 }
 
+/// ### TEST
+/// M-ESDT_DEP_FAIL
+///
+/// ### ACTION
+/// Call 'deposit()' with transfer data for a target outside the allowlist
+///
+/// ### EXPECTED
+/// Error SC_CALL_TARGET_NOT_ALLOWED, the allowed target can still be called
+#[test]
+fn test_deposit_sc_call_target_not_allowed() {
+    let mut state = MvxEsdtSafeTestState::new();
+
+    let config = EsdtSafeConfig {
+        allowed_targets: ManagedVec::from(vec![AllowedTarget {
+            address: TESTING_SC_ADDRESS.to_managed_address(),
+            endpoints: ManagedVec::from(vec![ManagedBuffer::from(TESTING_SC_ENDPOINT)]),
+        }]),
+        ..EsdtSafeConfig::default_config()
+    };
+
+    state
+        .common_setup
+        .deploy_mvx_esdt_safe(OptionalValue::Some(config));
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            sc.native_token()
+                .set(EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN));
+        });
+
+    state
+        .common_setup
+        .deploy_fee_market(None, ESDT_SAFE_ADDRESS);
+    state.common_setup.deploy_testing_sc();
+    state.set_fee_market_address(FEE_MARKET_ADDRESS);
+    state.complete_setup_phase();
+
+    let payments_vec = PaymentsVec::from(vec![EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(FIRST_TEST_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    )]);
+
+    let transfer_data = MultiValue3::from((
+        1u64,
+        ManagedBuffer::from(TESTING_SC_ENDPOINT),
+        MultiValueEncoded::new(),
+    ));
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::Some(transfer_data.clone()),
+        payments_vec.clone(),
+        Some(SC_CALL_TARGET_NOT_ALLOWED),
+    );
+
+    state.deposit(
+        TESTING_SC_ADDRESS.to_managed_address(),
+        OptionalValue::Some(transfer_data),
+        payments_vec,
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+}
+
 /// ### TEST
 /// M-ESDT_DEP_OK
 ///
@@ -1332,17 +1491,9 @@ fn test_register_token_fungible_token_with_prefix() {
     let bitmap = state.common_setup.full_bitmap(1);
     let epoch = 0;
 
-    let payment =
-        EgldOrEsdtTokenPayment::new(EGLD_000000_TOKEN_IDENTIFIER.into(), 0u64, ISSUE_COST.into());
-
     let signature = state.deploy_and_complete_setup_phase(&hash_of_hashes);
 
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        ManagedVec::from_single_item(payment),
-        None,
-    );
+    state.fund_issue_pool(ISSUE_COST.into(), None);
 
     state.common_setup.register_operation(
         OWNER_ADDRESS,
@@ -1392,17 +1543,9 @@ fn test_register_token_fungible_token_no_prefix() {
     let bitmap = state.common_setup.full_bitmap(1);
     let epoch = 0;
 
-    let payment =
-        EgldOrEsdtTokenPayment::new(EGLD_000000_TOKEN_IDENTIFIER.into(), 0u64, ISSUE_COST.into());
-
     let signature = state.deploy_and_complete_setup_phase(&hash_of_hashes);
 
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        ManagedVec::from_single_item(payment),
-        None,
-    );
+    state.fund_issue_pool(ISSUE_COST.into(), None);
 
     state.common_setup.register_operation(
         OWNER_ADDRESS,
@@ -1457,17 +1600,9 @@ fn test_register_token_non_fungible_token_dynamic() {
     let bitmap = state.common_setup.full_bitmap(1);
     let epoch = 0;
 
-    let payment =
-        EgldOrEsdtTokenPayment::new(EGLD_000000_TOKEN_IDENTIFIER.into(), 0u64, ISSUE_COST.into());
-
     let signature = state.deploy_and_complete_setup_phase(&hash_of_hashes);
 
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        ManagedVec::from_single_item(payment),
-        None,
-    );
+    state.fund_issue_pool(ISSUE_COST.into(), None);
 
     state.common_setup.register_operation(
         OWNER_ADDRESS,
@@ -1734,34 +1869,42 @@ fn test_execute_operation_success() {
 /// M-ESDT_EXEC_FAIL
 ///
 /// ### ACTION
-/// Call 'execute_operation()' with payments and transfer data that exceeds the max gas limit
+/// Call 'execute_operation()' with transfer data for an endpoint outside the allowlist
 ///
 /// ### EXPECTED
-/// Error GAS_LIMIT_TOO_HIGH, operation does not execute
+/// Error SC_CALL_TARGET_NOT_ALLOWED, the tokens stay in the safe and a refund is emitted
 #[test]
-fn test_execute_operation_transfer_data_gas_limit_too_high_with_payments() {
+fn test_execute_operation_sc_call_target_not_allowed() {
     let mut state = MvxEsdtSafeTestState::new();
 
     state.deploy_contract_with_roles(None);
+    state.update_esdt_safe_config_during_setup_phase(
+        EsdtSafeConfig {
+            allowed_targets: ManagedVec::from(vec![AllowedTarget {
+                address: TESTING_SC_ADDRESS.to_managed_address(),
+                endpoints: ManagedVec::from(vec![ManagedBuffer::from(WRONG_ENDPOINT_NAME)]),
+            }]),
+            ..EsdtSafeConfig::default_config()
+        },
+        None,
+    );
     state.complete_setup_phase();
 
     let token_data = EsdtTokenData {
         amount: BigUint::from(ONE_HUNDRED_THOUSAND),
         ..Default::default()
     };
-
     let payment = OperationEsdtPayment::new(
         EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
         0,
         token_data,
     );
 
-    let gas_limit = MAX_GAS_PER_TRANSACTION + 1;
-    let function = ManagedBuffer::<StaticApi>::from(TESTING_SC_ENDPOINT);
-    let args =
-        ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::from(vec![ManagedBuffer::from("1")]);
-
-    let transfer_data = TransferData::new(gas_limit, function, args);
+    let transfer_data = TransferData::new(
+        1,
+        ManagedBuffer::from(TESTING_SC_ENDPOINT),
+        ManagedVec::from(vec![ManagedBuffer::from("1")]),
+    );
 
     let operation = Operation::new(
         TESTING_SC_ADDRESS.to_managed_address(),
@@ -1773,14 +1916,13 @@ fn test_execute_operation_transfer_data_gas_limit_too_high_with_payments() {
         ),
     );
 
-    let operation_hash = state.common_setup.get_operation_hash(&operation);
-    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
-
     state.common_setup.deploy_chain_config(
         OptionalValue::Some(SovereignConfig::default_config_for_test()),
         None,
     );
 
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
     let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
 
     state.common_setup.register(
@@ -1798,11 +1940,21 @@ fn test_execute_operation_transfer_data_gas_limit_too_high_with_payments() {
         .complete_header_verifier_setup_phase(None);
     state.common_setup.deploy_testing_sc();
 
-    let expected_logs = vec![log!(
-        EXECUTE_BRIDGE_OPS_ENDPOINT,
-        topics: [EXECUTED_BRIDGE_OP_EVENT],
-        data: Some(GAS_LIMIT_TOO_HIGH)
-    )];
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        ManagedVec::from_single_item(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            0,
+            BigUint::from(ONE_HUNDRED_THOUSAND),
+        )),
+        None,
+    );
+
+    let expected_logs = vec![
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [DEPOSIT_EVENT]),
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(SC_CALL_TARGET_NOT_ALLOWED)),
+    ];
 
     state.register_and_execute_operation(
         &operation,
@@ -1812,49 +1964,56 @@ fn test_execute_operation_transfer_data_gas_limit_too_high_with_payments() {
         expected_logs,
         true,
     );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
 }
 
 /// ### TEST
-/// M-ESDT_EXEC_OK
+/// M-ESDT_EXEC_ROUTE_OK
 ///
 /// ### ACTION
-/// Call 'execute_operation()' with payment containing the registered token
+/// Call 'execute_operation()' with an operation targeting another sovereign chain
 ///
 /// ### EXPECTED
-/// The operation is executed in the testing smart contract
+/// The tokens are deposited in the destination sovereign's safe on behalf of the receiver
 #[test]
-fn test_execute_operation_with_native_token_success() {
+fn test_execute_operation_routed_to_destination_sovereign() {
     let mut state = MvxEsdtSafeTestState::new();
 
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
+    state.deploy_destination_safe(None);
+    state
+        .common_setup
+        .set_deployed_sovereign_contracts_in_forge(
+            DESTINATION_CHAIN_ID,
+            vec![(ScArray::ESDTSafe, DESTINATION_ESDT_SAFE_ADDRESS)],
+        );
 
     let token_data = EsdtTokenData {
         amount: BigUint::from(ONE_HUNDRED_THOUSAND),
         ..Default::default()
     };
-
     let payment = OperationEsdtPayment::new(
-        EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN),
+        EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
         0,
         token_data,
     );
 
-    let gas_limit = 1;
-    let function = ManagedBuffer::<StaticApi>::from(TESTING_SC_ENDPOINT);
-    let args =
-        ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::from(vec![ManagedBuffer::from("1")]);
-
-    let transfer_data = TransferData::new(gas_limit, function, args);
-
     let operation = Operation::new(
-        TESTING_SC_ADDRESS.to_managed_address(),
+        USER_ADDRESS.to_managed_address(),
         vec![payment].into(),
         OperationData::new(
             state.common_setup.next_operation_nonce(),
             OWNER_ADDRESS.to_managed_address(),
-            Some(transfer_data),
-        ),
+            None,
+        )
+        .with_destination_chain_id(Some(DESTINATION_CHAIN_ID.into())),
     );
 
     state.common_setup.deploy_chain_config(
@@ -1871,7 +2030,6 @@ fn test_execute_operation_with_native_token_success() {
         &MultiEgldOrEsdtPayment::new(),
         None,
     );
-
     state.common_setup.complete_chain_config_setup_phase();
 
     state
@@ -1880,90 +2038,93 @@ fn test_execute_operation_with_native_token_success() {
     state
         .common_setup
         .complete_header_verifier_setup_phase(None);
-    state.common_setup.deploy_testing_sc();
 
-    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
-
-    state.register_and_execute_operation(
-        &operation,
-        &hash_of_hashes,
-        signature,
-        1,
-        expected_logs,
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        ManagedVec::from_single_item(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            0,
+            BigUint::from(ONE_HUNDRED_THOUSAND),
+        )),
+        None,
+    );
+
+    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
+
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
         true,
     );
 
     state.common_setup.check_account_single_esdt(
-        TESTING_SC_ADDRESS.to_address(),
-        NATIVE_TEST_TOKEN,
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::zero(),
+    );
+    state.common_setup.check_account_single_esdt(
+        DESTINATION_ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
         0u64,
         BigUint::from(ONE_HUNDRED_THOUSAND),
     );
 }
 
 /// ### TEST
-/// M-ESDT_EXEC_OK
+/// M-ESDT_EXEC_ROUTE_FAIL
 ///
 /// ### ACTION
-/// Call 'execute_operation()' after setting the burn mechanism without prior deposit
+/// Call 'execute_operation()' targeting a sovereign chain without a deployed ESDT safe
 ///
 /// ### EXPECTED
-/// The operation executes successfully with minted tokens
+/// Error DESTINATION_ESDT_SAFE_NOT_FOUND, the tokens stay in the safe and a refund is emitted
 #[test]
-fn test_execute_operation_burn_mechanism_without_deposit() {
+fn test_execute_operation_routed_destination_safe_not_found() {
     let mut state = MvxEsdtSafeTestState::new();
+
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
 
+    let token_data = EsdtTokenData {
+        amount: BigUint::from(ONE_HUNDRED_THOUSAND),
+        ..Default::default()
+    };
     let payment = OperationEsdtPayment::new(
-        EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
+        EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
         0,
-        EsdtTokenData {
-            amount: BigUint::from(ONE_HUNDRED_THOUSAND),
-            ..Default::default()
-        },
+        token_data,
     );
 
-    let burn_operation = SetBurnMechanismOperation {
-        token_id: EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
-        nonce: state.common_setup.next_operation_nonce(),
-    };
-    let burn_operation_hash = burn_operation.generate_hash();
-    let burn_hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&burn_operation_hash.to_vec()));
-
     let operation = Operation::new(
-        SOVEREIGN_RECEIVER_ADDRESS.to_managed_address(),
+        USER_ADDRESS.to_managed_address(),
         vec![payment].into(),
         OperationData::new(
             state.common_setup.next_operation_nonce(),
             OWNER_ADDRESS.to_managed_address(),
             None,
-        ),
+        )
+        .with_destination_chain_id(Some(DESTINATION_CHAIN_ID.into())),
     );
-    let operation_hash = state.common_setup.get_operation_hash(&operation);
-    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
 
-    // Deploy and register validators
     state.common_setup.deploy_chain_config(
         OptionalValue::Some(SovereignConfig::default_config_for_test()),
         None,
     );
 
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
     let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
-    let (signature_burn, public_keys_burn) = state
-        .common_setup
-        .get_sig_and_pub_keys(1, &burn_hash_of_hashes);
 
     state.common_setup.register(
         public_keys.first().unwrap(),
         &MultiEgldOrEsdtPayment::new(),
         None,
     );
-    state.common_setup.register(
-        public_keys_burn.first().unwrap(),
-        &MultiEgldOrEsdtPayment::new(),
-        None,
-    );
     state.common_setup.complete_chain_config_setup_phase();
 
     state
@@ -1972,65 +2133,89 @@ fn test_execute_operation_burn_mechanism_without_deposit() {
     state
         .common_setup
         .complete_header_verifier_setup_phase(None);
-    state.common_setup.deploy_testing_sc();
-
-    state.common_setup.register_operation(
-        OWNER_ADDRESS,
-        signature,
-        &hash_of_hashes,
-        state.common_setup.full_bitmap(1),
-        0,
-        MultiValueEncoded::from(ManagedVec::from(vec![operation_hash.clone()])),
-    );
 
-    state.common_setup.register_operation(
-        OWNER_ADDRESS,
-        signature_burn,
-        &burn_hash_of_hashes,
-        state.common_setup.bitmap_for_signers(&[1]),
-        0,
-        MultiValueEncoded::from(ManagedVec::from(vec![burn_operation_hash])),
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        ManagedVec::from_single_item(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            0,
+            BigUint::from(ONE_HUNDRED_THOUSAND),
+        )),
+        None,
     );
 
-    state.set_token_burn_mechanism(&burn_hash_of_hashes, burn_operation);
     let expected_logs = vec![
-        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(DEPOSIT_AMOUNT_NOT_ENOUGH)),
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [DEPOSIT_EVENT]),
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(DESTINATION_ESDT_SAFE_NOT_FOUND)),
     ];
-    state.execute_operation(&hash_of_hashes, &operation, expected_logs);
 
-    state
-        .common_setup
-        .check_operation_hash_status_is_empty(&operation_hash);
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        true,
+    );
+
     state.common_setup.check_account_single_esdt(
-        TESTING_SC_ADDRESS.to_address(),
-        TestTokenIdentifier::new(TRUSTED_TOKEN),
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
         0u64,
-        BigUint::zero(),
+        BigUint::from(ONE_HUNDRED_THOUSAND),
     );
 }
 
 /// ### TEST
-/// M-ESDT_EXEC_FAIL
+/// M-ESDT_EXEC_ROUTE_OK
 ///
 /// ### ACTION
-/// Call 'execute_operation()' with no tokens and no transfer data
+/// Call 'execute_operation()' targeting a sovereign chain whose safe charges a fee in the routed token
 ///
 /// ### EXPECTED
-/// Error NOTHING_TO_TRANSFER
+/// The fee is taken out of the routed tokens and the rest is deposited in the destination sovereign's safe
 #[test]
-fn test_execute_no_transfer_data_no_token_transfer() {
+fn test_execute_operation_routed_destination_fee_deducted() {
     let mut state = MvxEsdtSafeTestState::new();
+
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
+    let fee = FeeStruct {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        fee_type: FeeType::Fixed {
+            token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            per_transfer: PER_TRANSFER.into(),
+            per_gas: PER_GAS.into(),
+        },
+    };
+    state.deploy_destination_safe(Some(fee));
+    state
+        .common_setup
+        .set_deployed_sovereign_contracts_in_forge(
+            DESTINATION_CHAIN_ID,
+            vec![(ScArray::ESDTSafe, DESTINATION_ESDT_SAFE_ADDRESS)],
+        );
+
+    let token_data = EsdtTokenData {
+        amount: BigUint::from(ONE_HUNDRED_THOUSAND),
+        ..Default::default()
+    };
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        0,
+        token_data,
+    );
 
     let operation = Operation::new(
-        TESTING_SC_ADDRESS.to_managed_address(),
-        ManagedVec::new(),
+        USER_ADDRESS.to_managed_address(),
+        vec![payment].into(),
         OperationData::new(
             state.common_setup.next_operation_nonce(),
             OWNER_ADDRESS.to_managed_address(),
             None,
-        ),
+        )
+        .with_destination_chain_id(Some(DESTINATION_CHAIN_ID.into())),
     );
 
     state.common_setup.deploy_chain_config(
@@ -2047,24 +2232,27 @@ fn test_execute_no_transfer_data_no_token_transfer() {
         &MultiEgldOrEsdtPayment::new(),
         None,
     );
-
     state.common_setup.complete_chain_config_setup_phase();
 
     state
         .common_setup
         .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
-
     state
         .common_setup
         .complete_header_verifier_setup_phase(None);
 
-    state.common_setup.deploy_testing_sc();
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        ManagedVec::from_single_item(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            0,
+            BigUint::from(ONE_HUNDRED_THOUSAND),
+        )),
+        None,
+    );
 
-    let expected_logs = vec![log!(
-        EXECUTE_BRIDGE_OPS_ENDPOINT,
-        topics: [EXECUTED_BRIDGE_OP_EVENT],
-        data: Some(NOTHING_TO_TRANSFER)
-    )];
+    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
 
     state.register_and_execute_operation(
         &operation,
@@ -2074,47 +2262,85 @@ fn test_execute_no_transfer_data_no_token_transfer() {
         expected_logs,
         true,
     );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::zero(),
+    );
+    state.common_setup.check_account_single_esdt(
+        DESTINATION_ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_THOUSAND as u64 - PER_TRANSFER),
+    );
+    state.common_setup.check_account_single_esdt(
+        DESTINATION_FEE_MARKET_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(PER_TRANSFER),
+    );
 }
 
 /// ### TEST
-/// M-ESDT_EXEC_OK
+/// M-ESDT_EXEC_ROUTE_FAIL
 ///
 /// ### ACTION
-/// Call 'execute_operation()' with transfer data only
+/// Call 'execute_operation()' targeting a sovereign chain whose safe charges a fee in a token that is not routed
 ///
 /// ### EXPECTED
-/// The operation is executed in the testing smart contract
+/// Error DESTINATION_FEE_NOT_COVERED, the tokens are not routed and a refund is emitted
 #[test]
-fn test_execute_operation_only_transfer_data_no_fee() {
+fn test_execute_operation_routed_destination_fee_not_covered() {
     let mut state = MvxEsdtSafeTestState::new();
+
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
+    let fee = FeeStruct {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN),
+        fee_type: FeeType::Fixed {
+            token: EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN),
+            per_transfer: PER_TRANSFER.into(),
+            per_gas: PER_GAS.into(),
+        },
+    };
+    state.deploy_destination_safe(Some(fee));
+    state
+        .common_setup
+        .set_deployed_sovereign_contracts_in_forge(
+            DESTINATION_CHAIN_ID,
+            vec![(ScArray::ESDTSafe, DESTINATION_ESDT_SAFE_ADDRESS)],
+        );
 
-    let gas_limit = 40_000_000u64;
-    let function = ManagedBuffer::<StaticApi>::from(TESTING_SC_ENDPOINT);
-    let args =
-        ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::from(vec![ManagedBuffer::from("1")]);
-
-    let transfer_data = TransferData::new(gas_limit, function, args);
+    let token_data = EsdtTokenData {
+        amount: BigUint::from(ONE_HUNDRED_THOUSAND),
+        ..Default::default()
+    };
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        0,
+        token_data,
+    );
 
     let operation = Operation::new(
-        TESTING_SC_ADDRESS.to_managed_address(),
-        ManagedVec::new(),
+        USER_ADDRESS.to_managed_address(),
+        vec![payment].into(),
         OperationData::new(
             state.common_setup.next_operation_nonce(),
             OWNER_ADDRESS.to_managed_address(),
-            Some(transfer_data),
-        ),
+            None,
+        )
+        .with_destination_chain_id(Some(DESTINATION_CHAIN_ID.into())),
     );
 
-    let operation_hash = state.common_setup.get_operation_hash(&operation);
-    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
-
     state.common_setup.deploy_chain_config(
         OptionalValue::Some(SovereignConfig::default_config_for_test()),
         None,
     );
 
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
     let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
 
     state.common_setup.register(
@@ -2122,7 +2348,6 @@ fn test_execute_operation_only_transfer_data_no_fee() {
         &MultiEgldOrEsdtPayment::new(),
         None,
     );
-
     state.common_setup.complete_chain_config_setup_phase();
 
     state
@@ -2132,9 +2357,22 @@ fn test_execute_operation_only_transfer_data_no_fee() {
         .common_setup
         .complete_header_verifier_setup_phase(None);
 
-    state.common_setup.deploy_testing_sc();
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        ManagedVec::from_single_item(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            0,
+            BigUint::from(ONE_HUNDRED_THOUSAND),
+        )),
+        None,
+    );
+
+    let expected_logs = vec![
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [DEPOSIT_EVENT]),
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(DESTINATION_FEE_NOT_COVERED)),
+    ];
 
-    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
     state.register_and_execute_operation(
         &operation,
         &hash_of_hashes,
@@ -2143,23 +2381,48 @@ fn test_execute_operation_only_transfer_data_no_fee() {
         expected_logs,
         true,
     );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+    state.common_setup.check_account_single_esdt(
+        DESTINATION_ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::zero(),
+    );
 }
 
 /// ### TEST
 /// M-ESDT_EXEC_FAIL
 ///
 /// ### ACTION
-/// Call 'execute_operation()' with only transfer data whose gas limit exceeds the max threshold
+/// Call 'execute_operation()' with payments and transfer data that exceeds the max gas limit
 ///
 /// ### EXPECTED
-/// Error GAS_LIMIT_TOO_HIGH, no SC call is performed
+/// Error GAS_LIMIT_TOO_HIGH, operation does not execute
 #[test]
-fn test_execute_operation_only_transfer_data_gas_limit_too_high() {
+fn test_execute_operation_transfer_data_gas_limit_too_high_with_payments() {
     let mut state = MvxEsdtSafeTestState::new();
+
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
 
-    let gas_limit = MAX_GAS_PER_TRANSACTION + 1;
+    let token_data = EsdtTokenData {
+        amount: BigUint::from(ONE_HUNDRED_THOUSAND),
+        ..Default::default()
+    };
+
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        0,
+        token_data,
+    );
+
+    let gas_limit = MAX_GAS_PER_TRANSACTION + 1;
     let function = ManagedBuffer::<StaticApi>::from(TESTING_SC_ENDPOINT);
     let args =
         ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::from(vec![ManagedBuffer::from("1")]);
@@ -2168,7 +2431,7 @@ fn test_execute_operation_only_transfer_data_gas_limit_too_high() {
 
     let operation = Operation::new(
         TESTING_SC_ADDRESS.to_managed_address(),
-        ManagedVec::new(),
+        vec![payment].into(),
         OperationData::new(
             state.common_setup.next_operation_nonce(),
             OWNER_ADDRESS.to_managed_address(),
@@ -2191,7 +2454,6 @@ fn test_execute_operation_only_transfer_data_gas_limit_too_high() {
         &MultiEgldOrEsdtPayment::new(),
         None,
     );
-
     state.common_setup.complete_chain_config_setup_phase();
 
     state
@@ -2200,7 +2462,6 @@ fn test_execute_operation_only_transfer_data_gas_limit_too_high() {
     state
         .common_setup
         .complete_header_verifier_setup_phase(None);
-
     state.common_setup.deploy_testing_sc();
 
     let expected_logs = vec![log!(
@@ -2223,22 +2484,108 @@ fn test_execute_operation_only_transfer_data_gas_limit_too_high() {
 /// M-ESDT_EXEC_OK
 ///
 /// ### ACTION
-/// Call 'execute_operation()' with burn mechanism after deposit
+/// Call 'execute_operation()' with payment containing the registered token
 ///
 /// ### EXPECTED
-/// The operation executes successfully, tokens are burned
+/// The operation is executed in the testing smart contract
 #[test]
-fn test_execute_operation_success_burn_mechanism() {
+fn test_execute_operation_with_native_token_success() {
+    let mut state = MvxEsdtSafeTestState::new();
+
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    let token_data = EsdtTokenData {
+        amount: BigUint::from(ONE_HUNDRED_THOUSAND),
+        ..Default::default()
+    };
+
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN),
+        0,
+        token_data,
+    );
+
+    let gas_limit = 1;
+    let function = ManagedBuffer::<StaticApi>::from(TESTING_SC_ENDPOINT);
+    let args =
+        ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::from(vec![ManagedBuffer::from("1")]);
+
+    let transfer_data = TransferData::new(gas_limit, function, args);
+
+    let operation = Operation::new(
+        TESTING_SC_ADDRESS.to_managed_address(),
+        vec![payment].into(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            OWNER_ADDRESS.to_managed_address(),
+            Some(transfer_data),
+        ),
+    );
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+    state.common_setup.deploy_testing_sc();
+
+    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
+
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        true,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        TESTING_SC_ADDRESS.to_address(),
+        NATIVE_TEST_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_OK
+///
+/// ### ACTION
+/// Call 'execute_operation()' after setting the burn mechanism without prior deposit
+///
+/// ### EXPECTED
+/// The operation executes successfully with minted tokens
+#[test]
+fn test_execute_operation_burn_mechanism_without_deposit() {
     let mut state = MvxEsdtSafeTestState::new();
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
 
-    let amount = BigUint::from(ONE_HUNDRED_THOUSAND);
     let payment = OperationEsdtPayment::new(
         EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
         0,
         EsdtTokenData {
-            amount: amount.clone(),
+            amount: BigUint::from(ONE_HUNDRED_THOUSAND),
             ..Default::default()
         },
     );
@@ -2251,8 +2598,8 @@ fn test_execute_operation_success_burn_mechanism() {
     let burn_hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&burn_operation_hash.to_vec()));
 
     let operation = Operation::new(
-        TESTING_SC_ADDRESS.to_managed_address(),
-        vec![payment.clone()].into(),
+        SOVEREIGN_RECEIVER_ADDRESS.to_managed_address(),
+        vec![payment].into(),
         OperationData::new(
             state.common_setup.next_operation_nonce(),
             OWNER_ADDRESS.to_managed_address(),
@@ -2262,6 +2609,7 @@ fn test_execute_operation_success_burn_mechanism() {
     let operation_hash = state.common_setup.get_operation_hash(&operation);
     let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
 
+    // Deploy and register validators
     state.common_setup.deploy_chain_config(
         OptionalValue::Some(SovereignConfig::default_config_for_test()),
         None,
@@ -2292,13 +2640,6 @@ fn test_execute_operation_success_burn_mechanism() {
         .complete_header_verifier_setup_phase(None);
     state.common_setup.deploy_testing_sc();
 
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        PaymentsVec::from(vec![payment]),
-        None,
-    );
-
     state.common_setup.register_operation(
         OWNER_ADDRESS,
         signature,
@@ -2308,10 +2649,6 @@ fn test_execute_operation_success_burn_mechanism() {
         MultiValueEncoded::from(ManagedVec::from(vec![operation_hash.clone()])),
     );
 
-    state
-        .common_setup
-        .check_operation_hash_status(&operation_hash, OperationHashStatus::NotLocked);
-
     state.common_setup.register_operation(
         OWNER_ADDRESS,
         signature_burn,
@@ -2322,339 +2659,86 @@ fn test_execute_operation_success_burn_mechanism() {
     );
 
     state.set_token_burn_mechanism(&burn_hash_of_hashes, burn_operation);
-    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
+    let expected_logs = vec![
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(DEPOSIT_AMOUNT_NOT_ENOUGH)),
+    ];
     state.execute_operation(&hash_of_hashes, &operation, expected_logs);
 
     state
         .common_setup
-        .world
-        .check_account(OWNER_ADDRESS)
-        .esdt_balance(
-            EsdtTokenIdentifier::from(TRUSTED_TOKEN),
-            &(BigUint::from(ONE_HUNDRED_MILLION) - &amount),
-        );
-
-    state.common_setup.check_account_single_esdt(
-        ESDT_SAFE_ADDRESS.to_address(),
-        TestTokenIdentifier::new(TRUSTED_TOKEN),
-        0u64,
-        BigUint::zero(),
-    );
-
-    state
-        .common_setup
-        .check_deposited_tokens_amount(vec![(EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN), 0)]);
-
+        .check_operation_hash_status_is_empty(&operation_hash);
     state.common_setup.check_account_single_esdt(
         TESTING_SC_ADDRESS.to_address(),
         TestTokenIdentifier::new(TRUSTED_TOKEN),
         0u64,
-        amount,
+        BigUint::zero(),
     );
-
-    state
-        .common_setup
-        .check_operation_hash_status_is_empty(&operation_hash);
 }
 
 /// ### TEST
-/// M-ESDT_EXEC_OK
+/// M-ESDT_EXEC_FAIL
 ///
 /// ### ACTION
-/// Call 'execute_operation()' after switching between LOCK and BURN mechanisms
+/// Call 'execute_operation()' with no tokens and no transfer data
 ///
 /// ### EXPECTED
-/// - Operations execute successfully in different mechanism states
-/// - Token balances are tracked correctly during mechanism switches
+/// Error NOTHING_TO_TRANSFER
 #[test]
-fn test_deposit_execute_switch_mechanism() {
+fn test_execute_no_transfer_data_no_token_transfer() {
     let mut state = MvxEsdtSafeTestState::new();
-
-    // === Initial Setup ===
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
 
-    let chain_config_config = SovereignConfig {
-        max_validators: 4,
-        ..SovereignConfig::default_config_for_test()
-    };
-    state
-        .common_setup
-        .deploy_chain_config(OptionalValue::Some(chain_config_config), None);
-
-    let trusted_token_id = TRUSTED_TOKEN;
-    let execute_amount = 500u64;
-    let deposit_amount = 1000u64;
-
-    // === Setup Operations ===
-    let execute_payment = OperationEsdtPayment::new(
-        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
-        0,
-        EsdtTokenData {
-            amount: BigUint::from(execute_amount),
-            ..Default::default()
-        },
-    );
-
-    let burn_operation = SetBurnMechanismOperation {
-        token_id: EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
-        nonce: state.common_setup.next_operation_nonce(),
-    };
-    let burn_operation_hash = burn_operation.generate_hash();
-    let burn_operation_hash_of_hashes =
-        ManagedBuffer::new_from_bytes(&sha256(&burn_operation_hash.to_vec()));
-    let (signature_burn, pub_keys_burn) = state
-        .common_setup
-        .get_sig_and_pub_keys(1, &burn_operation_hash_of_hashes);
-    state.common_setup.register(
-        pub_keys_burn.first().unwrap(),
-        &MultiEgldOrEsdtPayment::new(),
-        None,
-    );
-
-    let operation_one = Operation::new(
+    let operation = Operation::new(
         TESTING_SC_ADDRESS.to_managed_address(),
-        vec![execute_payment.clone()].into(),
+        ManagedVec::new(),
         OperationData::new(
             state.common_setup.next_operation_nonce(),
             OWNER_ADDRESS.to_managed_address(),
             None,
         ),
     );
-    let operation_one_hash = state.common_setup.get_operation_hash(&operation_one);
-    let hash_of_hashes_one = ManagedBuffer::new_from_bytes(&sha256(&operation_one_hash.to_vec()));
-    let (signature_one, pub_keys_one) = state
-        .common_setup
-        .get_sig_and_pub_keys(1, &hash_of_hashes_one);
-    state.common_setup.register(
-        pub_keys_one.first().unwrap(),
-        &MultiEgldOrEsdtPayment::new(),
-        None,
-    );
 
-    let lock_operation = SetLockMechanismOperation {
-        token_id: EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
-        nonce: state.common_setup.next_operation_nonce(),
-    };
-    let lock_operation_hash = lock_operation.generate_hash();
-    let lock_operation_hash_of_hashes =
-        ManagedBuffer::new_from_bytes(&sha256(&lock_operation_hash.to_vec()));
-    let (signature_lock, pub_keys_lock) = state
-        .common_setup
-        .get_sig_and_pub_keys(1, &lock_operation_hash_of_hashes);
-    state.common_setup.register(
-        pub_keys_lock.first().unwrap(),
-        &MultiEgldOrEsdtPayment::new(),
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
         None,
     );
 
-    let operation_two = Operation::new(
-        TESTING_SC_ADDRESS.to_managed_address(),
-        vec![execute_payment].into(),
-        OperationData::new(
-            state.common_setup.next_operation_nonce(),
-            OWNER_ADDRESS.to_managed_address(),
-            None,
-        ),
-    );
-    let operation_two_hash = state.common_setup.get_operation_hash(&operation_two);
-    let hash_of_hashes_two = ManagedBuffer::new_from_bytes(&sha256(&operation_two_hash.to_vec()));
-    let (signature_two, pub_keys_two) = state
-        .common_setup
-        .get_sig_and_pub_keys(1, &hash_of_hashes_two);
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
     state.common_setup.register(
-        pub_keys_two.first().unwrap(),
+        public_keys.first().unwrap(),
         &MultiEgldOrEsdtPayment::new(),
         None,
     );
 
-    // === Complete Remaining Setup ===
     state.common_setup.complete_chain_config_setup_phase();
+
     state
         .common_setup
         .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
     state
         .common_setup
         .complete_header_verifier_setup_phase(None);
-    state.common_setup.deploy_testing_sc();
-
-    let deposit_payment = OperationEsdtPayment::new(
-        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
-        0,
-        EsdtTokenData {
-            amount: BigUint::from(deposit_amount),
-            ..Default::default()
-        },
-    );
-
-    // === Test Flow ===
-    // 1. First deposit (default LOCK mechanism)
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        PaymentsVec::from(vec![deposit_payment.clone()]),
-        None,
-    );
-
-    state.common_setup.check_account_single_esdt(
-        ESDT_SAFE_ADDRESS.to_address(),
-        TestTokenIdentifier::new(trusted_token_id),
-        0,
-        BigUint::from(deposit_amount),
-    );
-
-    // 2. Switch to BURN mechanism (uses validator 0)
-    let burn_bitmap = state.common_setup.bitmap_for_signers(&[0]);
-    state.common_setup.register_operation(
-        OWNER_ADDRESS,
-        signature_burn,
-        &burn_operation_hash_of_hashes,
-        burn_bitmap,
-        0,
-        MultiValueEncoded::from(ManagedVec::from(vec![burn_operation_hash.clone()])),
-    );
-    state.set_token_burn_mechanism(&burn_operation_hash_of_hashes, burn_operation);
-
-    let mut expected_deposited = deposit_amount;
-    state.common_setup.check_deposited_tokens_amount(vec![(
-        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
-        expected_deposited,
-    )]);
-    state.common_setup.check_account_single_esdt(
-        ESDT_SAFE_ADDRESS.to_address(),
-        TestTokenIdentifier::new(trusted_token_id),
-        0,
-        BigUint::zero(),
-    );
-
-    // 3. Execute operation 1 (BURN mechanism, uses validator 1)
-    state.common_setup.register_operation(
-        OWNER_ADDRESS,
-        signature_one,
-        &hash_of_hashes_one,
-        state.common_setup.bitmap_for_signers(&[1]),
-        0,
-        MultiValueEncoded::from(ManagedVec::from(vec![operation_one_hash])),
-    );
-
-    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
-    state.execute_operation(&hash_of_hashes_one, &operation_one, expected_logs);
-
-    let mut expected_receiver = execute_amount;
-    expected_deposited -= execute_amount;
-
-    state.common_setup.check_deposited_tokens_amount(vec![(
-        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
-        expected_deposited,
-    )]);
-    state.common_setup.check_account_single_esdt(
-        ESDT_SAFE_ADDRESS.to_address(),
-        TestTokenIdentifier::new(trusted_token_id),
-        0,
-        BigUint::zero(),
-    );
-
-    // 4. Second deposit (BURN mechanism)
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        PaymentsVec::from(vec![deposit_payment.clone()]),
-        None,
-    );
-
-    expected_deposited += deposit_amount;
-    state.common_setup.check_deposited_tokens_amount(vec![(
-        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
-        expected_deposited,
-    )]);
-    state.common_setup.check_account_single_esdt(
-        ESDT_SAFE_ADDRESS.to_address(),
-        TestTokenIdentifier::new(trusted_token_id),
-        0,
-        BigUint::zero(),
-    );
-
-    // 5. Switch back to LOCK mechanism (uses validator 2)
-    let lock_bitmap = state.common_setup.bitmap_for_signers(&[2]);
-    state.common_setup.register_operation(
-        OWNER_ADDRESS,
-        signature_lock,
-        &lock_operation_hash_of_hashes,
-        lock_bitmap,
-        0,
-        MultiValueEncoded::from(ManagedVec::from(vec![lock_operation_hash.clone()])),
-    );
-    state.set_token_lock_mechanism(&lock_operation_hash_of_hashes, lock_operation);
-
-    state.common_setup.check_deposited_tokens_amount(vec![(
-        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
-        0,
-    )]);
-    state.common_setup.check_account_single_esdt(
-        ESDT_SAFE_ADDRESS.to_address(),
-        TestTokenIdentifier::new(trusted_token_id),
-        0,
-        BigUint::from(expected_deposited),
-    );
-
-    // 6. Execute operation 2 (LOCK mechanism, uses validator 3)
-    state.common_setup.register_operation(
-        OWNER_ADDRESS,
-        signature_two,
-        &hash_of_hashes_two,
-        state.common_setup.bitmap_for_signers(&[3]),
-        0,
-        MultiValueEncoded::from(ManagedVec::from(vec![operation_two_hash])),
-    );
-
-    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
-    state.execute_operation(&hash_of_hashes_two, &operation_two, expected_logs);
-
-    expected_receiver += execute_amount;
-    expected_deposited -= execute_amount;
-
-    state.common_setup.check_deposited_tokens_amount(vec![(
-        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
-        0,
-    )]);
-    state.common_setup.check_account_single_esdt(
-        ESDT_SAFE_ADDRESS.to_address(),
-        TestTokenIdentifier::new(trusted_token_id),
-        0,
-        BigUint::from(expected_deposited),
-    );
-    state.common_setup.check_account_single_esdt(
-        TESTING_SC_ADDRESS.to_address(),
-        TestTokenIdentifier::new(trusted_token_id),
-        0,
-        BigUint::from(expected_receiver),
-    );
 
-    // 7. Third deposit (LOCK mechanism)
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        PaymentsVec::from(vec![deposit_payment]),
-        None,
-    );
+    state.common_setup.deploy_testing_sc();
 
-    expected_deposited += deposit_amount;
+    let expected_logs = vec![log!(
+        EXECUTE_BRIDGE_OPS_ENDPOINT,
+        topics: [EXECUTED_BRIDGE_OP_EVENT],
+        data: Some(NOTHING_TO_TRANSFER)
+    )];
 
-    state.common_setup.check_deposited_tokens_amount(vec![(
-        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
-        0,
-    )]);
-    state.common_setup.check_account_single_esdt(
-        ESDT_SAFE_ADDRESS.to_address(),
-        TestTokenIdentifier::new(trusted_token_id),
-        0,
-        BigUint::from(expected_deposited),
-    );
-    state.common_setup.check_account_single_esdt(
-        TESTING_SC_ADDRESS.to_address(),
-        TestTokenIdentifier::new(trusted_token_id),
-        0,
-        BigUint::from(expected_receiver),
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        true,
     );
 }
 
@@ -2662,17 +2746,17 @@ fn test_deposit_execute_switch_mechanism() {
 /// M-ESDT_EXEC_OK
 ///
 /// ### ACTION
-/// Call 'execute_operation()' with empty payments
+/// Call 'execute_operation()' with transfer data only
 ///
 /// ### EXPECTED
 /// The operation is executed in the testing smart contract
 #[test]
-fn test_execute_operation_no_payments() {
+fn test_execute_operation_only_transfer_data_no_fee() {
     let mut state = MvxEsdtSafeTestState::new();
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
 
-    let gas_limit = 1;
+    let gas_limit = 40_000_000u64;
     let function = ManagedBuffer::<StaticApi>::from(TESTING_SC_ENDPOINT);
     let args =
         ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::from(vec![ManagedBuffer::from("1")]);
@@ -2710,7 +2794,6 @@ fn test_execute_operation_no_payments() {
     state
         .common_setup
         .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
-
     state
         .common_setup
         .complete_header_verifier_setup_phase(None);
@@ -2718,7 +2801,6 @@ fn test_execute_operation_no_payments() {
     state.common_setup.deploy_testing_sc();
 
     let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
-
     state.register_and_execute_operation(
         &operation,
         &hash_of_hashes,
@@ -2730,30 +2812,26 @@ fn test_execute_operation_no_payments() {
 }
 
 /// ### TEST
-/// M-ESDT_EXEC_OK
+/// M-ESDT_EXEC_FAIL
 ///
 /// ### ACTION
-/// Call 'execute_operation()' with empty payments and wrong endpoint
+/// Call 'execute_operation()' with only transfer data whose gas limit exceeds the max threshold
 ///
 /// ### EXPECTED
-/// The operation is not executed in the testing smart contract
+/// Error GAS_LIMIT_TOO_HIGH, no SC call is performed
 #[test]
-fn test_execute_operation_no_payments_failed_event() {
+fn test_execute_operation_only_transfer_data_gas_limit_too_high() {
     let mut state = MvxEsdtSafeTestState::new();
-
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
 
-    state.common_setup.deploy_chain_config(
-        OptionalValue::Some(SovereignConfig::default_config_for_test()),
-        None,
-    );
-
-    let gas_limit = 1;
-    let function = ManagedBuffer::<StaticApi>::from(WRONG_ENDPOINT_NAME);
+    let gas_limit = MAX_GAS_PER_TRANSACTION + 1;
+    let function = ManagedBuffer::<StaticApi>::from(TESTING_SC_ENDPOINT);
     let args =
         ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::from(vec![ManagedBuffer::from("1")]);
+
     let transfer_data = TransferData::new(gas_limit, function, args);
+
     let operation = Operation::new(
         TESTING_SC_ADDRESS.to_managed_address(),
         ManagedVec::new(),
@@ -2763,9 +2841,15 @@ fn test_execute_operation_no_payments_failed_event() {
             Some(transfer_data),
         ),
     );
+
     let operation_hash = state.common_setup.get_operation_hash(&operation);
     let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
 
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
     let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
 
     state.common_setup.register(
@@ -2779,16 +2863,17 @@ fn test_execute_operation_no_payments_failed_event() {
     state
         .common_setup
         .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
-
     state
         .common_setup
         .complete_header_verifier_setup_phase(None);
 
     state.common_setup.deploy_testing_sc();
 
-    let expected_logs = vec![
-        log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(INVALID_FUNCTION_NOT_FOUND)),
-    ];
+    let expected_logs = vec![log!(
+        EXECUTE_BRIDGE_OPS_ENDPOINT,
+        topics: [EXECUTED_BRIDGE_OP_EVENT],
+        data: Some(GAS_LIMIT_TOO_HIGH)
+    )];
 
     state.register_and_execute_operation(
         &operation,
@@ -2801,158 +2886,3293 @@ fn test_execute_operation_no_payments_failed_event() {
 }
 
 /// ### TEST
-/// M-NATIVE_ESDT_EXEC_OK
+/// M-ESDT_EXEC_OK
 ///
 /// ### ACTION
-/// Call 'execute_operation()' with native esdt payment and wrong endpoint
+/// Call 'execute_operation()' with burn mechanism after deposit
 ///
 /// ### EXPECTED
-/// The operation is not executed in the testing smart contract
-/// Native ESDT should be burned
+/// The operation executes successfully, tokens are burned
 #[test]
-fn test_execute_operation_native_token_failed_event() {
+fn test_execute_operation_success_burn_mechanism() {
     let mut state = MvxEsdtSafeTestState::new();
-
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
 
-    state.common_setup.deploy_chain_config(
-        OptionalValue::Some(SovereignConfig::default_config_for_test()),
-        None,
-    );
-
-    let token_data = EsdtTokenData {
-        amount: BigUint::from(ONE_HUNDRED_TOKENS),
-        ..Default::default()
-    };
+    let amount = BigUint::from(ONE_HUNDRED_THOUSAND);
     let payment = OperationEsdtPayment::new(
-        EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN),
+        EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
+        0,
+        EsdtTokenData {
+            amount: amount.clone(),
+            ..Default::default()
+        },
+    );
+
+    let burn_operation = SetBurnMechanismOperation {
+        token_id: EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let burn_operation_hash = burn_operation.generate_hash();
+    let burn_hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&burn_operation_hash.to_vec()));
+
+    let operation = Operation::new(
+        TESTING_SC_ADDRESS.to_managed_address(),
+        vec![payment.clone()].into(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            OWNER_ADDRESS.to_managed_address(),
+            None,
+        ),
+    );
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+    let (signature_burn, public_keys_burn) = state
+        .common_setup
+        .get_sig_and_pub_keys(1, &burn_hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.register(
+        public_keys_burn.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+    state.common_setup.deploy_testing_sc();
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![payment]),
+        None,
+    );
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from(ManagedVec::from(vec![operation_hash.clone()])),
+    );
+
+    state
+        .common_setup
+        .check_operation_hash_status(&operation_hash, OperationHashStatus::NotLocked);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature_burn,
+        &burn_hash_of_hashes,
+        state.common_setup.bitmap_for_signers(&[1]),
+        0,
+        MultiValueEncoded::from(ManagedVec::from(vec![burn_operation_hash])),
+    );
+
+    state.set_token_burn_mechanism(&burn_hash_of_hashes, burn_operation);
+    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
+    state.execute_operation(&hash_of_hashes, &operation, expected_logs);
+
+    state
+        .common_setup
+        .world
+        .check_account(OWNER_ADDRESS)
+        .esdt_balance(
+            EsdtTokenIdentifier::from(TRUSTED_TOKEN),
+            &(BigUint::from(ONE_HUNDRED_MILLION) - &amount),
+        );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_TOKEN),
+        0u64,
+        BigUint::zero(),
+    );
+
+    state
+        .common_setup
+        .check_deposited_tokens_amount(vec![(EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN), 0)]);
+
+    state.common_setup.check_account_single_esdt(
+        TESTING_SC_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_TOKEN),
+        0u64,
+        amount,
+    );
+
+    state
+        .common_setup
+        .check_operation_hash_status_is_empty(&operation_hash);
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_OK
+///
+/// ### ACTION
+/// Call 'execute_operation()' after switching between LOCK and BURN mechanisms
+///
+/// ### EXPECTED
+/// - Operations execute successfully in different mechanism states
+/// - Token balances are tracked correctly during mechanism switches
+/// - The token registry entry follows the mechanism switches
+#[test]
+fn test_deposit_execute_switch_mechanism() {
+    let mut state = MvxEsdtSafeTestState::new();
+
+    // === Initial Setup ===
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    let chain_config_config = SovereignConfig {
+        max_validators: 4,
+        ..SovereignConfig::default_config_for_test()
+    };
+    state
+        .common_setup
+        .deploy_chain_config(OptionalValue::Some(chain_config_config), None);
+
+    let trusted_token_id = TRUSTED_TOKEN;
+    let execute_amount = 500u64;
+    let deposit_amount = 1000u64;
+
+    // === Setup Operations ===
+    let execute_payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
+        0,
+        EsdtTokenData {
+            amount: BigUint::from(execute_amount),
+            ..Default::default()
+        },
+    );
+
+    let burn_operation = SetBurnMechanismOperation {
+        token_id: EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let burn_operation_hash = burn_operation.generate_hash();
+    let burn_operation_hash_of_hashes =
+        ManagedBuffer::new_from_bytes(&sha256(&burn_operation_hash.to_vec()));
+    let (signature_burn, pub_keys_burn) = state
+        .common_setup
+        .get_sig_and_pub_keys(1, &burn_operation_hash_of_hashes);
+    state.common_setup.register(
+        pub_keys_burn.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    let operation_one = Operation::new(
+        TESTING_SC_ADDRESS.to_managed_address(),
+        vec![execute_payment.clone()].into(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            OWNER_ADDRESS.to_managed_address(),
+            None,
+        ),
+    );
+    let operation_one_hash = state.common_setup.get_operation_hash(&operation_one);
+    let hash_of_hashes_one = ManagedBuffer::new_from_bytes(&sha256(&operation_one_hash.to_vec()));
+    let (signature_one, pub_keys_one) = state
+        .common_setup
+        .get_sig_and_pub_keys(1, &hash_of_hashes_one);
+    state.common_setup.register(
+        pub_keys_one.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    let lock_operation = SetLockMechanismOperation {
+        token_id: EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let lock_operation_hash = lock_operation.generate_hash();
+    let lock_operation_hash_of_hashes =
+        ManagedBuffer::new_from_bytes(&sha256(&lock_operation_hash.to_vec()));
+    let (signature_lock, pub_keys_lock) = state
+        .common_setup
+        .get_sig_and_pub_keys(1, &lock_operation_hash_of_hashes);
+    state.common_setup.register(
+        pub_keys_lock.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    let operation_two = Operation::new(
+        TESTING_SC_ADDRESS.to_managed_address(),
+        vec![execute_payment].into(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            OWNER_ADDRESS.to_managed_address(),
+            None,
+        ),
+    );
+    let operation_two_hash = state.common_setup.get_operation_hash(&operation_two);
+    let hash_of_hashes_two = ManagedBuffer::new_from_bytes(&sha256(&operation_two_hash.to_vec()));
+    let (signature_two, pub_keys_two) = state
+        .common_setup
+        .get_sig_and_pub_keys(1, &hash_of_hashes_two);
+    state.common_setup.register(
+        pub_keys_two.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    // === Complete Remaining Setup ===
+    state.common_setup.complete_chain_config_setup_phase();
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+    state.common_setup.deploy_testing_sc();
+
+    let deposit_payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
+        0,
+        EsdtTokenData {
+            amount: BigUint::from(deposit_amount),
+            ..Default::default()
+        },
+    );
+
+    // === Test Flow ===
+    // 1. First deposit (default LOCK mechanism)
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![deposit_payment.clone()]),
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(trusted_token_id),
+        0,
+        BigUint::from(deposit_amount),
+    );
+    state
+        .common_setup
+        .check_token_registry_mechanism(trusted_token_id, TokenMechanism::Lock);
+
+    // 2. Switch to BURN mechanism (uses validator 0)
+    let burn_bitmap = state.common_setup.bitmap_for_signers(&[0]);
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature_burn,
+        &burn_operation_hash_of_hashes,
+        burn_bitmap,
+        0,
+        MultiValueEncoded::from(ManagedVec::from(vec![burn_operation_hash.clone()])),
+    );
+    state.set_token_burn_mechanism(&burn_operation_hash_of_hashes, burn_operation);
+    state
+        .common_setup
+        .check_token_registry_mechanism(trusted_token_id, TokenMechanism::Burn);
+
+    let mut expected_deposited = deposit_amount;
+    state.common_setup.check_deposited_tokens_amount(vec![(
+        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
+        expected_deposited,
+    )]);
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(trusted_token_id),
+        0,
+        BigUint::zero(),
+    );
+
+    // 3. Execute operation 1 (BURN mechanism, uses validator 1)
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature_one,
+        &hash_of_hashes_one,
+        state.common_setup.bitmap_for_signers(&[1]),
+        0,
+        MultiValueEncoded::from(ManagedVec::from(vec![operation_one_hash])),
+    );
+
+    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
+    state.execute_operation(&hash_of_hashes_one, &operation_one, expected_logs);
+
+    let mut expected_receiver = execute_amount;
+    expected_deposited -= execute_amount;
+
+    state.common_setup.check_deposited_tokens_amount(vec![(
+        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
+        expected_deposited,
+    )]);
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(trusted_token_id),
+        0,
+        BigUint::zero(),
+    );
+
+    // 4. Second deposit (BURN mechanism)
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![deposit_payment.clone()]),
+        None,
+    );
+
+    expected_deposited += deposit_amount;
+    state.common_setup.check_deposited_tokens_amount(vec![(
+        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
+        expected_deposited,
+    )]);
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(trusted_token_id),
+        0,
+        BigUint::zero(),
+    );
+
+    // 5. Switch back to LOCK mechanism (uses validator 2)
+    let lock_bitmap = state.common_setup.bitmap_for_signers(&[2]);
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature_lock,
+        &lock_operation_hash_of_hashes,
+        lock_bitmap,
+        0,
+        MultiValueEncoded::from(ManagedVec::from(vec![lock_operation_hash.clone()])),
+    );
+    state.set_token_lock_mechanism(&lock_operation_hash_of_hashes, lock_operation);
+    state
+        .common_setup
+        .check_token_registry_mechanism(trusted_token_id, TokenMechanism::Lock);
+
+    state.common_setup.check_deposited_tokens_amount(vec![(
+        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
+        0,
+    )]);
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(trusted_token_id),
+        0,
+        BigUint::from(expected_deposited),
+    );
+
+    // 6. Execute operation 2 (LOCK mechanism, uses validator 3)
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature_two,
+        &hash_of_hashes_two,
+        state.common_setup.bitmap_for_signers(&[3]),
+        0,
+        MultiValueEncoded::from(ManagedVec::from(vec![operation_two_hash])),
+    );
+
+    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
+    state.execute_operation(&hash_of_hashes_two, &operation_two, expected_logs);
+
+    expected_receiver += execute_amount;
+    expected_deposited -= execute_amount;
+
+    state.common_setup.check_deposited_tokens_amount(vec![(
+        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
+        0,
+    )]);
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(trusted_token_id),
+        0,
+        BigUint::from(expected_deposited),
+    );
+    state.common_setup.check_account_single_esdt(
+        TESTING_SC_ADDRESS.to_address(),
+        TestTokenIdentifier::new(trusted_token_id),
+        0,
+        BigUint::from(expected_receiver),
+    );
+
+    // 7. Third deposit (LOCK mechanism)
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![deposit_payment]),
+        None,
+    );
+
+    expected_deposited += deposit_amount;
+
+    state.common_setup.check_deposited_tokens_amount(vec![(
+        EgldOrEsdtTokenIdentifier::esdt(trusted_token_id),
+        0,
+    )]);
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(trusted_token_id),
+        0,
+        BigUint::from(expected_deposited),
+    );
+    state.common_setup.check_account_single_esdt(
+        TESTING_SC_ADDRESS.to_address(),
+        TestTokenIdentifier::new(trusted_token_id),
+        0,
+        BigUint::from(expected_receiver),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_OK
+///
+/// ### ACTION
+/// Call 'execute_operation()' with empty payments
+///
+/// ### EXPECTED
+/// The operation is executed in the testing smart contract
+#[test]
+fn test_execute_operation_no_payments() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    let gas_limit = 1;
+    let function = ManagedBuffer::<StaticApi>::from(TESTING_SC_ENDPOINT);
+    let args =
+        ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::from(vec![ManagedBuffer::from("1")]);
+
+    let transfer_data = TransferData::new(gas_limit, function, args);
+
+    let operation = Operation::new(
+        TESTING_SC_ADDRESS.to_managed_address(),
+        ManagedVec::new(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            OWNER_ADDRESS.to_managed_address(),
+            Some(transfer_data),
+        ),
+    );
+
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.deploy_testing_sc();
+
+    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
+
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        true,
+    );
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_OK
+///
+/// ### ACTION
+/// Call 'execute_operation()' with empty payments and wrong endpoint
+///
+/// ### EXPECTED
+/// The operation is not executed in the testing smart contract
+#[test]
+fn test_execute_operation_no_payments_failed_event() {
+    let mut state = MvxEsdtSafeTestState::new();
+
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let gas_limit = 1;
+    let function = ManagedBuffer::<StaticApi>::from(WRONG_ENDPOINT_NAME);
+    let args =
+        ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::from(vec![ManagedBuffer::from("1")]);
+    let transfer_data = TransferData::new(gas_limit, function, args);
+    let operation = Operation::new(
+        TESTING_SC_ADDRESS.to_managed_address(),
+        ManagedVec::new(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            OWNER_ADDRESS.to_managed_address(),
+            Some(transfer_data),
+        ),
+    );
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.deploy_testing_sc();
+
+    let expected_logs = vec![
+        log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(INVALID_FUNCTION_NOT_FOUND)),
+    ];
+
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        true,
+    );
+}
+
+/// ### TEST
+/// M-NATIVE_ESDT_EXEC_OK
+///
+/// ### ACTION
+/// Call 'execute_operation()' with native esdt payment and wrong endpoint
+///
+/// ### EXPECTED
+/// The operation is not executed in the testing smart contract
+/// Native ESDT should be burned
+#[test]
+fn test_execute_operation_native_token_failed_event() {
+    let mut state = MvxEsdtSafeTestState::new();
+
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let token_data = EsdtTokenData {
+        amount: BigUint::from(ONE_HUNDRED_TOKENS),
+        ..Default::default()
+    };
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN),
+        0,
+        token_data,
+    );
+
+    let gas_limit = 1;
+    let function = ManagedBuffer::<StaticApi>::from(WRONG_ENDPOINT_NAME);
+    let args =
+        ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::from(vec![ManagedBuffer::from("1")]);
+    let transfer_data = TransferData::new(gas_limit, function, args);
+    let operation_data = OperationData::new(
+        state.common_setup.next_operation_nonce(),
+        OWNER_ADDRESS.to_managed_address(),
+        Some(transfer_data),
+    );
+    let operation = Operation::new(
+        TESTING_SC_ADDRESS.to_managed_address(),
+        vec![payment].into(),
+        operation_data,
+    );
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.deploy_testing_sc();
+
+    let expected_logs = vec![
+        log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(INVALID_FUNCTION_NOT_FOUND)),
+    ];
+
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        true,
+    );
+
+    let addresses = [OWNER_ADDRESS.to_address(), TESTING_SC_ADDRESS.to_address()];
+    for address in &addresses {
+        state.common_setup.check_account_single_esdt(
+            address.clone(),
+            NATIVE_TEST_TOKEN,
+            0u64,
+            BigUint::zero(),
+        );
+    }
+}
+
+/// ### TEST
+/// M-ESDT_SET_BURN_FAIL
+///
+/// ### ACTION
+/// Call 'set_token_burn_mechanism()' without the proper roles
+///
+/// ### EXPECTED
+/// Error MINT_AND_BURN_ROLES_NOT_FOUND
+#[test]
+fn test_set_token_burn_mechanism_no_roles() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    state.set_token_burn_mechanism_before_setup_phase("WEGLD", Some(MINT_AND_BURN_ROLES_NOT_FOUND));
+}
+
+/// ### TEST
+/// M-ESDT_SET_BURN_FAIL
+///
+/// ### ACTION
+/// Call 'set_token_burn_mechanism()' without a trusted token id
+///
+/// ### EXPECTED
+/// Error TOKEN_ID_IS_NOT_TRUSTED
+#[test]
+fn test_set_token_burn_mechanism_token_not_trusted() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    state.set_token_burn_mechanism_before_setup_phase(
+        FIRST_TEST_TOKEN.as_str(),
+        Some(TOKEN_ID_IS_NOT_TRUSTED),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_SET_BURN_OK
+///
+/// ### ACTION
+/// Call 'set_token_burn_mechanism()' with a trusted token id
+///
+/// ### EXPECTED
+/// The trusted token has the burn mechanism set
+#[test]
+fn test_set_token_burn_mechanism() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_TOKEN, None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            assert!(sc
+                .burn_mechanism_tokens()
+                .contains(&EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN)))
+        });
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_TOKEN),
+        0u64,
+        BigUint::zero(),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_SET_BURN_OK
+///
+/// ### ACTION
+/// Call both 'set_token_burn_mechanism()' and 'set_token_lock_mechanism()' with a trusted token id.
+///
+/// ### EXPECTED
+/// The trusted token has the lock mechanism set
+#[test]
+fn test_set_token_lock_mechanism() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_TOKEN, None);
+    state.set_token_lock_mechanism_before_setup_phase(TRUSTED_TOKEN, None);
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            assert!(sc.burn_mechanism_tokens().is_empty())
+        });
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_TOKEN),
+        100u64,
+        BigUint::zero(),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_SET_BURN_OK
+///
+/// ### ACTION
+/// Call 'set_token_burn_mechanism()' with a trusted SFT token id
+///
+/// ### EXPECTED
+/// The trusted SFT token has the burn mechanism set
+#[test]
+fn test_set_token_burn_mechanism_sft() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_SFT_TOKEN, None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            assert!(sc
+                .burn_mechanism_tokens()
+                .contains(&EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN)))
+        });
+}
+
+/// ### TEST
+/// M-ESDT_DEP_OK
+///
+/// ### ACTION
+/// Call 'deposit()' with an SFT that has the burn mechanism set
+///
+/// ### EXPECTED
+/// The SFT is burned except for one unit kept for its nonce, and the burned amount is tracked
+/// for its nonce
+#[test]
+fn test_deposit_success_burn_mechanism_sft() {
+    let mut state = MvxEsdtSafeTestState::new();
+
+    state.deploy_contract_with_roles(None);
+    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_SFT_TOKEN, None);
+    state.complete_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    let sft_payment = EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(TRUSTED_SFT_TOKEN),
+        1,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![sft_payment]),
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        BigUint::from(1u64),
+    );
+
+    state
+        .common_setup
+        .check_deposited_tokens_amount_with_nonce(vec![
+            (
+                EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN),
+                1u64,
+                (ONE_HUNDRED_THOUSAND - 1).into(),
+            ),
+            (
+                EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN),
+                0u64,
+                0u64,
+            ),
+        ]);
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_OK
+///
+/// ### ACTION
+/// Call 'execute_operation()' with an SFT deposited with the burn mechanism set
+///
+/// ### EXPECTED
+/// The SFT quantity is added back and sent to the receiver together with the kept unit, and the
+/// deposited amount is cleared. The reserve counters only cover the bridged nonce
+#[test]
+fn test_execute_operation_success_burn_mechanism_sft() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_SFT_TOKEN, None);
+    state.complete_setup_phase();
+
+    let amount = BigUint::from(ONE_HUNDRED_THOUSAND);
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN),
+        1,
+        EsdtTokenData {
+            amount: amount.clone(),
+            token_type: EsdtTokenType::SemiFungible,
+            ..Default::default()
+        },
+    );
+
+    let operation = Operation::new(
+        TESTING_SC_ADDRESS.to_managed_address(),
+        vec![payment].into(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            OWNER_ADDRESS.to_managed_address(),
+            None,
+        ),
+    );
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+    state.common_setup.deploy_testing_sc();
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![EsdtTokenPayment::<StaticApi>::new(
+            EsdtTokenIdentifier::from(TRUSTED_SFT_TOKEN),
+            1,
+            amount.clone(),
+        )]),
+        None,
+    );
+
+    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        true,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        BigUint::zero(),
+    );
+    state.common_setup.check_account_single_esdt(
+        TESTING_SC_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        amount,
+    );
+    state
+        .common_setup
+        .check_deposited_tokens_amount_with_nonce(vec![(
+            EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN),
+            1u64,
+            0u64,
+        )]);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let reserves =
+                sc.get_token_reserves(EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN), 1);
+            assert!(
+                reserves.reserve_amount == 0u64
+                    && reserves.total_deposited == ONE_HUNDRED_THOUSAND
+                    && reserves.total_executed == ONE_HUNDRED_THOUSAND
+            );
+
+            let other_nonce_reserves =
+                sc.get_token_reserves(EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN), 2);
+            assert!(
+                other_nonce_reserves.total_deposited == 0u64
+                    && other_nonce_reserves.total_executed == 0u64
+            );
+        });
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_OK
+///
+/// ### ACTION
+/// Deposit the full amount of an SFT nonce with the burn mechanism set, execute it back, then deposit it again
+///
+/// ### EXPECTED
+/// The round trip mints back exactly the burned amount, so the nonce supply is unchanged and the
+/// safe holds no unit. The next deposit keeps a new unit and the quantity can be burned again
+#[test]
+fn test_execute_operation_burn_mechanism_sft_round_trip_keeps_supply() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_SFT_TOKEN, None);
+    state.complete_setup_phase();
+
+    let amount = BigUint::from(ONE_HUNDRED_THOUSAND);
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN),
+        1,
+        EsdtTokenData {
+            amount: amount.clone(),
+            token_type: EsdtTokenType::SemiFungible,
+            ..Default::default()
+        },
+    );
+
+    let operation = Operation::new(
+        TESTING_SC_ADDRESS.to_managed_address(),
+        vec![payment].into(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            OWNER_ADDRESS.to_managed_address(),
+            None,
+        ),
+    );
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+    state.common_setup.deploy_testing_sc();
+
+    let sft_deposit = PaymentsVec::from(vec![EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(TRUSTED_SFT_TOKEN),
+        1,
+        amount.clone(),
+    )]);
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        sft_deposit.clone(),
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        BigUint::from(1u64),
+    );
+
+    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        true,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        TESTING_SC_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        amount.clone(),
+    );
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        BigUint::zero(),
+    );
+    state.common_setup.check_account_single_esdt(
+        OWNER_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        BigUint::from(ONE_HUNDRED_MILLION - ONE_HUNDRED_THOUSAND),
+    );
+    state
+        .common_setup
+        .check_deposited_tokens_amount_with_nonce(vec![(
+            EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN),
+            1u64,
+            0u64,
+        )]);
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        sft_deposit,
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        BigUint::from(1u64),
+    );
+    state
+        .common_setup
+        .check_deposited_tokens_amount_with_nonce(vec![(
+            EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN),
+            1u64,
+            (ONE_HUNDRED_THOUSAND - 1).into(),
+        )]);
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_OK
+///
+/// ### ACTION
+/// Call 'execute_operation()' with an SFT escrowed under the lock mechanism, after switching to the burn mechanism
+///
+/// ### EXPECTED
+/// The escrowed SFT balance is converted to a deposited amount and the operation executes successfully
+#[test]
+fn test_execute_operation_sft_escrow_after_burn_mechanism_switch() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    let amount = BigUint::from(ONE_HUNDRED_THOUSAND);
+    let execute_amount = BigUint::from(ONE_HUNDRED_THOUSAND / 2);
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN),
+        1,
+        EsdtTokenData {
+            amount: execute_amount.clone(),
+            token_type: EsdtTokenType::SemiFungible,
+            ..Default::default()
+        },
+    );
+
+    let burn_operation = SetBurnMechanismOperation {
+        token_id: EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let burn_operation_hash = burn_operation.generate_hash();
+    let burn_hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&burn_operation_hash.to_vec()));
+
+    let operation = Operation::new(
+        TESTING_SC_ADDRESS.to_managed_address(),
+        vec![payment].into(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            OWNER_ADDRESS.to_managed_address(),
+            None,
+        ),
+    );
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+    let (signature_burn, public_keys_burn) = state
+        .common_setup
+        .get_sig_and_pub_keys(1, &burn_hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.register(
+        public_keys_burn.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+    state.common_setup.deploy_testing_sc();
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![EsdtTokenPayment::<StaticApi>::new(
+            EsdtTokenIdentifier::from(TRUSTED_SFT_TOKEN),
+            1,
+            amount.clone(),
+        )]),
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        amount.clone(),
+    );
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from(ManagedVec::from(vec![operation_hash.clone()])),
+    );
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature_burn,
+        &burn_hash_of_hashes,
+        state.common_setup.bitmap_for_signers(&[1]),
+        0,
+        MultiValueEncoded::from(ManagedVec::from(vec![burn_operation_hash])),
+    );
+
+    state.set_token_burn_mechanism(&burn_hash_of_hashes, burn_operation);
+    let expected_logs = vec![log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
+    state.execute_operation(&hash_of_hashes, &operation, expected_logs);
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        BigUint::from(1u64),
+    );
+    state.common_setup.check_account_single_esdt(
+        TESTING_SC_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        execute_amount.clone(),
+    );
+    state
+        .common_setup
+        .check_deposited_tokens_amount_with_nonce(vec![(
+            EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN),
+            1u64,
+            (ONE_HUNDRED_THOUSAND / 2 - 1).into(),
+        )]);
+    state
+        .common_setup
+        .check_operation_hash_status_is_empty(&operation_hash);
+}
+
+/// ### TEST
+/// M-ESDT_UPDATE_CONFIG_FAIL
+///
+/// ### ACTION
+/// Call `update_esdt_safe_config()` before setup phase completion
+///
+/// ### EXPECTED
+/// ERROR SETUP_PHASE_NOT_COMPLETED
+#[test]
+fn test_update_config_setup_phase_not_completed() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    let esdt_safe_config = EsdtSafeConfig::default_config();
+
+    let nonce = state.common_setup.next_operation_nonce();
+    state.update_esdt_safe_config(
+        &ManagedBuffer::new(),
+        UpdateEsdtSafeConfigOperation {
+            esdt_safe_config,
+            nonce,
+        },
+        Some(SETUP_PHASE_NOT_COMPLETED),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_UPDATE_CONFIG_FAIL
+///
+/// ### ACTION
+/// Call `update_esdt_safe_config()` before registering operation
+///
+/// ### EXPECTED
+/// ERROR CURRENT_OPERATION_NOT_REGISTERED
+#[test]
+fn test_update_config_operation_not_registered() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    let esdt_safe_config = EsdtSafeConfig::default_config();
+
+    let nonce = state.common_setup.next_operation_nonce();
+    state.update_esdt_safe_config(
+        &ManagedBuffer::new(),
+        UpdateEsdtSafeConfigOperation {
+            esdt_safe_config,
+            nonce,
+        },
+        Some(CURRENT_OPERATION_NOT_REGISTERED),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_UPDATE_CONFIG_ERROR
+///
+/// ### ACTION
+/// Call `update_esdt_safe_config()` with an invalid config
+///
+/// ### EXPECTED
+/// failedBridgeOp event is emitted
+#[test]
+fn test_update_config_invalid_config() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let esdt_safe_config = EsdtSafeConfig {
+        max_tx_gas_limit: MAX_GAS_PER_TRANSACTION + 1,
+        ..EsdtSafeConfig::default_config()
+    };
+    let update_config_operation = UpdateEsdtSafeConfigOperation {
+        esdt_safe_config: esdt_safe_config.clone(),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+
+    let config_hash = update_config_operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&config_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    let bitmap = state.common_setup.full_bitmap(1);
+    let epoch = 0;
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        bitmap,
+        epoch,
+        MultiValueEncoded::from_iter(vec![config_hash]),
+    );
+
+    state.update_esdt_safe_config(
+        &hash_of_hashes,
+        update_config_operation,
+        Some(MAX_GAS_LIMIT_PER_TX_EXCEEDED),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_PATCH_CONFIG_OK
+///
+/// ### ACTION
+/// Call `patch_esdt_safe_config()` adding an address to the blacklist
+///
+/// ### EXPECTED
+/// Only the address blacklist is changed and deposits from that address fail
+#[test]
+fn test_patch_config_add_blacklisted_address() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let patch_config_operation = PatchEsdtSafeConfigOperation {
+        patch: EsdtSafeConfigPatch::AddBlacklistedAddress {
+            address: OWNER_ADDRESS.to_managed_address(),
+        },
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+
+    let operation_hash = patch_config_operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.patch_esdt_safe_config(&hash_of_hashes, patch_config_operation, None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let config = sc.get_esdt_safe_config();
+            assert!(
+                config.max_tx_gas_limit == DEFAULT_MAX_TX_GAS_LIMIT
+                    && config.address_blacklist.len() == 1
+                    && config
+                        .address_blacklist
+                        .contains(&OWNER_ADDRESS.to_managed_address())
+            );
+        });
+
+    let payment = EgldOrEsdtTokenPayment::egld_payment(BigUint::zero());
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![payment]),
+        Some(CALLER_IS_BLACKLISTED),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_PATCH_CONFIG_FAIL
+///
+/// ### ACTION
+/// Call `patch_esdt_safe_config()` removing a token that is not whitelisted
+///
+/// ### EXPECTED
+/// executedBridgeOp is emitted with the token not whitelisted error
+#[test]
+fn test_patch_config_remove_token_not_whitelisted() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let patch_config_operation = PatchEsdtSafeConfigOperation {
+        patch: EsdtSafeConfigPatch::RemoveTokenFromWhitelist {
+            token_id: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        },
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+
+    let operation_hash = patch_config_operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash.clone()]),
+    );
+
+    state.patch_esdt_safe_config(
+        &hash_of_hashes,
+        patch_config_operation,
+        Some(TOKEN_NOT_WHITELISTED),
+    );
+
+    state
+        .common_setup
+        .check_operation_hash_status_is_empty(&operation_hash);
+}
+
+/// ### TEST
+/// M-ESDT_PATCH_CONFIG_OK
+///
+/// ### ACTION
+/// Call `patch_esdt_safe_config()` allowing an endpoint of the testing SC
+///
+/// ### EXPECTED
+/// The allowlist holds the endpoint and SC calls to other targets are rejected
+#[test]
+fn test_patch_config_add_allowed_target_endpoint() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let patch_config_operation = PatchEsdtSafeConfigOperation {
+        patch: EsdtSafeConfigPatch::AddAllowedTargetEndpoint {
+            address: TESTING_SC_ADDRESS.to_managed_address(),
+            endpoint_name: ManagedBuffer::from(TESTING_SC_ENDPOINT),
+        },
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+
+    let operation_hash = patch_config_operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.patch_esdt_safe_config(&hash_of_hashes, patch_config_operation, None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let config = sc.get_esdt_safe_config();
+            assert_eq!(config.allowed_targets.len(), 1);
+
+            let allowed_target = config.allowed_targets.get(0);
+            assert!(
+                allowed_target.address == TESTING_SC_ADDRESS.to_managed_address()
+                    && allowed_target.endpoints.len() == 1
+                    && allowed_target
+                        .endpoints
+                        .contains(&ManagedBuffer::from(TESTING_SC_ENDPOINT))
+            );
+        });
+
+    let transfer_data = MultiValue3::from((
+        1u64,
+        ManagedBuffer::from(TESTING_SC_ENDPOINT),
+        MultiValueEncoded::new(),
+    ));
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::Some(transfer_data),
+        PaymentsVec::new(),
+        Some(SC_CALL_TARGET_NOT_ALLOWED),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_PATCH_CONFIG_FAIL
+///
+/// ### ACTION
+/// Call `patch_esdt_safe_config()` removing an endpoint that is not allowed
+///
+/// ### EXPECTED
+/// executedBridgeOp is emitted with the endpoint not allowed error
+#[test]
+fn test_patch_config_remove_allowed_target_endpoint_not_allowed() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let patch_config_operation = PatchEsdtSafeConfigOperation {
+        patch: EsdtSafeConfigPatch::RemoveAllowedTargetEndpoint {
+            address: TESTING_SC_ADDRESS.to_managed_address(),
+            endpoint_name: ManagedBuffer::from(TESTING_SC_ENDPOINT),
+        },
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+
+    let operation_hash = patch_config_operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.patch_esdt_safe_config(
+        &hash_of_hashes,
+        patch_config_operation,
+        Some(TARGET_ENDPOINT_NOT_ALLOWED),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_UPGRADE_OK
+///
+/// ### ACTION
+/// Call `upgrade()` with the config stored in the legacy single value storage
+///
+/// ### EXPECTED
+/// The config is moved into the dedicated mappers and the legacy storage is cleared
+#[test]
+fn test_upgrade_migrates_legacy_config() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let legacy_config = LegacyEsdtSafeConfig {
+                token_whitelist: ManagedVec::from_iter(vec![EgldOrEsdtTokenIdentifier::esdt(
+                    FIRST_TEST_TOKEN,
+                )]),
+                token_blacklist: ManagedVec::from_iter(vec![EgldOrEsdtTokenIdentifier::esdt(
+                    SECOND_TEST_TOKEN,
+                )]),
+                max_tx_gas_limit: ONE_HUNDRED_THOUSAND as u64,
+                banned_endpoints: ManagedVec::from_iter(vec![ManagedBuffer::from(
+                    WRONG_ENDPOINT_NAME,
+                )]),
+                address_blacklist: ManagedVec::from_iter(vec![USER_ADDRESS.to_managed_address()]),
+                max_bridged_token_amounts: ManagedVec::from_iter(vec![MaxBridgedAmount {
+                    token_id: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+                    amount: BigUint::from(ONE_HUNDRED_TOKENS),
+                }]),
+            };
+            sc.legacy_esdt_safe_config().set(legacy_config);
+
+            sc.upgrade(MultiValueEncoded::new());
+
+            assert!(sc.legacy_esdt_safe_config().is_empty());
+            assert!(sc
+                .token_whitelist()
+                .contains(&EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)));
+            assert!(sc
+                .token_blacklist()
+                .contains(&EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN)));
+            assert!(sc.max_tx_gas_limit().get() == ONE_HUNDRED_THOUSAND as u64);
+            assert!(sc
+                .banned_endpoints()
+                .contains(&ManagedBuffer::from(WRONG_ENDPOINT_NAME)));
+            assert!(sc
+                .address_blacklist()
+                .contains(&USER_ADDRESS.to_managed_address()));
+            assert!(
+                sc.max_bridged_token_amounts()
+                    .get(&EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN))
+                    == Some(BigUint::from(ONE_HUNDRED_TOKENS))
+            );
+        });
+}
+
+/// ### TEST
+/// M-ESDT_TOKEN_REGISTRY_OK
+///
+/// ### ACTION
+/// Add registry entries and NFT nonce mappings, clear one of the mappings, then call the paginated views
+///
+/// ### EXPECTED
+/// The views return the registered pairs page by page and the cleared NFT nonce mapping is gone
+#[test]
+fn test_token_registry_views() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let token_pairs = [
+                (SOV_FIRST_TOKEN_ID, FIRST_TOKEN_ID, 1u64),
+                (SOV_SECOND_TOKEN_ID, SECOND_TOKEN_ID, 2u64),
+            ];
+
+            for (sov_token, mvx_token, op_nonce) in token_pairs {
+                let register_token_operation = RegisterTokenOperation {
+                    token_id: EgldOrEsdtTokenIdentifier::esdt(sov_token.to_token_identifier()),
+                    token_type: EsdtTokenType::NonFungible,
+                    token_display_name: ManagedBuffer::from("Token"),
+                    token_ticker: ManagedBuffer::from("TKN"),
+                    num_decimals: 0,
+                    data: OperationData::new(op_nonce, USER_ADDRESS.to_managed_address(), None),
+                };
+                sc.set_corresponding_token_ids(
+                    &register_token_operation.token_id,
+                    &EgldOrEsdtTokenIdentifier::esdt(mvx_token.to_token_identifier()),
+                );
+                sc.add_token_registry_entry(
+                    &register_token_operation,
+                    &EgldOrEsdtTokenIdentifier::esdt(mvx_token.to_token_identifier()),
+                );
+            }
+
+            let sov_token_id = EgldOrEsdtTokenIdentifier::esdt(SOV_FIRST_TOKEN_ID);
+            let mvx_token_id = EgldOrEsdtTokenIdentifier::esdt(FIRST_TOKEN_ID);
+            sc.update_esdt_info_mappers(&sov_token_id, 1, &mvx_token_id, 10);
+            sc.update_esdt_info_mappers(&sov_token_id, 2, &mvx_token_id, 11);
+            sc.clear_mvx_to_sov_esdt_info_mapper(&mvx_token_id, 10);
+            sc.clear_sov_to_mvx_esdt_info_mapper(&sov_token_id, 1);
+        });
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            assert!(sc.get_registered_tokens_count() == 2);
+
+            let first_page = sc.get_registered_tokens(0, 1).to_vec();
+            assert!(first_page.len() == 1);
+            let first_entry = first_page.get(0);
+            assert!(
+                first_entry.sov_token_id == EgldOrEsdtTokenIdentifier::esdt(SOV_FIRST_TOKEN_ID)
+                    && first_entry.mvx_token_id == EgldOrEsdtTokenIdentifier::esdt(FIRST_TOKEN_ID)
+                    && first_entry.registration_nonce == 1
+                    && first_entry.mechanism == TokenMechanism::Burn
+            );
+
+            let second_page = sc.get_registered_tokens(1, 5).to_vec();
+            assert!(second_page.len() == 1);
+            assert!(
+                second_page.get(0).sov_token_id
+                    == EgldOrEsdtTokenIdentifier::esdt(SOV_SECOND_TOKEN_ID)
+            );
+            assert!(sc.get_registered_tokens(2, 5).is_empty());
+
+            assert!(sc.get_nft_nonce_mappings_count() == 1);
+            let nft_mappings = sc.get_nft_nonce_mappings(0, 10).to_vec();
+            let nft_mapping = nft_mappings.get(0);
+            assert!(
+                nft_mapping.mvx_esdt_info.token_nonce == 11
+                    && nft_mapping.sov_esdt_info.token_nonce == 2
+            );
+        });
+}
+
+/// ### TEST
+/// M-ESDT_TOKEN_REGISTRY_OK
+///
+/// ### ACTION
+/// Set a native token, a sovereign token mapping and a burn mechanism token without registry entries, then call 'upgrade()' with backfill entries
+///
+/// ### EXPECTED
+/// Every token gets a registry entry with its MultiversX token id and the mechanism in use
+#[test]
+fn test_upgrade_backfills_token_registry() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            sc.native_token()
+                .set(EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN));
+            sc.set_corresponding_token_ids(
+                &EgldOrEsdtTokenIdentifier::esdt(SOV_FIRST_TOKEN_ID),
+                &EgldOrEsdtTokenIdentifier::esdt(FIRST_TOKEN_ID),
+            );
+            sc.burn_mechanism_tokens()
+                .insert(EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN));
+
+            let mut backfill = MultiValueEncoded::new();
+            backfill.push(MultiValue3::from((
+                EgldOrEsdtTokenIdentifier::esdt(SOV_FIRST_TOKEN_ID),
+                EsdtTokenType::Fungible,
+                6usize,
+            )));
+            backfill.push(MultiValue3::from((
+                EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
+                EsdtTokenType::Fungible,
+                18usize,
+            )));
+            backfill.push(MultiValue3::from((
+                EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+                EsdtTokenType::Fungible,
+                18usize,
+            )));
+            sc.upgrade(backfill);
+
+            assert!(sc.get_registered_tokens_count() == 4);
+
+            let native_entry = sc
+                .token_registry_entry(&EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN))
+                .get();
+            assert!(
+                native_entry.mvx_token_id == EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN)
+                    && native_entry.num_decimals == 18
+                    && native_entry.mechanism == TokenMechanism::Burn
+            );
+
+            let sov_entry = sc
+                .token_registry_entry(&EgldOrEsdtTokenIdentifier::esdt(SOV_FIRST_TOKEN_ID))
+                .get();
+            assert!(
+                sov_entry.mvx_token_id == EgldOrEsdtTokenIdentifier::esdt(FIRST_TOKEN_ID)
+                    && sov_entry.num_decimals == 6
+                    && sov_entry.mechanism == TokenMechanism::Burn
+            );
+
+            let burn_entry = sc
+                .token_registry_entry(&EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN))
+                .get();
+            assert!(burn_entry.mechanism == TokenMechanism::Burn);
+
+            let lock_entry = sc
+                .token_registry_entry(&EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN))
+                .get();
+            assert!(
+                lock_entry.mvx_token_id == EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)
+                    && lock_entry.mechanism == TokenMechanism::Lock
+            );
+        });
+}
+
+/// ### TEST
+/// M-ESDT_RESERVES_OK
+///
+/// ### ACTION
+/// Call 'deposit()' with a lock mechanism token and a burn mechanism token, then query the reserves
+///
+/// ### EXPECTED
+/// The lock token reserve is the escrowed balance, the burn token reserve is the deposited amount
+#[test]
+fn test_token_reserves_after_deposit() {
+    let mut state = MvxEsdtSafeTestState::new();
+
+    state.deploy_contract_with_roles(None);
+    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_TOKEN, None);
+    state.complete_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    let lock_payment = EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(FIRST_TEST_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+    let burn_payment = EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(TRUSTED_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND / 2),
+    );
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![lock_payment, burn_payment]),
+        None,
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let lock_reserves =
+                sc.get_token_reserves(EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN), 0);
+            assert!(
+                lock_reserves.mechanism == TokenMechanism::Lock
+                    && !lock_reserves.is_sovereign_wrapper
+                    && lock_reserves.reserve_amount == ONE_HUNDRED_THOUSAND
+                    && lock_reserves.total_deposited == ONE_HUNDRED_THOUSAND
+                    && lock_reserves.total_executed == 0u64
+            );
+
+            let burn_reserves =
+                sc.get_token_reserves(EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN), 0);
+            assert!(
+                burn_reserves.mechanism == TokenMechanism::Burn
+                    && burn_reserves.reserve_amount == ONE_HUNDRED_THOUSAND / 2
+                    && burn_reserves.total_deposited == ONE_HUNDRED_THOUSAND / 2
+            );
+        });
+}
+
+/// ### TEST
+/// M-ESDT_UPDATE_CONFIG_OK
+///
+/// ### ACTION
+/// Call `update_esdt_safe_config()`
+///
+/// ### EXPECTED
+/// EsdtSafeConfig is updated and executedBridgeOp is emitted
+#[test]
+fn test_update_config() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let esdt_safe_config = EsdtSafeConfig {
+        max_tx_gas_limit: ONE_HUNDRED_THOUSAND as u64,
+        address_blacklist: ManagedVec::from_iter(vec![OWNER_ADDRESS.to_managed_address()]),
+        ..EsdtSafeConfig::default_config()
+    };
+    let update_config_operation = UpdateEsdtSafeConfigOperation {
+        esdt_safe_config,
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+
+    let config_hash = update_config_operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&config_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    let bitmap = state.common_setup.full_bitmap(1);
+    let epoch = 0;
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        bitmap,
+        epoch,
+        MultiValueEncoded::from_iter(vec![config_hash]),
+    );
+
+    state.update_esdt_safe_config(&hash_of_hashes, update_config_operation.clone(), None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let config = sc.get_esdt_safe_config();
+            assert!(
+                config.max_tx_gas_limit == ONE_HUNDRED_THOUSAND as u64
+                    && config
+                        .address_blacklist
+                        .contains(&OWNER_ADDRESS.to_managed_address())
+            );
+        });
+
+    let payment = EgldOrEsdtTokenPayment::egld_payment(BigUint::zero());
+    let payment_vec = PaymentsVec::from(vec![payment]);
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        payment_vec,
+        Some(CALLER_IS_BLACKLISTED),
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(HEADER_VERIFIER_ADDRESS)
+        .whitebox(header_verifier::contract_obj, |sc| {
+            let config_hash_whitebox =
+                ManagedBuffer::from(update_config_operation.generate_hash().to_vec());
+            let hash_of_hashes_whitebox =
+                ManagedBuffer::new_from_bytes(&sha256(&config_hash_whitebox.to_vec()));
+            assert!(sc
+                .operation_hash_status(&hash_of_hashes_whitebox, &config_hash_whitebox)
+                .is_empty())
+        });
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_OK
+///
+/// ### ACTION
+/// Call `execute_operation()` when the contract is paused
+///
+/// ### EXPECTED
+/// The operation is executed and the tokens are burned from SC
+#[test]
+fn test_execute_paused_refund() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            sc.paused_status().set(true);
+        });
+
+    let token_data = EsdtTokenData {
+        amount: BigUint::from(100u64),
+        ..Default::default()
+    };
+
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::from(FIRST_TEST_TOKEN.as_bytes()),
+        0,
+        token_data,
+    );
+    let operation_data = OperationData::new(
+        state.common_setup.next_operation_nonce(),
+        OWNER_ADDRESS.to_managed_address(),
+        None,
+    );
+    let operation = Operation {
+        to: USER_ADDRESS.to_managed_address(),
+        tokens: vec![payment].into(),
+        data: operation_data,
+    };
+
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+    let (signature, bls_key) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+    let egld_payment = EgldOrEsdtTokenPayment::egld_payment(BigUint::default());
+    let payments_vec = PaymentsVec::from(vec![egld_payment]);
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+    state
+        .common_setup
+        .register(&bls_key[0], &payments_vec, None);
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    let expected_logs = vec![
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(ESDT_SAFE_STILL_PAUSED)),
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [DEPOSIT_EVENT, FIRST_TEST_TOKEN.as_str()]),
+    ];
+
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        false,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0,
+        BigUint::zero(),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_FAIL
+///
+/// ### ACTION
+/// Call `execute_operation()` towards an address on the address blacklist
+///
+/// ### EXPECTED
+/// The operation fails with the receiver blacklisted error and the tokens are refunded
+#[test]
+fn test_execute_blacklisted_receiver_refund() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    let config = EsdtSafeConfig {
+        address_blacklist: ManagedVec::from_iter(vec![USER_ADDRESS.to_managed_address()]),
+        ..EsdtSafeConfig::default_config()
+    };
+    state.update_esdt_safe_config_during_setup_phase(config, None);
+    state.complete_setup_phase();
+
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        0,
+        EsdtTokenData {
+            amount: BigUint::from(100u64),
+            ..Default::default()
+        },
+    );
+    let operation = Operation::new(
+        USER_ADDRESS.to_managed_address(),
+        vec![payment].into(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            OWNER_ADDRESS.to_managed_address(),
+            None,
+        ),
+    );
+
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+    let (signature, bls_key) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+    state
+        .common_setup
+        .register(&bls_key[0], &MultiEgldOrEsdtPayment::new(), None);
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    let expected_logs = vec![
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(RECEIVER_IS_BLACKLISTED)),
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [DEPOSIT_EVENT, FIRST_TEST_TOKEN.as_str()]),
+    ];
+
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        false,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0,
+        BigUint::zero(),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_FAIL
+///
+/// ### ACTION
+/// Call `execute_operation()` with an amount over the inbound max bridged amount
+///
+/// ### EXPECTED
+/// The separate inbound policy is applied, the operation fails and the tokens are refunded
+#[test]
+fn test_execute_over_inbound_max_amount_refund() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    let inbound_policy = InboundPolicy {
+        token_blacklist: ManagedVec::new(),
+        address_blacklist: ManagedVec::new(),
+        max_bridged_token_amounts: ManagedVec::from_iter(vec![MaxBridgedAmount {
+            token_id: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            amount: BigUint::from(50u64),
+        }]),
+    };
+    state.set_inbound_policy_during_setup_phase(inbound_policy, None);
+    state.complete_setup_phase();
+
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        0,
+        EsdtTokenData {
+            amount: BigUint::from(100u64),
+            ..Default::default()
+        },
+    );
+    let operation = Operation::new(
+        USER_ADDRESS.to_managed_address(),
+        vec![payment].into(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            OWNER_ADDRESS.to_managed_address(),
+            None,
+        ),
+    );
+
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+    let (signature, bls_key) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+    state
+        .common_setup
+        .register(&bls_key[0], &MultiEgldOrEsdtPayment::new(), None);
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    let expected_logs = vec![
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(EXECUTION_OVER_MAX_AMOUNT)),
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [DEPOSIT_EVENT, FIRST_TEST_TOKEN.as_str()]),
+    ];
+
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        false,
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            assert!(sc.separate_inbound_policy().get());
+            assert!(sc.max_bridged_token_amounts().is_empty());
+        });
+}
+
+/// ### TEST
+/// M-ESDT_PAUSE_STATUS_OK
+///
+/// ### ACTION
+/// Call `switch_pause_status()` when the contract is unpaused
+///
+/// ### EXPECTED
+/// Contract is paused and deposit endpoint fails
+#[test]
+fn test_pause_contract() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let nonce = state.common_setup.next_operation_nonce();
+    let pause_operation = PauseStatusOperation {
+        status: true,
+        nonce,
+    };
+    let operation_hash = pause_operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.complete_setup_phase();
+
+    let bitmap = state.common_setup.full_bitmap(1);
+    let epoch = 0;
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        bitmap,
+        epoch,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.switch_pause_status(
+        &hash_of_hashes,
+        pause_operation,
+        vec![log!(
+            PAUSE_CONTRACT_LOG,
+            topics: [EXECUTED_BRIDGE_OP_EVENT]
+        )],
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            assert!(sc.is_paused());
+        });
+
+    let egld_payment = EgldOrEsdtTokenPayment::egld_payment(BigUint::from(100u64));
+    let payments_vec = PaymentsVec::from(vec![egld_payment]);
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        payments_vec,
+        Some(ESDT_SAFE_STILL_PAUSED),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_FREEZE_TOKEN_OK
+///
+/// ### ACTION
+/// Call `set_token_freeze_status()` for a token
+///
+/// ### EXPECTED
+/// Token is listed as frozen and depositing it fails
+#[test]
+fn test_freeze_token() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let freeze_operation = SetTokenFreezeOperation {
+        token_id: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        frozen: true,
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let operation_hash = freeze_operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.complete_setup_phase();
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.set_token_freeze_status(
+        &hash_of_hashes,
+        freeze_operation,
+        vec![log!(
+            SET_TOKEN_FREEZE_STATUS_LOG,
+            topics: [EXECUTED_BRIDGE_OP_EVENT]
+        )],
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            assert!(sc
+                .frozen_tokens()
+                .contains(&EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)));
+        });
+
+    let esdt_token_payment = EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(FIRST_TEST_TOKEN),
+        0,
+        BigUint::from(100u64),
+    );
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![esdt_token_payment]),
+        Some(TOKEN_FROZEN),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_OK
+///
+/// ### ACTION
+/// Call `execute_operation()` with a frozen token
+///
+/// ### EXPECTED
+/// The operation fails with a frozen token error and the tokens are refunded
+#[test]
+fn test_execute_frozen_token_refund() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
+
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            sc.frozen_tokens()
+                .insert(EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN));
+        });
+
+    let token_data = EsdtTokenData {
+        amount: BigUint::from(100u64),
+        ..Default::default()
+    };
+
+    let payment = OperationEsdtPayment::new(
+        EgldOrEsdtTokenIdentifier::from(FIRST_TEST_TOKEN.as_bytes()),
         0,
         token_data,
     );
+    let operation_data = OperationData::new(
+        state.common_setup.next_operation_nonce(),
+        OWNER_ADDRESS.to_managed_address(),
+        None,
+    );
+    let operation = Operation {
+        to: USER_ADDRESS.to_managed_address(),
+        tokens: vec![payment].into(),
+        data: operation_data,
+    };
+
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+    let (signature, bls_key) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+    state
+        .common_setup
+        .register(&bls_key[0], &MultiEgldOrEsdtPayment::new(), None);
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    let expected_logs = vec![
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(TOKEN_FROZEN)),
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [DEPOSIT_EVENT, FIRST_TEST_TOKEN.as_str()]),
+    ];
+
+    state.register_and_execute_operation(
+        &operation,
+        &hash_of_hashes,
+        signature,
+        1,
+        expected_logs,
+        false,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0,
+        BigUint::zero(),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_EXEC_OK
+///
+/// ### ACTION
+/// Execute a bridge operation with mixed token payments, including one without prefunded liquidity.
+///
+/// ### EXPECTED
+/// Contract emits the partial-execution logs and refunds earlier mints, leaving balances unchanged.
+#[test]
+fn test_execute_operation_partial_execution() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_TOKEN, None);
+    state.complete_setup_phase();
+
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let token_pairs = [
+                (FIRST_TOKEN_ID, SOV_FIRST_TOKEN_ID),
+                (SECOND_TOKEN_ID, SOV_SECOND_TOKEN_ID),
+            ];
+
+            for (mvx_token, sov_token) in token_pairs {
+                sc.multiversx_to_sovereign_token_id_mapper(&EgldOrEsdtTokenIdentifier::esdt(
+                    mvx_token,
+                ))
+                .set(EgldOrEsdtTokenIdentifier::esdt(
+                    sov_token.to_token_identifier(),
+                ));
+
+                sc.sovereign_to_multiversx_token_id_mapper(&EgldOrEsdtTokenIdentifier::esdt(
+                    sov_token.to_token_identifier(),
+                ))
+                .set(EgldOrEsdtTokenIdentifier::esdt(
+                    mvx_token.to_token_identifier(),
+                ));
+            }
+        });
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let token_data = EsdtTokenData {
+        amount: BigUint::from(ONE_HUNDRED_THOUSAND),
+        ..Default::default()
+    };
+
+    let token_ids = [
+        EgldOrEsdtTokenIdentifier::esdt(SOV_FIRST_TOKEN_ID),
+        EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
+        EgldOrEsdtTokenIdentifier::esdt(SOV_SECOND_TOKEN_ID),
+    ];
+
+    let payments: Vec<_> = token_ids
+        .iter()
+        .map(|token_id| OperationEsdtPayment::new(token_id.clone(), 0, token_data.clone()))
+        .collect();
+
+    let operation = Operation::new(
+        USER_ADDRESS.to_managed_address(),
+        payments.into(),
+        OperationData::new(
+            state.common_setup.next_operation_nonce(),
+            USER_ADDRESS.to_managed_address(),
+            None,
+        ),
+    );
+    let operation_hash = state.common_setup.get_operation_hash(&operation);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    let expected_logs = vec![
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(DEPOSIT_AMOUNT_NOT_ENOUGH)),
+        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [DEPOSIT_EVENT, SOV_FIRST_TOKEN_ID.as_str(), TRUSTED_TOKEN, SOV_SECOND_TOKEN_ID.as_str()]),
+    ];
+
+    let bitmap = state.common_setup.full_bitmap(1);
+    let operations_hashes = MultiValueEncoded::from(ManagedVec::from(vec![operation_hash.clone()]));
+
+    state.common_setup.register_operation(
+        USER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        bitmap,
+        0,
+        operations_hashes,
+    );
+
+    state.execute_operation(&hash_of_hashes, &operation, expected_logs);
+
+    let addresses = [USER_ADDRESS.to_address(), ESDT_SAFE_ADDRESS.to_address()];
+    let tokens = [
+        FIRST_TOKEN_ID,
+        SECOND_TOKEN_ID,
+        TestTokenIdentifier::new(TRUSTED_TOKEN),
+    ];
+
+    for address in &addresses {
+        for token_id in &tokens {
+            state.common_setup.check_account_single_esdt(
+                address.clone(),
+                *token_id,
+                0,
+                BigUint::zero(),
+            );
+        }
+    }
+}
+
+/// ### TEST
+/// M-ESDT_MIGRATE_SAFE_OK
+///
+/// ### ACTION
+/// Call `migrate_safe()` towards the successor registered in the forge, then `import_migration_state()` on the successor
+///
+/// ### EXPECTED
+/// The old safe is paused, the escrow is moved to the successor and the mapping state is imported.
+/// Importing the same page again leaves the state as it is.
+/// Deposits stay rejected even after the owner calls `unpause`
+#[test]
+fn test_migrate_safe() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_TOKEN, None);
+    state.deploy_successor_safe();
+    state
+        .common_setup
+        .set_esdt_safe_successor_in_forge(SOVEREIGN_TOKEN_PREFIX, SUCCESSOR_ESDT_SAFE_ADDRESS);
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let migrate_safe_operation = MigrateSafeOperation {
+        successor: SUCCESSOR_ESDT_SAFE_ADDRESS.to_managed_address(),
+        tokens: ManagedVec::from(vec![
+            EsdtInfo {
+                token_identifier: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+                token_nonce: 0,
+            },
+            EsdtInfo {
+                token_identifier: EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
+                token_nonce: 0,
+            },
+        ]),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let operation_hash = migrate_safe_operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.complete_setup_phase();
+
+    let lock_payment = EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(FIRST_TEST_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+    let burn_payment = EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(TRUSTED_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND / 2),
+    );
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![lock_payment, burn_payment]),
+        None,
+    );
+
+    state.import_migration_state(0, 10, Some(SAFE_NOT_MIGRATED));
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.migrate_safe(
+        &hash_of_hashes,
+        migrate_safe_operation,
+        vec![log!(MIGRATE_SAFE_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])],
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::zero(),
+    );
+    state.common_setup.check_account_single_esdt(
+        SUCCESSOR_ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            assert!(sc.is_paused());
+            assert!(
+                sc.successor_safe_address().get()
+                    == SUCCESSOR_ESDT_SAFE_ADDRESS.to_managed_address()
+            );
+            assert!(sc.get_migration_state_count() == 5);
+        });
+
+    state.import_migration_state(0, 10, None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SUCCESSOR_ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let trusted_token_id = EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN);
+
+            assert!(sc.is_burn_mechanism_set(&trusted_token_id));
+            assert!(
+                sc.deposited_tokens_amount(&trusted_token_id, 0).get() == ONE_HUNDRED_THOUSAND / 2
+            );
+            assert!(sc.native_token().get() == EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN));
+            assert!(sc.get_registered_tokens_count() == 2);
+            assert!(sc.migration_import_index().get() == 5);
+        });
+
+    state.import_migration_state(0, 10, None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SUCCESSOR_ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let trusted_token_id = EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN);
+
+            assert!(
+                sc.deposited_tokens_amount(&trusted_token_id, 0).get() == ONE_HUNDRED_THOUSAND / 2
+            );
+            assert!(sc.native_token().get() == EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN));
+            assert!(sc.get_registered_tokens_count() == 2);
+            assert!(sc.migration_import_index().get() == 5);
+        });
+
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(HEADER_VERIFIER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(MvxEsdtSafeProxy)
+        .unpause_endpoint()
+        .run();
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![EsdtTokenPayment::<StaticApi>::new(
+            EsdtTokenIdentifier::from(FIRST_TEST_TOKEN),
+            0,
+            BigUint::from(ONE_HUNDRED_THOUSAND),
+        )]),
+        Some(SAFE_ALREADY_MIGRATED),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_MIGRATE_SAFE_OK
+///
+/// ### ACTION
+/// Migrate a safe holding the kept unit of a burn mechanism SFT nonce, import its state, then
+/// process an operation bridging the whole nonce back on the successor
+///
+/// ### EXPECTED
+/// The kept unit and its flag move to the successor, which adds the quantity back for the nonce
+#[test]
+fn test_migrate_safe_kept_nonce_unit() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_SFT_TOKEN, None);
+    state.deploy_successor_safe();
+    state
+        .common_setup
+        .set_esdt_safe_successor_in_forge(SOVEREIGN_TOKEN_PREFIX, SUCCESSOR_ESDT_SAFE_ADDRESS);
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let sft_id = EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN);
+    let migrate_safe_operation = MigrateSafeOperation {
+        successor: SUCCESSOR_ESDT_SAFE_ADDRESS.to_managed_address(),
+        tokens: ManagedVec::from(vec![EsdtInfo {
+            token_identifier: sft_id.clone(),
+            token_nonce: 1,
+        }]),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let operation_hash = migrate_safe_operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.complete_setup_phase();
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![EsdtTokenPayment::<StaticApi>::new(
+            EsdtTokenIdentifier::from(TRUSTED_SFT_TOKEN),
+            1,
+            BigUint::from(ONE_HUNDRED_THOUSAND),
+        )]),
+        None,
+    );
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+    state.migrate_safe(
+        &hash_of_hashes,
+        migrate_safe_operation,
+        vec![log!(MIGRATE_SAFE_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT])],
+    );
 
-    let gas_limit = 1;
-    let function = ManagedBuffer::<StaticApi>::from(WRONG_ENDPOINT_NAME);
-    let args =
-        ManagedVec::<StaticApi, ManagedBuffer<StaticApi>>::from(vec![ManagedBuffer::from("1")]);
-    let transfer_data = TransferData::new(gas_limit, function, args);
-    let operation_data = OperationData::new(
-        state.common_setup.next_operation_nonce(),
-        OWNER_ADDRESS.to_managed_address(),
-        Some(transfer_data),
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        BigUint::zero(),
     );
-    let operation = Operation::new(
-        TESTING_SC_ADDRESS.to_managed_address(),
-        vec![payment].into(),
-        operation_data,
+    state.common_setup.check_account_single_esdt(
+        SUCCESSOR_ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        BigUint::from(1u64),
     );
-    let operation_hash = state.common_setup.get_operation_hash(&operation);
+
+    state.import_migration_state(0, 10, None);
+
+    let operation_nonce = state.common_setup.next_operation_nonce();
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SUCCESSOR_ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let sft_id = EgldOrEsdtTokenIdentifier::esdt(TRUSTED_SFT_TOKEN);
+
+            let operation = Operation::new(
+                TESTING_SC_ADDRESS.to_managed_address(),
+                vec![OperationEsdtPayment::new(
+                    sft_id.clone(),
+                    1,
+                    EsdtTokenData {
+                        amount: BigUint::from(ONE_HUNDRED_THOUSAND),
+                        token_type: EsdtTokenType::SemiFungible,
+                        ..Default::default()
+                    },
+                )]
+                .into(),
+                OperationData::new(operation_nonce, OWNER_ADDRESS.to_managed_address(), None),
+            );
+
+            assert!(sc.is_burn_mechanism_set(&sft_id));
+            assert!(sc.kept_nonce_unit(&sft_id, 1).get());
+            assert!(sc.deposited_tokens_amount(&sft_id, 1).get() == ONE_HUNDRED_THOUSAND - 1);
+
+            assert!(sc.process_operation_payments(&operation).is_ok());
+            assert!(!sc.kept_nonce_unit(&sft_id, 1).get());
+            assert!(sc.deposited_tokens_amount(&sft_id, 1).get() == 0u64);
+        });
+
+    state.common_setup.check_account_single_esdt(
+        SUCCESSOR_ESDT_SAFE_ADDRESS.to_address(),
+        TestTokenIdentifier::new(TRUSTED_SFT_TOKEN),
+        1u64,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+}
+
+/// ### TEST
+/// M-ESDT_MIGRATE_SAFE_FAIL
+///
+/// ### ACTION
+/// Call `migrate_safe()` towards a registered successor that lives in another shard
+///
+/// ### EXPECTED
+/// The operation fails with SUCCESSOR_IN_OTHER_SHARD and the safe is not migrated
+#[test]
+fn test_migrate_safe_successor_in_other_shard() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+    state
+        .common_setup
+        .set_esdt_safe_successor_in_forge(SOVEREIGN_TOKEN_PREFIX, OTHER_SHARD_SUCCESSOR_ADDRESS);
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let migrate_safe_operation = MigrateSafeOperation {
+        successor: OTHER_SHARD_SUCCESSOR_ADDRESS.to_managed_address(),
+        tokens: ManagedVec::new(),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let operation_hash = migrate_safe_operation.generate_hash();
     let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
 
     let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
-
     state.common_setup.register(
         public_keys.first().unwrap(),
         &MultiEgldOrEsdtPayment::new(),
         None,
     );
-
     state.common_setup.complete_chain_config_setup_phase();
 
     state
         .common_setup
         .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
-
     state
         .common_setup
         .complete_header_verifier_setup_phase(None);
 
-    state.common_setup.deploy_testing_sc();
+    state.complete_setup_phase();
 
-    let expected_logs = vec![
-        log!(EXECUTE_OPERATION_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(INVALID_FUNCTION_NOT_FOUND)),
-    ];
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
 
-    state.register_and_execute_operation(
-        &operation,
+    state.migrate_safe(
         &hash_of_hashes,
-        signature,
-        1,
-        expected_logs,
-        true,
+        migrate_safe_operation,
+        vec![
+            log!(MIGRATE_SAFE_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(SUCCESSOR_IN_OTHER_SHARD)),
+        ],
     );
 
-    let addresses = [OWNER_ADDRESS.to_address(), TESTING_SC_ADDRESS.to_address()];
-    for address in &addresses {
-        state.common_setup.check_account_single_esdt(
-            address.clone(),
-            NATIVE_TEST_TOKEN,
-            0u64,
-            BigUint::zero(),
-        );
-    }
+    state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            assert!(!sc.safe_migrated().get());
+            assert!(!sc.is_paused());
+        });
 }
 
 /// ### TEST
-/// M-ESDT_SET_BURN_FAIL
+/// M-ESDT_DEP_REFUND_ADDRESS_OK
 ///
 /// ### ACTION
-/// Call 'set_token_burn_mechanism()' without the proper roles
+/// Call 'deposit_with_refund_address()' with a non-whitelisted token
 ///
 /// ### EXPECTED
-/// Error MINT_AND_BURN_ROLES_NOT_FOUND
+/// The tokens are refunded to the refund address instead of the depositor
 #[test]
-fn test_set_token_burn_mechanism_no_roles() {
+fn test_deposit_with_refund_address() {
     let mut state = MvxEsdtSafeTestState::new();
-    state.deploy_contract_with_roles(None);
+
+    let config = EsdtSafeConfig {
+        token_whitelist: ManagedVec::from(vec![EgldOrEsdtTokenIdentifier::esdt(CROWD_TOKEN_ID)]),
+        ..EsdtSafeConfig::default_config()
+    };
 
     state
         .common_setup
-        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+        .deploy_mvx_esdt_safe(OptionalValue::Some(config));
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            sc.native_token()
+                .set(EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN));
+        });
+    state
+        .common_setup
+        .deploy_fee_market(None, ESDT_SAFE_ADDRESS);
+    state.set_fee_market_address(FEE_MARKET_ADDRESS);
+    state.complete_setup_phase();
 
-    state.set_token_burn_mechanism_before_setup_phase("WEGLD", Some(MINT_AND_BURN_ROLES_NOT_FOUND));
+    let esdt_token_payment = EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(FIRST_TEST_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+
+    state.deposit_with_refund_address(
+        USER_ADDRESS.to_managed_address(),
+        ManagedAddress::zero(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![esdt_token_payment.clone()]),
+        Some(INVALID_REFUND_ADDRESS),
+    );
+
+    state.deposit_with_refund_address(
+        USER_ADDRESS.to_managed_address(),
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        PaymentsVec::from(vec![esdt_token_payment]),
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        OWNER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_MILLION - ONE_HUNDRED_THOUSAND),
+    );
+    state.common_setup.check_account_single_esdt(
+        USER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_MILLION + ONE_HUNDRED_THOUSAND),
+    );
 }
 
 /// ### TEST
-/// M-ESDT_SET_BURN_FAIL
+/// M-ESDT_DEP_MULTI_OK
 ///
 /// ### ACTION
-/// Call 'set_token_burn_mechanism()' without a trusted token id
+/// Call 'deposit_multi()' with one entry per payment for two different recipients
 ///
 /// ### EXPECTED
-/// Error TOKEN_ID_IS_NOT_TRUSTED
+/// The tokens are locked in the safe and each recipient gets its own tx nonce
 #[test]
-fn test_set_token_burn_mechanism_token_not_trusted() {
+fn test_deposit_multi() {
     let mut state = MvxEsdtSafeTestState::new();
-    state.deploy_contract_with_roles(None);
+
+    state.common_setup.deploy_mvx_esdt_safe(OptionalValue::None);
     state
         .common_setup
-        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            sc.native_token()
+                .set(EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN));
+        });
+    state
+        .common_setup
+        .deploy_fee_market(None, ESDT_SAFE_ADDRESS);
+    state.set_fee_market_address(FEE_MARKET_ADDRESS);
+    state.complete_setup_phase();
 
-    state.set_token_burn_mechanism_before_setup_phase(
-        FIRST_TEST_TOKEN.as_str(),
-        Some(TOKEN_ID_IS_NOT_TRUSTED),
+    let first_payment = EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(FIRST_TEST_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
     );
-}
+    let second_payment = EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(SECOND_TEST_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+    let payments_vec = PaymentsVec::from(vec![first_payment, second_payment]);
 
-/// ### TEST
-/// M-ESDT_SET_BURN_OK
-///
-/// ### ACTION
-/// Call 'set_token_burn_mechanism()' with a trusted token id
-///
-/// ### EXPECTED
-/// The trusted token has the burn mechanism set
-#[test]
-fn test_set_token_burn_mechanism() {
-    let mut state = MvxEsdtSafeTestState::new();
-    state.deploy_contract_with_roles(None);
+    let user_entry = MultiDepositEntry {
+        to: USER_ADDRESS.to_managed_address(),
+        payment_index: 1,
+        opt_transfer_data: None,
+    };
+    let receiver_entry = MultiDepositEntry {
+        to: SOVEREIGN_RECEIVER_ADDRESS.to_managed_address(),
+        payment_index: 0,
+        opt_transfer_data: None,
+    };
+
+    state.deposit_multi(
+        vec![user_entry.clone()],
+        payments_vec.clone(),
+        Some(DEPOSIT_ENTRIES_PAYMENTS_MISMATCH),
+    );
+
+    state.deposit_multi(
+        vec![user_entry.clone(), user_entry.clone()],
+        payments_vec.clone(),
+        Some(INVALID_PAYMENT_INDEX),
+    );
 
+    state.deposit_multi(vec![user_entry, receiver_entry], payments_vec, None);
+
+    let tokens_vec = vec![
+        MultiValue3::from((FIRST_TEST_TOKEN, 0u64, BigUint::from(ONE_HUNDRED_THOUSAND))),
+        MultiValue3::from((SECOND_TEST_TOKEN, 0u64, BigUint::from(ONE_HUNDRED_THOUSAND))),
+    ];
     state
         .common_setup
-        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
-
-    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_TOKEN, None);
+        .check_account_multiple_esdts(ESDT_SAFE_ADDRESS.to_address(), tokens_vec);
 
     state
         .common_setup
@@ -2960,38 +6180,103 @@ fn test_set_token_burn_mechanism() {
         .query()
         .to(ESDT_SAFE_ADDRESS)
         .whitebox(mvx_esdt_safe::contract_obj, |sc| {
-            assert!(sc
-                .burn_mechanism_tokens()
-                .contains(&EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN)))
+            assert_eq!(sc.last_tx_nonce().get(), 2);
         });
-
-    state.common_setup.check_account_single_esdt(
-        ESDT_SAFE_ADDRESS.to_address(),
-        TestTokenIdentifier::new(TRUSTED_TOKEN),
-        0u64,
-        BigUint::zero(),
-    );
 }
 
 /// ### TEST
-/// M-ESDT_SET_BURN_OK
+/// M-ESDT_DEP_MULTI_REFUND_ADDRESS_OK
 ///
 /// ### ACTION
-/// Call both 'set_token_burn_mechanism()' and 'set_token_lock_mechanism()' with a trusted token id.
+/// Call 'deposit_multi_with_refund_address()' with one whitelisted and one non-whitelisted entry
 ///
 /// ### EXPECTED
-/// The trusted token has the lock mechanism set
+/// The non-whitelisted tokens go to the refund address and only the sent entry gets a tx nonce
 #[test]
-fn test_set_token_lock_mechanism() {
+fn test_deposit_multi_with_refund_address() {
     let mut state = MvxEsdtSafeTestState::new();
-    state.deploy_contract_with_roles(None);
 
-    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_TOKEN, None);
-    state.set_token_lock_mechanism_before_setup_phase(TRUSTED_TOKEN, None);
+    let config = EsdtSafeConfig {
+        token_whitelist: ManagedVec::from(vec![EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)]),
+        ..EsdtSafeConfig::default_config()
+    };
+
+    state
+        .common_setup
+        .deploy_mvx_esdt_safe(OptionalValue::Some(config));
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            sc.native_token()
+                .set(EgldOrEsdtTokenIdentifier::esdt(NATIVE_TEST_TOKEN));
+        });
+    state
+        .common_setup
+        .deploy_fee_market(None, ESDT_SAFE_ADDRESS);
+    state.set_fee_market_address(FEE_MARKET_ADDRESS);
+    state.complete_setup_phase();
+
+    let first_payment = EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(FIRST_TEST_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+    let second_payment = EsdtTokenPayment::<StaticApi>::new(
+        EsdtTokenIdentifier::from(SECOND_TEST_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+    let payments_vec = PaymentsVec::from(vec![first_payment, second_payment]);
+
+    let entries = vec![
+        MultiDepositEntry {
+            to: SOVEREIGN_RECEIVER_ADDRESS.to_managed_address(),
+            payment_index: 0,
+            opt_transfer_data: None,
+        },
+        MultiDepositEntry {
+            to: SOVEREIGN_RECEIVER_ADDRESS.to_managed_address(),
+            payment_index: 1,
+            opt_transfer_data: None,
+        },
+    ];
+
+    state.deposit_multi_with_refund_address(
+        ManagedAddress::zero(),
+        entries.clone(),
+        payments_vec.clone(),
+        Some(INVALID_REFUND_ADDRESS),
+    );
+
+    state.deposit_multi_with_refund_address(
+        USER_ADDRESS.to_managed_address(),
+        entries,
+        payments_vec,
+        None,
+    );
 
-    state
-        .common_setup
-        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
+    state.common_setup.check_account_single_esdt(
+        OWNER_ADDRESS.to_address(),
+        SECOND_TEST_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_MILLION - ONE_HUNDRED_THOUSAND),
+    );
+    state.common_setup.check_account_single_esdt(
+        USER_ADDRESS.to_address(),
+        SECOND_TEST_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
 
     state
         .common_setup
@@ -2999,104 +6284,172 @@ fn test_set_token_lock_mechanism() {
         .query()
         .to(ESDT_SAFE_ADDRESS)
         .whitebox(mvx_esdt_safe::contract_obj, |sc| {
-            assert!(sc.burn_mechanism_tokens().is_empty())
+            assert_eq!(sc.last_tx_nonce().get(), 1);
         });
-
-    state.common_setup.check_account_single_esdt(
-        ESDT_SAFE_ADDRESS.to_address(),
-        TestTokenIdentifier::new(TRUSTED_TOKEN),
-        100u64,
-        BigUint::zero(),
-    );
 }
 
 /// ### TEST
-/// M-ESDT_UPDATE_CONFIG_FAIL
+/// M-ESDT_OPERATION_DATA_ENCODING_OK
 ///
 /// ### ACTION
-/// Call `update_esdt_safe_config()` before setup phase completion
+/// Encode `OperationData` with and without a refund address
 ///
 /// ### EXPECTED
-/// ERROR SETUP_PHASE_NOT_COMPLETED
+/// Without a refund address the legacy layout is kept, with one the field is appended and decoded back
 #[test]
-fn test_update_config_setup_phase_not_completed() {
-    let mut state = MvxEsdtSafeTestState::new();
-    state.deploy_contract_with_roles(None);
+fn test_operation_data_refund_address_encoding() {
+    let legacy_operation_data =
+        OperationData::<StaticApi>::new(7u64, USER_ADDRESS.to_managed_address(), None);
 
-    let esdt_safe_config = EsdtSafeConfig::default_config();
+    let mut expected_legacy_encoding = 7u64.to_be_bytes().to_vec();
+    expected_legacy_encoding.extend_from_slice(USER_ADDRESS.to_address().as_bytes());
+    expected_legacy_encoding.push(0);
 
-    let nonce = state.common_setup.next_operation_nonce();
-    state.update_esdt_safe_config(
-        &ManagedBuffer::new(),
-        UpdateEsdtSafeConfigOperation {
-            esdt_safe_config,
-            nonce,
-        },
-        Some(SETUP_PHASE_NOT_COMPLETED),
-    );
+    let legacy_encoding = top_encode_to_vec_u8_or_panic(&legacy_operation_data);
+    assert_eq!(legacy_encoding, expected_legacy_encoding);
+
+    let operation_data =
+        legacy_operation_data.with_refund_address(Some(OWNER_ADDRESS.to_managed_address()));
+    let encoding = top_encode_to_vec_u8_or_panic(&operation_data);
+    assert_eq!(encoding.len(), expected_legacy_encoding.len() + 33);
+
+    let decoded_operation_data = OperationData::<StaticApi>::top_decode(encoding).unwrap();
+    assert!(decoded_operation_data.refund_address() == &OWNER_ADDRESS.to_managed_address());
+
+    let decoded_legacy_operation_data =
+        OperationData::<StaticApi>::top_decode(legacy_encoding).unwrap();
+    assert!(decoded_legacy_operation_data.refund_address() == &USER_ADDRESS.to_managed_address());
 }
 
 /// ### TEST
-/// M-ESDT_UPDATE_CONFIG_FAIL
+/// M-ESDT_OPERATION_DATA_CONTAINER_ENCODING_OK
 ///
 /// ### ACTION
-/// Call `update_esdt_safe_config()` before registering operation
+/// Encode `Operation` and `RegisterTokenOperation` with every combination of the trailing `OperationData` fields
 ///
 /// ### EXPECTED
-/// ERROR CURRENT_OPERATION_NOT_REGISTERED
+/// A destination chain id alone is preceded by an encoded `None` refund address and every layout decodes back unchanged
 #[test]
-fn test_update_config_operation_not_registered() {
-    let mut state = MvxEsdtSafeTestState::new();
-    state.deploy_contract_with_roles(None);
-    state.complete_setup_phase();
-
-    state
-        .common_setup
-        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
+fn test_operation_data_trailing_fields_in_containers() {
+    let destination_chain_id = ManagedBuffer::<StaticApi>::from("sov1");
+    let legacy_operation_data =
+        OperationData::<StaticApi>::new(7u64, USER_ADDRESS.to_managed_address(), None);
+    let legacy_encoding = top_encode_to_vec_u8_or_panic(&legacy_operation_data);
+
+    let chain_only_operation_data = legacy_operation_data
+        .clone()
+        .with_destination_chain_id(Some(destination_chain_id.clone()));
+    let mut expected_chain_only_encoding = legacy_encoding.clone();
+    expected_chain_only_encoding.push(0);
+    expected_chain_only_encoding.push(1);
+    expected_chain_only_encoding.extend_from_slice(&4u32.to_be_bytes());
+    expected_chain_only_encoding.extend_from_slice(b"sov1");
+    assert_eq!(
+        top_encode_to_vec_u8_or_panic(&chain_only_operation_data),
+        expected_chain_only_encoding
+    );
+
+    let all_operation_data = [
+        legacy_operation_data.clone(),
+        legacy_operation_data
+            .clone()
+            .with_refund_address(Some(OWNER_ADDRESS.to_managed_address())),
+        chain_only_operation_data,
+        legacy_operation_data
+            .with_refund_address(Some(OWNER_ADDRESS.to_managed_address()))
+            .with_destination_chain_id(Some(destination_chain_id)),
+    ];
 
-    let esdt_safe_config = EsdtSafeConfig::default_config();
+    for operation_data in all_operation_data {
+        let operation = Operation::new(
+            ESDT_SAFE_ADDRESS.to_managed_address(),
+            ManagedVec::new(),
+            operation_data.clone(),
+        );
+        let decoded_operation =
+            Operation::<StaticApi>::top_decode(top_encode_to_vec_u8_or_panic(&operation)).unwrap();
+        assert_operation_data_eq(&decoded_operation.data, &operation_data);
+        assert!(decoded_operation.to == ESDT_SAFE_ADDRESS.to_managed_address());
+
+        let register_token_operation = RegisterTokenOperation {
+            token_id: EgldOrEsdtTokenIdentifier::esdt(SOV_TOKEN.to_token_identifier()),
+            token_type: EsdtTokenType::Fungible,
+            token_display_name: ManagedBuffer::from("Token"),
+            token_ticker: ManagedBuffer::from("TKN"),
+            num_decimals: 18,
+            data: operation_data.clone(),
+        };
+        let decoded_register_token_operation = RegisterTokenOperation::<StaticApi>::top_decode(
+            top_encode_to_vec_u8_or_panic(&register_token_operation),
+        )
+        .unwrap();
+        assert_operation_data_eq(&decoded_register_token_operation.data, &operation_data);
+        assert_eq!(decoded_register_token_operation.num_decimals, 18);
+    }
+}
 
-    let nonce = state.common_setup.next_operation_nonce();
-    state.update_esdt_safe_config(
-        &ManagedBuffer::new(),
-        UpdateEsdtSafeConfigOperation {
-            esdt_safe_config,
-            nonce,
-        },
-        Some(CURRENT_OPERATION_NOT_REGISTERED),
-    );
+fn assert_operation_data_eq(
+    actual: &OperationData<StaticApi>,
+    expected: &OperationData<StaticApi>,
+) {
+    assert_eq!(actual.op_nonce, expected.op_nonce);
+    assert!(actual.op_sender == expected.op_sender);
+    assert!(actual.opt_transfer_data.is_none() && expected.opt_transfer_data.is_none());
+    assert!(actual.opt_refund_address == expected.opt_refund_address);
+    assert!(actual.opt_destination_chain_id == expected.opt_destination_chain_id);
 }
 
 /// ### TEST
-/// M-ESDT_UPDATE_CONFIG_ERROR
+/// M-ESDT_UPDATE_NFT_METADATA_FAIL
 ///
 /// ### ACTION
-/// Call `update_esdt_safe_config()` with an invalid config
+/// Call `update_nft_metadata()` for a registered sovereign token with an unknown nonce
 ///
 /// ### EXPECTED
-/// failedBridgeOp event is emitted
+/// The operation completes with `NFT_NONCE_NOT_REGISTERED`
 #[test]
-fn test_update_config_invalid_config() {
+fn test_update_nft_metadata_nonce_not_registered() {
     let mut state = MvxEsdtSafeTestState::new();
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
 
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            let sov_token_id = EgldOrEsdtTokenIdentifier::esdt(SOV_TOKEN.to_token_identifier());
+            let mvx_token_id =
+                EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN.to_token_identifier());
+
+            sc.multiversx_to_sovereign_token_id_mapper(&mvx_token_id)
+                .set(sov_token_id.clone());
+            sc.sovereign_to_multiversx_token_id_mapper(&sov_token_id)
+                .set(mvx_token_id);
+        });
+
     state.common_setup.deploy_chain_config(
         OptionalValue::Some(SovereignConfig::default_config_for_test()),
         None,
     );
 
-    let esdt_safe_config = EsdtSafeConfig {
-        max_tx_gas_limit: MAX_GAS_PER_TRANSACTION + 1,
-        ..EsdtSafeConfig::default_config()
-    };
-    let update_config_operation = UpdateEsdtSafeConfigOperation {
-        esdt_safe_config: esdt_safe_config.clone(),
+    let operation = UpdateNftMetadataOperation {
+        token_id: EgldOrEsdtTokenIdentifier::esdt(SOV_TOKEN.to_token_identifier()),
+        token_nonce: 1,
+        metadata: NftMetadata {
+            name: ManagedBuffer::from("Updated NFT"),
+            royalties: 0,
+            hash: ManagedBuffer::new(),
+            attributes: ManagedBuffer::from("level:2"),
+            uris: ManagedVec::new(),
+        },
         nonce: state.common_setup.next_operation_nonce(),
     };
 
-    let config_hash = update_config_operation.generate_hash();
-    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&config_hash.to_vec()));
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
 
     let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
 
@@ -3105,7 +6458,6 @@ fn test_update_config_invalid_config() {
         &MultiEgldOrEsdtPayment::new(),
         None,
     );
-
     state.common_setup.complete_chain_config_setup_phase();
 
     state
@@ -3115,56 +6467,62 @@ fn test_update_config_invalid_config() {
         .common_setup
         .complete_header_verifier_setup_phase(None);
 
-    let bitmap = state.common_setup.full_bitmap(1);
-    let epoch = 0;
-
     state.common_setup.register_operation(
         OWNER_ADDRESS,
         signature,
         &hash_of_hashes,
-        bitmap,
-        epoch,
-        MultiValueEncoded::from_iter(vec![config_hash]),
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
     );
 
-    state.update_esdt_safe_config(
-        &hash_of_hashes,
-        update_config_operation,
-        Some(MAX_GAS_LIMIT_PER_TX_EXCEEDED),
-    );
+    state.update_nft_metadata(&hash_of_hashes, operation, Some(NFT_NONCE_NOT_REGISTERED));
 }
 
 /// ### TEST
-/// M-ESDT_UPDATE_CONFIG_OK
+/// M-ESDT_UPDATE_NFT_METADATA_FAIL
 ///
 /// ### ACTION
-/// Call `update_esdt_safe_config()`
+/// Call `update_nft_metadata()` while the safe is paused
 ///
 /// ### EXPECTED
-/// EsdtSafeConfig is updated and executedBridgeOp is emitted
+/// The operation completes with `ESDT_SAFE_STILL_PAUSED`
 #[test]
-fn test_update_config() {
+fn test_update_nft_metadata_paused() {
     let mut state = MvxEsdtSafeTestState::new();
     state.deploy_contract_with_roles(None);
     state.complete_setup_phase();
 
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            sc.paused_status().set(true);
+        });
+
     state.common_setup.deploy_chain_config(
         OptionalValue::Some(SovereignConfig::default_config_for_test()),
         None,
     );
 
-    let esdt_safe_config = EsdtSafeConfig {
-        max_tx_gas_limit: ONE_HUNDRED_THOUSAND as u64,
-        address_blacklist: ManagedVec::from_iter(vec![OWNER_ADDRESS.to_managed_address()]),
-        ..EsdtSafeConfig::default_config()
-    };
-    let update_config_operation = UpdateEsdtSafeConfigOperation {
-        esdt_safe_config,
+    let operation = UpdateNftMetadataOperation {
+        token_id: EgldOrEsdtTokenIdentifier::esdt(SOV_TOKEN.to_token_identifier()),
+        token_nonce: 1,
+        metadata: NftMetadata {
+            name: ManagedBuffer::from("Updated NFT"),
+            royalties: 0,
+            hash: ManagedBuffer::new(),
+            attributes: ManagedBuffer::from("level:2"),
+            uris: ManagedVec::new(),
+        },
         nonce: state.common_setup.next_operation_nonce(),
     };
 
-    let config_hash = update_config_operation.generate_hash();
-    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&config_hash.to_vec()));
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
 
     let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
 
@@ -3173,30 +6531,42 @@ fn test_update_config() {
         &MultiEgldOrEsdtPayment::new(),
         None,
     );
-
     state.common_setup.complete_chain_config_setup_phase();
 
     state
         .common_setup
         .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
-
     state
         .common_setup
         .complete_header_verifier_setup_phase(None);
 
-    let bitmap = state.common_setup.full_bitmap(1);
-    let epoch = 0;
-
     state.common_setup.register_operation(
         OWNER_ADDRESS,
         signature,
         &hash_of_hashes,
-        bitmap,
-        epoch,
-        MultiValueEncoded::from_iter(vec![config_hash]),
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
     );
 
-    state.update_esdt_safe_config(&hash_of_hashes, update_config_operation.clone(), None);
+    state.update_nft_metadata(&hash_of_hashes, operation, Some(ESDT_SAFE_STILL_PAUSED));
+}
+
+/// ### TEST
+/// M-ESDT_ISSUE_POOL_OK
+///
+/// ### ACTION
+/// Call `fund_issue_pool()` with EGLD and then with no EGLD
+///
+/// ### EXPECTED
+/// The pool balance and the funder contribution are increased, the empty funding is rejected
+#[test]
+fn test_fund_issue_pool() {
+    let mut state = MvxEsdtSafeTestState::new();
+    state.deploy_contract_with_roles(None);
+
+    state.fund_issue_pool(ISSUE_COST.into(), None);
+    state.fund_issue_pool(BigUint::zero(), Some(ISSUE_POOL_FUNDING_AMOUNT_ZERO));
 
     state
         .common_setup
@@ -3204,53 +6574,79 @@ fn test_update_config() {
         .query()
         .to(ESDT_SAFE_ADDRESS)
         .whitebox(mvx_esdt_safe::contract_obj, |sc| {
-            let config = sc.esdt_safe_config().get();
+            assert!(sc.get_issue_pool_available_balance() == ISSUE_COST);
+            assert!(sc.issue_pool_reserved().get() == 0u64);
             assert!(
-                config.max_tx_gas_limit == ONE_HUNDRED_THOUSAND as u64
-                    && config
-                        .address_blacklist
-                        .contains(&OWNER_ADDRESS.to_managed_address())
+                sc.issue_pool_contribution(&USER_ADDRESS.to_managed_address())
+                    .get()
+                    == ISSUE_COST
             );
         });
+}
 
-    let payment = EgldOrEsdtTokenPayment::egld_payment(BigUint::zero());
-    let payment_vec = PaymentsVec::from(vec![payment]);
-    state.deposit(
-        USER_ADDRESS.to_managed_address(),
-        OptionalValue::None,
-        payment_vec,
-        Some(CALLER_IS_BLACKLISTED),
+/// ### TEST
+/// M-ESDT_ISSUE_POOL_OK
+///
+/// ### ACTION
+/// Call `register_token()` with a funded issue pool
+///
+/// ### EXPECTED
+/// The issue cost is taken out of the pool once the issue succeeds and nothing stays reserved
+#[test]
+fn test_register_token_consumes_issue_cost() {
+    let mut state = MvxEsdtSafeTestState::new();
+
+    let register_token_args = RegisterTokenOperation {
+        token_id: EgldOrEsdtTokenIdentifier::esdt(SOV_TOKEN),
+        token_type: EsdtTokenType::Fungible,
+        token_display_name: "TokenOne".into(),
+        token_ticker: FIRST_TEST_TOKEN.as_str().into(),
+        num_decimals: 3,
+        data: OperationData::new(0u64, USER_ADDRESS.to_managed_address(), None),
+    };
+
+    let token_hash = register_token_args.generate_hash();
+    let hash_of_hashes = ManagedBuffer::from(&sha256(&token_hash.to_vec()));
+
+    let signature = state.deploy_and_complete_setup_phase(&hash_of_hashes);
+
+    state.fund_issue_pool((ISSUE_COST * 2).into(), None);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![token_hash]),
     );
 
+    let expected_logs = vec![log!(REGISTER_TOKEN_EVENT, topics: [EXECUTED_BRIDGE_OP_EVENT])];
+    state.register_token(register_token_args, hash_of_hashes, None, expected_logs);
+
     state
         .common_setup
         .world
         .query()
-        .to(HEADER_VERIFIER_ADDRESS)
-        .whitebox(header_verifier::contract_obj, |sc| {
-            let config_hash_whitebox =
-                ManagedBuffer::from(update_config_operation.generate_hash().to_vec());
-            let hash_of_hashes_whitebox =
-                ManagedBuffer::new_from_bytes(&sha256(&config_hash_whitebox.to_vec()));
-            assert!(sc
-                .operation_hash_status(&hash_of_hashes_whitebox, &config_hash_whitebox)
-                .is_empty())
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            assert!(sc.issue_pool_reserved().get() == 0u64);
+            assert!(sc.issue_pool_balance().get() == ISSUE_COST);
         });
 }
 
 /// ### TEST
-/// M-ESDT_EXEC_OK
+/// M-ESDT_ISSUE_POOL_OK
 ///
 /// ### ACTION
-/// Call `execute_operation()` when the contract is paused
+/// Call 'upgrade()' on a safe holding bridged EGLD, then fund the issue pool
 ///
 /// ### EXPECTED
-/// The operation is executed and the tokens are burned from SC
+/// The bridged EGLD is not added to the pool, only the funded amount is
 #[test]
-fn test_execute_paused_refund() {
+fn test_upgrade_keeps_issue_pool_empty() {
     let mut state = MvxEsdtSafeTestState::new();
     state.deploy_contract_with_roles(None);
-    state.complete_setup_phase();
 
     state
         .common_setup
@@ -3258,100 +6654,60 @@ fn test_execute_paused_refund() {
         .tx()
         .from(OWNER_ADDRESS)
         .to(ESDT_SAFE_ADDRESS)
+        .egld(ISSUE_COST)
         .whitebox(mvx_esdt_safe::contract_obj, |sc| {
-            sc.paused_status().set(true);
+            sc.upgrade(MultiValueEncoded::new());
+            assert!(sc.issue_pool_balance().get() == 0u64);
+            assert!(!sc.can_cover_issue_cost());
         });
 
-    let token_data = EsdtTokenData {
-        amount: BigUint::from(100u64),
-        ..Default::default()
-    };
-
-    let payment = OperationEsdtPayment::new(
-        EgldOrEsdtTokenIdentifier::from(FIRST_TEST_TOKEN.as_bytes()),
-        0,
-        token_data,
-    );
-    let operation_data = OperationData::new(
-        state.common_setup.next_operation_nonce(),
-        OWNER_ADDRESS.to_managed_address(),
-        None,
-    );
-    let operation = Operation {
-        to: USER_ADDRESS.to_managed_address(),
-        tokens: vec![payment].into(),
-        data: operation_data,
-    };
-
-    let operation_hash = operation.generate_hash();
-    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
-    let (signature, bls_key) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
-    let egld_payment = EgldOrEsdtTokenPayment::egld_payment(BigUint::default());
-    let payments_vec = PaymentsVec::from(vec![egld_payment]);
-
-    state.common_setup.deploy_chain_config(
-        OptionalValue::Some(SovereignConfig::default_config_for_test()),
-        None,
-    );
-    state
-        .common_setup
-        .register(&bls_key[0], &payments_vec, None);
-    state.common_setup.complete_chain_config_setup_phase();
+    state.fund_issue_pool(ISSUE_COST.into(), None);
 
     state
         .common_setup
-        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
-    state
-        .common_setup
-        .complete_header_verifier_setup_phase(None);
-
-    let expected_logs = vec![
-        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(ESDT_SAFE_STILL_PAUSED)),
-        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [DEPOSIT_EVENT, FIRST_TEST_TOKEN.as_str()]),
-    ];
-
-    state.register_and_execute_operation(
-        &operation,
-        &hash_of_hashes,
-        signature,
-        1,
-        expected_logs,
-        false,
-    );
-
-    state.common_setup.check_account_single_esdt(
-        ESDT_SAFE_ADDRESS.to_address(),
-        FIRST_TEST_TOKEN,
-        0,
-        BigUint::zero(),
-    );
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
+            assert!(sc.issue_pool_balance().get() == ISSUE_COST);
+        });
 }
 
 /// ### TEST
-/// M-ESDT_PAUSE_STATUS_OK
+/// M-ESDT_ISSUE_POOL_OK
 ///
 /// ### ACTION
-/// Call `switch_pause_status()` when the contract is unpaused
+/// Call `set_issue_cost()` with a new cost
 ///
 /// ### EXPECTED
-/// Contract is paused and deposit endpoint fails
+/// The new issue cost is returned by `getIssueCost` and refunded for failed registrations
 #[test]
-fn test_pause_contract() {
+fn test_set_issue_cost() {
     let mut state = MvxEsdtSafeTestState::new();
     state.deploy_contract_with_roles(None);
+    state.complete_setup_phase();
 
     state.common_setup.deploy_chain_config(
         OptionalValue::Some(SovereignConfig::default_config_for_test()),
         None,
     );
 
-    let nonce = state.common_setup.next_operation_nonce();
-    let pause_operation = PauseStatusOperation {
-        status: true,
-        nonce,
+    let new_issue_cost = ISSUE_COST * 2;
+    let valid_operation = SetIssueCostOperation {
+        issue_cost: new_issue_cost.into(),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let zero_cost_operation = SetIssueCostOperation {
+        issue_cost: BigUint::zero(),
+        nonce: state.common_setup.next_operation_nonce(),
     };
-    let operation_hash = pause_operation.generate_hash();
-    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let valid_operation_hash = valid_operation.generate_hash();
+    let zero_cost_operation_hash = zero_cost_operation.generate_hash();
+    let mut aggregated_hashes = ManagedBuffer::new();
+    aggregated_hashes.append(&valid_operation_hash);
+    aggregated_hashes.append(&zero_cost_operation_hash);
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&aggregated_hashes.to_vec()));
 
     let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
 
@@ -3369,26 +6725,20 @@ fn test_pause_contract() {
         .common_setup
         .complete_header_verifier_setup_phase(None);
 
-    state.complete_setup_phase();
-
-    let bitmap = state.common_setup.full_bitmap(1);
-    let epoch = 0;
     state.common_setup.register_operation(
         OWNER_ADDRESS,
         signature,
         &hash_of_hashes,
-        bitmap,
-        epoch,
-        MultiValueEncoded::from_iter(vec![operation_hash]),
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![valid_operation_hash, zero_cost_operation_hash]),
     );
 
-    state.switch_pause_status(
+    state.set_issue_cost(&hash_of_hashes, valid_operation, None);
+    state.set_issue_cost(
         &hash_of_hashes,
-        pause_operation,
-        vec![log!(
-            PAUSE_CONTRACT_LOG,
-            topics: [EXECUTED_BRIDGE_OP_EVENT]
-        )],
+        zero_cost_operation,
+        Some(INVALID_ISSUE_COST),
     );
 
     state
@@ -3397,33 +6747,87 @@ fn test_pause_contract() {
         .query()
         .to(ESDT_SAFE_ADDRESS)
         .whitebox(mvx_esdt_safe::contract_obj, |sc| {
-            assert!(sc.is_paused());
+            assert!(sc.get_issue_cost() == new_issue_cost);
+
+            let (_, _, refund_token_data) = sc
+                .create_issue_cost_event_payment_tuple(sc.get_issue_cost())
+                .into_iter()
+                .next()
+                .unwrap()
+                .into_tuple();
+            assert!(refund_token_data.amount == new_issue_cost);
         });
+}
 
-    let egld_payment = EgldOrEsdtTokenPayment::egld_payment(BigUint::from(100u64));
-    let payments_vec = PaymentsVec::from(vec![egld_payment]);
+/// ### TEST
+/// M-ESDT_DEP_FAIL
+///
+/// ### ACTION
+/// Call 'deposit()' without a fee payment while a fee is active for another token
+///
+/// ### EXPECTED
+/// Error TOKEN_NOT_ACCEPTED_AS_FEE, the deposited token is taken as the fee and rejected
+#[test]
+fn test_deposit_without_fee_payment_rejected() {
+    let mut state = MvxEsdtSafeTestState::new();
+
+    let fee = FeeStruct {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+        fee_type: FeeType::Fixed {
+            token: EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+            per_transfer: PER_TRANSFER.into(),
+            per_gas: PER_GAS.into(),
+        },
+    };
+
+    state.deploy_contract_with_roles(Some(fee));
+    state.complete_setup_phase();
+
+    let payment = EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    );
 
     state.deposit(
         USER_ADDRESS.to_managed_address(),
         OptionalValue::None,
-        payments_vec,
-        Some(ESDT_SAFE_STILL_PAUSED),
+        PaymentsVec::from_single_item(payment),
+        Some(TOKEN_NOT_ACCEPTED_AS_FEE),
     );
+
+    let expected_balances = vec![
+        MultiValue3::from((FIRST_TEST_TOKEN, 0u64, BigUint::from(ONE_HUNDRED_MILLION))),
+        MultiValue3::from((FEE_TOKEN, 0u64, BigUint::from(ONE_HUNDRED_MILLION))),
+    ];
+
+    state
+        .common_setup
+        .check_account_multiple_esdts(OWNER_ADDRESS.to_address(), expected_balances);
 }
 
 /// ### TEST
-/// M-ESDT_EXEC_OK
+/// M-ESDT_DEP_OK
 ///
 /// ### ACTION
-/// Execute a bridge operation with mixed token payments, including one without prefunded liquidity.
+/// Call 'deposit()' without a fee payment towards a fee exempt destination
 ///
 /// ### EXPECTED
-/// Contract emits the partial-execution logs and refunds earlier mints, leaving balances unchanged.
+/// The deposit succeeds and no fee is charged
 #[test]
-fn test_execute_operation_partial_execution() {
+fn test_deposit_to_fee_exempt_destination() {
     let mut state = MvxEsdtSafeTestState::new();
-    state.deploy_contract_with_roles(None);
-    state.set_token_burn_mechanism_before_setup_phase(TRUSTED_TOKEN, None);
+
+    let fee = FeeStruct {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+        fee_type: FeeType::Fixed {
+            token: EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+            per_transfer: PER_TRANSFER.into(),
+            per_gas: PER_GAS.into(),
+        },
+    };
+
+    state.deploy_contract_with_roles(Some(fee));
     state.complete_setup_phase();
 
     state
@@ -3431,115 +6835,94 @@ fn test_execute_operation_partial_execution() {
         .world
         .tx()
         .from(OWNER_ADDRESS)
-        .to(ESDT_SAFE_ADDRESS)
-        .whitebox(mvx_esdt_safe::contract_obj, |sc| {
-            let token_pairs = [
-                (FIRST_TOKEN_ID, SOV_FIRST_TOKEN_ID),
-                (SECOND_TOKEN_ID, SOV_SECOND_TOKEN_ID),
-            ];
-
-            for (mvx_token, sov_token) in token_pairs {
-                sc.multiversx_to_sovereign_token_id_mapper(&EgldOrEsdtTokenIdentifier::esdt(
-                    mvx_token,
-                ))
-                .set(EgldOrEsdtTokenIdentifier::esdt(
-                    sov_token.to_token_identifier(),
-                ));
-
-                sc.sovereign_to_multiversx_token_id_mapper(&EgldOrEsdtTokenIdentifier::esdt(
-                    sov_token.to_token_identifier(),
-                ))
-                .set(EgldOrEsdtTokenIdentifier::esdt(
-                    mvx_token.to_token_identifier(),
-                ));
-            }
-        });
-
-    state.common_setup.deploy_chain_config(
-        OptionalValue::Some(SovereignConfig::default_config_for_test()),
-        None,
+        .to(FEE_MARKET_ADDRESS)
+        .typed(MvxFeeMarketProxy)
+        .add_fee_exemptions_during_setup_phase(
+            ManagedVec::from_single_item(USER_ADDRESS.to_managed_address()),
+            ManagedVec::<StaticApi, EgldOrEsdtTokenIdentifier<StaticApi>>::new(),
+        )
+        .run();
+
+    let payment = EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
     );
 
-    let token_data = EsdtTokenData {
-        amount: BigUint::from(ONE_HUNDRED_THOUSAND),
-        ..Default::default()
-    };
-
-    let token_ids = [
-        EgldOrEsdtTokenIdentifier::esdt(SOV_FIRST_TOKEN_ID),
-        EgldOrEsdtTokenIdentifier::esdt(TRUSTED_TOKEN),
-        EgldOrEsdtTokenIdentifier::esdt(SOV_SECOND_TOKEN_ID),
-    ];
-
-    let payments: Vec<_> = token_ids
-        .iter()
-        .map(|token_id| OperationEsdtPayment::new(token_id.clone(), 0, token_data.clone()))
-        .collect();
-
-    let operation = Operation::new(
+    state.deposit(
         USER_ADDRESS.to_managed_address(),
-        payments.into(),
-        OperationData::new(
-            state.common_setup.next_operation_nonce(),
-            USER_ADDRESS.to_managed_address(),
-            None,
-        ),
-    );
-    let operation_hash = state.common_setup.get_operation_hash(&operation);
-    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
-
-    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
-
-    state.common_setup.register(
-        public_keys.first().unwrap(),
-        &MultiEgldOrEsdtPayment::new(),
+        OptionalValue::None,
+        PaymentsVec::from_single_item(payment),
         None,
     );
 
-    state.common_setup.complete_chain_config_setup_phase();
-
-    state
-        .common_setup
-        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::ESDTSafe]);
-
-    state
-        .common_setup
-        .complete_header_verifier_setup_phase(None);
-
-    let expected_logs = vec![
-        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: Some(DEPOSIT_AMOUNT_NOT_ENOUGH)),
-        log!(EXECUTE_BRIDGE_OPS_ENDPOINT, topics: [DEPOSIT_EVENT, SOV_FIRST_TOKEN_ID.as_str(), TRUSTED_TOKEN, SOV_SECOND_TOKEN_ID.as_str()]),
-    ];
+    state.common_setup.check_account_single_esdt(
+        OWNER_ADDRESS.to_address(),
+        FEE_TOKEN,
+        0u64,
+        BigUint::from(ONE_HUNDRED_MILLION - ONE_HUNDRED_THOUSAND),
+    );
+}
 
-    let bitmap = state.common_setup.full_bitmap(1);
-    let operations_hashes = MultiValueEncoded::from(ManagedVec::from(vec![operation_hash.clone()]));
+/// ### TEST
+/// M-ESDT_GET_FEE_QUOTE_OK
+///
+/// ### ACTION
+/// Query `getFeeQuote` on the ESDT safe
+///
+/// ### EXPECTED
+/// The quote is forwarded from the fee market
+#[test]
+fn test_get_fee_quote() {
+    let mut state = MvxEsdtSafeTestState::new();
 
-    state.common_setup.register_operation(
-        USER_ADDRESS,
-        signature,
-        &hash_of_hashes,
-        bitmap,
-        0,
-        operations_hashes,
-    );
+    let fee = FeeStruct {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+        fee_type: FeeType::Fixed {
+            token: EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+            per_transfer: PER_TRANSFER.into(),
+            per_gas: PER_GAS.into(),
+        },
+    };
 
-    state.execute_operation(&hash_of_hashes, &operation, expected_logs);
+    state.deploy_contract_with_roles(Some(fee));
 
-    let addresses = [USER_ADDRESS.to_address(), ESDT_SAFE_ADDRESS.to_address()];
-    let tokens = [
-        FIRST_TOKEN_ID,
-        SECOND_TOKEN_ID,
-        TestTokenIdentifier::new(TRUSTED_TOKEN),
-    ];
+    let gas_limit = 1_000u64;
+    let transfers = PaymentsVec::from(vec![
+        EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            0,
+            BigUint::from(ONE_HUNDRED_THOUSAND),
+        ),
+        EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+            0,
+            BigUint::from(ONE_HUNDRED_THOUSAND),
+        ),
+    ]);
 
-    for address in &addresses {
-        for token_id in &tokens {
-            state.common_setup.check_account_single_esdt(
-                address.clone(),
-                *token_id,
-                0,
-                BigUint::zero(),
-            );
-        }
-    }
+    let fee_quote = state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(MvxEsdtSafeProxy)
+        .get_fee_quote(
+            EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+            transfers,
+            gas_limit,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .returns(ReturnsResult)
+        .run();
+
+    assert_eq!(
+        fee_quote.fee.token_identifier,
+        EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN)
+    );
+    assert_eq!(
+        fee_quote.fee.amount,
+        BigUint::from(2 * PER_TRANSFER + gas_limit * PER_GAS)
+    );
+    assert_eq!(fee_quote.accepted_tokens.len(), 1);
 }
@@ -6,10 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           26
+// Endpoints:                           63
 // Async Callback (empty):               1
 // Promise callbacks:                    3
-// Total number of exported functions:  32
+// Total number of exported functions:  69
 
 #![no_std]
 
@@ -23,10 +23,16 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         updateEsdtSafeConfigSetupPhase => update_esdt_safe_config_during_setup_phase
         updateEsdtSafeConfig => update_esdt_safe_config
+        patchEsdtSafeConfig => patch_esdt_safe_config
         pauseContract => switch_pause_status
         setFeeMarketAddress => set_fee_market_address
         completeSetupPhase => complete_setup_phase
         deposit => deposit
+        depositWithRefundAddress => deposit_with_refund_address
+        depositMulti => deposit_multi
+        depositMultiWithRefundAddress => deposit_multi_with_refund_address
+        getFeeQuote => get_fee_quote
+        getEsdtSafeConfig => get_esdt_safe_config
         executeBridgeOps => execute_operations
         registerToken => register_sovereign_token
         registerNativeToken => register_native_token
@@ -34,7 +40,38 @@ multiversx_sc_wasm_adapter::endpoints! {
         setTokenBurnMechanism => set_token_burn_mechanism
         setTokenLockMechanismSetupPhase => set_token_lock_mechanism_setup_phase
         setTokenLockMechanism => set_token_lock_mechanism
-        getDepositedTokensAmount => deposited_tokens_amount
+        getDepositedTokensAmount => get_deposited_tokens_amount
+        setTokenFreezeStatus => set_token_freeze_status
+        getFrozenTokens => frozen_tokens
+        setInboundPolicySetupPhase => set_inbound_policy_setup_phase
+        setInboundPolicy => set_inbound_policy
+        getInboundPolicy => get_inbound_policy
+        fundIssuePool => fund_issue_pool
+        setIssueCost => set_issue_cost
+        getIssueCost => get_issue_cost
+        getIssuePoolAvailableBalance => get_issue_pool_available_balance
+        getIssuePoolBalance => issue_pool_balance
+        getIssuePoolReservedBalance => issue_pool_reserved
+        getIssuePoolContribution => issue_pool_contribution
+        migrateSafe => migrate_safe
+        getMigrationStateCount => get_migration_state_count
+        exportMigrationState => export_migration_state
+        setPredecessorSafe => set_predecessor_safe
+        receiveMigratedFunds => receive_migrated_funds
+        importMigrationState => import_migration_state
+        isSafeMigrated => is_safe_migrated
+        getSuccessorSafeAddress => successor_safe_address
+        getPredecessorSafeAddress => predecessor_safe_address
+        getMigrationImportIndex => migration_import_index
+        updateNftMetadata => update_nft_metadata
+        getRegisteredTokensCount => get_registered_tokens_count
+        getRegisteredTokens => get_registered_tokens
+        getNftNonceMappingsCount => get_nft_nonce_mappings_count
+        getNftNonceMappings => get_nft_nonce_mappings
+        getTokenReserves => get_token_reserves
+        getTotalDeposited => total_deposited
+        getTotalExecuted => total_executed
+        getTotalRefunded => total_refunded
         getSovToMvxTokenId => sovereign_to_multiversx_token_id_mapper
         getMvxToSovTokenId => multiversx_to_sovereign_token_id_mapper
         getSovEsdtTokenInfo => sovereign_to_multiversx_esdt_info_mapper
@@ -0,0 +1,109 @@
+use error_messages::{SETUP_PHASE_ALREADY_COMPLETED, SETUP_PHASE_NOT_COMPLETED};
+use structs::{
+    fee::{AddFeeExemptionsOperation, RemoveFeeExemptionsOperation},
+    generate_hash::GenerateHash,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait FeeExemptionsModule:
+    fee_common::storage::FeeCommonStorageModule
+    + fee_common::helpers::FeeCommonHelpersModule
+    + setup_phase::SetupPhaseModule
+    + common_utils::CommonUtilsModule
+    + custom_events::CustomEventsModule
+{
+    #[only_owner]
+    #[endpoint(addFeeExemptionsSetupPhase)]
+    fn add_fee_exemptions_during_setup_phase(
+        &self,
+        destinations: ManagedVec<ManagedAddress>,
+        tokens: ManagedVec<EgldOrEsdtTokenIdentifier>,
+    ) {
+        require!(
+            !self.is_setup_phase_complete(),
+            SETUP_PHASE_ALREADY_COMPLETED
+        );
+
+        self.add_fee_exemptions_to_storage(&destinations, &tokens);
+    }
+
+    #[endpoint(addFeeExemptions)]
+    fn add_fee_exemptions_operation(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        add_exemptions_operation: AddFeeExemptionsOperation<Self::Api>,
+    ) {
+        let operation_hash = add_exemptions_operation.generate_hash();
+        if let Some(lock_operation_error) = self.lock_operation_hash_wrapper(
+            &hash_of_hashes,
+            &operation_hash,
+            add_exemptions_operation.nonce,
+        ) {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+
+        self.add_fee_exemptions_to_storage(
+            &add_exemptions_operation.destinations,
+            &add_exemptions_operation.tokens,
+        );
+        self.complete_operation(&hash_of_hashes, &operation_hash, None);
+    }
+
+    #[only_owner]
+    #[endpoint(removeFeeExemptionsSetupPhase)]
+    fn remove_fee_exemptions_during_setup_phase(
+        &self,
+        destinations: ManagedVec<ManagedAddress>,
+        tokens: ManagedVec<EgldOrEsdtTokenIdentifier>,
+    ) {
+        require!(
+            !self.is_setup_phase_complete(),
+            SETUP_PHASE_ALREADY_COMPLETED
+        );
+
+        self.remove_fee_exemptions_from_storage(&destinations, &tokens);
+    }
+
+    #[endpoint(removeFeeExemptions)]
+    fn remove_fee_exemptions_operation(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        remove_exemptions_operation: RemoveFeeExemptionsOperation<Self::Api>,
+    ) {
+        let operation_hash = remove_exemptions_operation.generate_hash();
+        if let Some(lock_operation_error) = self.lock_operation_hash_wrapper(
+            &hash_of_hashes,
+            &operation_hash,
+            remove_exemptions_operation.nonce,
+        ) {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+
+        self.remove_fee_exemptions_from_storage(
+            &remove_exemptions_operation.destinations,
+            &remove_exemptions_operation.tokens,
+        );
+        self.complete_operation(&hash_of_hashes, &operation_hash, None);
+    }
+}
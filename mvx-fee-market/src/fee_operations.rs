@@ -1,6 +1,10 @@
 use error_messages::{SETUP_PHASE_ALREADY_COMPLETED, SETUP_PHASE_NOT_COMPLETED};
 use structs::{
-    fee::{DistributeFeesOperation, FeeStruct, RemoveFeeOperation, SetFeeOperation},
+    fee::{
+        AllocateFeesOperation, DistributeFeesOperation, FeeStruct, RemoveFeeOperation,
+        RemoveFeePaymentTokenOperation, SetFeeOperation, SetFeePaymentTokenOperation,
+        SetFeeSplitOperation,
+    },
     generate_hash::GenerateHash,
 };
 
@@ -46,6 +50,63 @@ pub trait FeeOperationsModule:
         self.complete_operation(&hash_of_hashes, &operation_hash, None);
     }
 
+    #[endpoint(allocateFees)]
+    fn allocate_fees(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        operation: AllocateFeesOperation<Self::Api>,
+    ) {
+        let operation_hash = operation.generate_hash();
+        if let Some(lock_operation_error) =
+            self.lock_operation_hash_wrapper(&hash_of_hashes, &operation_hash, operation.nonce)
+        {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+        if let Some(err_msg) = self.validate_percentage_sum(&operation.pairs) {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(err_msg));
+            return;
+        }
+
+        self.allocate_fees_and_reset(&operation.pairs);
+        self.complete_operation(&hash_of_hashes, &operation_hash, None);
+    }
+
+    #[endpoint(setFeeSplit)]
+    fn set_fee_split(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        operation: SetFeeSplitOperation<Self::Api>,
+    ) {
+        let operation_hash = operation.generate_hash();
+        if let Some(lock_operation_error) =
+            self.lock_operation_hash_wrapper(&hash_of_hashes, &operation_hash, operation.nonce)
+        {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+
+        let opt_error =
+            self.set_fee_split_in_storage(&operation.pairs, operation.distribution_interval);
+        self.complete_operation(&hash_of_hashes, &operation_hash, opt_error);
+    }
+
     #[only_owner]
     #[endpoint(removeFeeDuringSetupPhase)]
     fn remove_fee_during_setup_phase(&self, base_token: EgldOrEsdtTokenIdentifier<Self::Api>) {
@@ -125,4 +186,64 @@ pub trait FeeOperationsModule:
 
         self.complete_operation(&hash_of_hashes, &fee_hash, None);
     }
+
+    #[endpoint(setFeePaymentToken)]
+    fn set_fee_payment_token(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        operation: SetFeePaymentTokenOperation<Self::Api>,
+    ) {
+        let operation_hash = operation.generate_hash();
+        if let Some(lock_operation_error) =
+            self.lock_operation_hash_wrapper(&hash_of_hashes, &operation_hash, operation.nonce)
+        {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+
+        let opt_error = self
+            .set_fee_payment_token_in_storage(
+                &operation.base_token,
+                &operation.payment_token,
+                &operation.rate,
+            )
+            .map(ManagedBuffer::from);
+        self.complete_operation(&hash_of_hashes, &operation_hash, opt_error);
+    }
+
+    #[endpoint(removeFeePaymentToken)]
+    fn remove_fee_payment_token(
+        &self,
+        hash_of_hashes: ManagedBuffer,
+        operation: RemoveFeePaymentTokenOperation<Self::Api>,
+    ) {
+        let operation_hash = operation.generate_hash();
+        if let Some(lock_operation_error) =
+            self.lock_operation_hash_wrapper(&hash_of_hashes, &operation_hash, operation.nonce)
+        {
+            self.complete_operation(&hash_of_hashes, &operation_hash, Some(lock_operation_error));
+            return;
+        }
+        if !self.is_setup_phase_complete() {
+            self.complete_operation(
+                &hash_of_hashes,
+                &operation_hash,
+                Some(SETUP_PHASE_NOT_COMPLETED.into()),
+            );
+            return;
+        }
+
+        let opt_error = self
+            .remove_fee_payment_token_from_storage(&operation.base_token, &operation.payment_token)
+            .map(ManagedBuffer::from);
+        self.complete_operation(&hash_of_hashes, &operation_hash, opt_error);
+    }
 }
@@ -4,6 +4,7 @@ use structs::fee::FeeStruct;
 
 multiversx_sc::imports!();
 
+pub mod fee_exemptions;
 pub mod fee_operations;
 pub mod fee_whitelist;
 
@@ -17,6 +18,7 @@ pub trait MvxFeeMarket:
     + fee_common::endpoints::FeeCommonEndpointsModule
     + fee_common::helpers::FeeCommonHelpersModule
     + fee_whitelist::FeeWhitelistModule
+    + fee_exemptions::FeeExemptionsModule
 {
     #[init]
     fn init(&self, esdt_safe_address: ManagedAddress, fee: Option<FeeStruct<Self::Api>>) {
@@ -24,7 +26,9 @@ pub trait MvxFeeMarket:
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, fee_tokens: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
+        self.migrate_active_fee_tokens(fee_tokens);
+    }
 
     #[only_owner]
     #[endpoint(completeSetupPhase)]
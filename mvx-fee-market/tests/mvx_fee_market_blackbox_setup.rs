@@ -1,12 +1,16 @@
 use common_test_setup::{
     base_setup::{init::ExpectedLogs, log_validations::assert_expected_logs},
-    constants::{DISTRIBUTE_FEES_ENDPOINT, REMOVE_FEE_ENDPOINT},
+    constants::{
+        ALLOCATE_FEES_ENDPOINT, DISTRIBUTE_FEES_ENDPOINT, REMOVE_FEE_ENDPOINT,
+        SET_FEE_SPLIT_ENDPOINT,
+    },
 };
 use multiversx_sc::{
     imports::OptionalValue,
     types::{
-        Address, BigUint, EgldOrEsdtTokenIdentifier, EsdtTokenPayment, ManagedBuffer, ManagedVec,
-        MultiValueEncoded, ReturnsHandledOrError, TestAddress, TestTokenIdentifier,
+        Address, BigUint, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, EsdtTokenPayment,
+        ManagedBuffer, ManagedVec, MultiValueEncoded, ReturnsHandledOrError, TestAddress,
+        TestTokenIdentifier,
     },
 };
 use multiversx_sc_scenario::imports::*;
@@ -16,16 +20,24 @@ use common_test_setup::{
     constants::{
         CROWD_TOKEN_ID, ESDT_SAFE_ADDRESS, EXECUTED_BRIDGE_OP_EVENT, FEE_MARKET_ADDRESS,
         FIRST_TEST_TOKEN, HEADER_VERIFIER_ADDRESS, MVX_ESDT_SAFE_CODE_PATH, OWNER_ADDRESS,
-        OWNER_BALANCE, SECOND_TEST_TOKEN, SET_FEE_ENDPOINT, USER_ADDRESS,
+        OWNER_BALANCE, RECEIVER_ADDRESS, SECOND_TEST_TOKEN, SET_FEE_ENDPOINT,
+        SET_FEE_PAYMENT_TOKEN_ENDPOINT, USER_ADDRESS,
     },
     log,
 };
 use proxies::mvx_fee_market_proxy::MvxFeeMarketProxy;
+use structs::aliases::PaymentsVec;
 use structs::fee::{
-    AddUsersToWhitelistOperation, DistributeFeesOperation, FeeStruct, FeeType, RemoveFeeOperation,
-    RemoveUsersFromWhitelistOperation, SetFeeOperation,
+    AddFeeExemptionsOperation, AddUsersToWhitelistOperation, AllocateFeesOperation,
+    DistributeFeesOperation, FeeStruct, FeeTier, FeeType, RemoveFeeOperation,
+    RemoveUsersFromWhitelistOperation, SetFeeOperation, SetFeePaymentTokenOperation,
+    SetFeeSplitOperation,
 };
 
+pub fn get_destinations() -> ManagedVec<StaticApi, ManagedAddress<StaticApi>> {
+    ManagedVec::from_single_item(RECEIVER_ADDRESS.to_managed_address())
+}
+
 pub struct MvxFeeMarketTestState {
     pub common_setup: BaseSetup,
 }
@@ -63,7 +75,7 @@ impl MvxFeeMarketTestState {
                 (SECOND_TEST_TOKEN, 0, BigUint::from(OWNER_BALANCE)),
                 (CROWD_TOKEN_ID, 0, BigUint::from(OWNER_BALANCE)),
             ]),
-            egld_balance: None,
+            egld_balance: Some(BigUint::from(OWNER_BALANCE)),
         };
 
         let account_setups = vec![owner_account, user_account, esdt_safe_address];
@@ -84,11 +96,66 @@ impl MvxFeeMarketTestState {
         }
     }
 
+    pub fn get_percentage_fee(
+        &self,
+        basis_points: usize,
+        min: u64,
+        max: u64,
+    ) -> FeeStruct<StaticApi> {
+        FeeStruct {
+            base_token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            fee_type: FeeType::Percentage {
+                token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+                basis_points,
+                min: BigUint::from(min),
+                max: BigUint::from(max),
+            },
+        }
+    }
+
+    pub fn get_egld_fee(&self) -> FeeStruct<StaticApi> {
+        FeeStruct {
+            base_token: EgldOrEsdtTokenIdentifier::egld(),
+            fee_type: FeeType::Fixed {
+                token: EgldOrEsdtTokenIdentifier::egld(),
+                per_transfer: BigUint::from(100u64),
+                per_gas: BigUint::from(0u64),
+            },
+        }
+    }
+
+    pub fn get_tiered_fee(&self, tiers: Vec<(u64, u64)>) -> FeeStruct<StaticApi> {
+        let tiers = tiers
+            .into_iter()
+            .map(|(min_volume, per_transfer)| FeeTier {
+                min_volume,
+                per_transfer: BigUint::from(per_transfer),
+                per_gas: BigUint::zero(),
+            })
+            .collect::<Vec<_>>();
+
+        FeeStruct {
+            base_token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            fee_type: FeeType::Tiered {
+                token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+                tiers: ManagedVec::from(tiers),
+            },
+        }
+    }
+
+    pub fn get_transfers(&self, amount: u64) -> PaymentsVec<StaticApi> {
+        PaymentsVec::from_single_item(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            0,
+            BigUint::from(amount),
+        ))
+    }
+
     pub fn subtract_fee(
         &mut self,
         payment_wanted: WantedFeeType,
         original_caller: Address,
-        total_transfers: usize,
+        transfers: PaymentsVec<StaticApi>,
         opt_gas_limit: OptionalValue<u64>,
         expected_error_message: Option<&str>,
     ) {
@@ -118,7 +185,12 @@ impl MvxFeeMarketTestState {
             .from(ESDT_SAFE_ADDRESS)
             .to(FEE_MARKET_ADDRESS)
             .typed(MvxFeeMarketProxy)
-            .subtract_fee(original_caller, total_transfers, opt_gas_limit)
+            .subtract_fee(
+                original_caller,
+                get_destinations(),
+                transfers,
+                opt_gas_limit,
+            )
             .payment(payment)
             .returns(ReturnsHandledOrError::new())
             .run();
@@ -127,6 +199,65 @@ impl MvxFeeMarketTestState {
             .assert_expected_error_message(response, expected_error_message);
     }
 
+    pub fn subtract_egld_fee(
+        &mut self,
+        amount: u64,
+        original_caller: Address,
+        transfers: PaymentsVec<StaticApi>,
+        opt_gas_limit: OptionalValue<u64>,
+        expected_error_message: Option<&str>,
+    ) {
+        let response = self
+            .common_setup
+            .world
+            .tx()
+            .from(ESDT_SAFE_ADDRESS)
+            .to(FEE_MARKET_ADDRESS)
+            .typed(MvxFeeMarketProxy)
+            .subtract_fee(
+                original_caller,
+                get_destinations(),
+                transfers,
+                opt_gas_limit,
+            )
+            .egld(BigUint::from(amount))
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+    }
+
+    pub fn subtract_fee_and_check_logs(
+        &mut self,
+        original_caller: Address,
+        transfers: PaymentsVec<StaticApi>,
+        expected_logs: Vec<ExpectedLogs>,
+    ) {
+        let logs = self
+            .common_setup
+            .world
+            .tx()
+            .from(ESDT_SAFE_ADDRESS)
+            .to(FEE_MARKET_ADDRESS)
+            .typed(MvxFeeMarketProxy)
+            .subtract_fee(
+                original_caller,
+                get_destinations(),
+                transfers,
+                OptionalValue::<u64>::None,
+            )
+            .payment(EsdtTokenPayment::new(
+                FIRST_TEST_TOKEN.to_token_identifier(),
+                0u64,
+                BigUint::from(200u64),
+            ))
+            .returns(ReturnsLogs)
+            .run();
+
+        assert_expected_logs(logs, expected_logs);
+    }
+
     pub fn remove_fee_during_setup_phase(&mut self, base_token: TestTokenIdentifier) {
         self.common_setup
             .world
@@ -194,6 +325,34 @@ impl MvxFeeMarketTestState {
         assert_expected_logs(logs, expected_logs);
     }
 
+    pub fn set_fee_payment_token(
+        &mut self,
+        hash_of_hashes: &ManagedBuffer<StaticApi>,
+        operation: SetFeePaymentTokenOperation<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let (response, logs) = self
+            .common_setup
+            .world
+            .tx()
+            .from(HEADER_VERIFIER_ADDRESS)
+            .to(FEE_MARKET_ADDRESS)
+            .typed(MvxFeeMarketProxy)
+            .set_fee_payment_token(hash_of_hashes, operation)
+            .returns(ReturnsHandledOrError::new())
+            .returns(ReturnsLogs)
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, None);
+
+        let expected_logs = vec![
+            log!(SET_FEE_PAYMENT_TOKEN_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: expected_error_message),
+        ];
+
+        assert_expected_logs(logs, expected_logs);
+    }
+
     pub fn set_fee_during_setup_phase(
         &mut self,
         token_id: EgldOrEsdtTokenIdentifier<StaticApi>,
@@ -224,6 +383,14 @@ impl MvxFeeMarketTestState {
             }
         };
 
+        self.set_fee_struct_during_setup_phase(fee_struct, expected_error_message);
+    }
+
+    pub fn set_fee_struct_during_setup_phase(
+        &mut self,
+        fee_struct: FeeStruct<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
         let response = self
             .common_setup
             .world
@@ -267,6 +434,99 @@ impl MvxFeeMarketTestState {
         assert_expected_logs(logs, expected_logs);
     }
 
+    pub fn allocate_fees(
+        &mut self,
+        hash_of_hashes: &ManagedBuffer<StaticApi>,
+        operation: AllocateFeesOperation<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let (response, logs) = self
+            .common_setup
+            .world
+            .tx()
+            .from(HEADER_VERIFIER_ADDRESS)
+            .to(FEE_MARKET_ADDRESS)
+            .typed(MvxFeeMarketProxy)
+            .allocate_fees(hash_of_hashes, operation)
+            .returns(ReturnsHandledOrError::new())
+            .returns(ReturnsLogs)
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, None);
+
+        let expected_logs = vec![
+            log!(ALLOCATE_FEES_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: expected_error_message),
+        ];
+
+        assert_expected_logs(logs, expected_logs);
+    }
+
+    pub fn claim_fees(
+        &mut self,
+        recipient: TestAddress,
+        token_ids: Vec<EgldOrEsdtTokenIdentifier<StaticApi>>,
+        expected_error_message: Option<&str>,
+    ) {
+        let response = self
+            .common_setup
+            .world
+            .tx()
+            .from(recipient)
+            .to(FEE_MARKET_ADDRESS)
+            .typed(MvxFeeMarketProxy)
+            .claim_fees(MultiValueEncoded::from_iter(token_ids))
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+    }
+
+    pub fn set_fee_split(
+        &mut self,
+        hash_of_hashes: &ManagedBuffer<StaticApi>,
+        operation: SetFeeSplitOperation<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let (response, logs) = self
+            .common_setup
+            .world
+            .tx()
+            .from(HEADER_VERIFIER_ADDRESS)
+            .to(FEE_MARKET_ADDRESS)
+            .typed(MvxFeeMarketProxy)
+            .set_fee_split(hash_of_hashes, operation)
+            .returns(ReturnsHandledOrError::new())
+            .returns(ReturnsLogs)
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, None);
+
+        let expected_logs = vec![
+            log!(SET_FEE_SPLIT_ENDPOINT, topics: [EXECUTED_BRIDGE_OP_EVENT], data: expected_error_message),
+        ];
+
+        assert_expected_logs(logs, expected_logs);
+    }
+
+    pub fn trigger_distribution(&mut self, expected_error_message: Option<&str>) {
+        let response = self
+            .common_setup
+            .world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(FEE_MARKET_ADDRESS)
+            .typed(MvxFeeMarketProxy)
+            .trigger_distribution()
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+    }
+
     pub fn add_users_to_whitelist_during_setup_phase(&mut self, users_vector: Vec<TestAddress>) {
         let mut users_vec = ManagedVec::new();
 
@@ -286,6 +546,45 @@ impl MvxFeeMarketTestState {
             .run();
     }
 
+    pub fn add_fee_exemptions_during_setup_phase(
+        &mut self,
+        destinations: Vec<TestAddress>,
+        tokens: Vec<TestTokenIdentifier>,
+    ) {
+        let destinations: ManagedVec<StaticApi, ManagedAddress<StaticApi>> = destinations
+            .iter()
+            .map(|destination| destination.to_managed_address())
+            .collect();
+        let tokens: ManagedVec<StaticApi, EgldOrEsdtTokenIdentifier<StaticApi>> = tokens
+            .into_iter()
+            .map(EgldOrEsdtTokenIdentifier::esdt)
+            .collect();
+
+        self.common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(FEE_MARKET_ADDRESS)
+            .typed(MvxFeeMarketProxy)
+            .add_fee_exemptions_during_setup_phase(destinations, tokens)
+            .run();
+    }
+
+    pub fn add_fee_exemptions(
+        &mut self,
+        hash_of_hashes: &ManagedBuffer<StaticApi>,
+        operation: AddFeeExemptionsOperation<StaticApi>,
+    ) {
+        self.common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(FEE_MARKET_ADDRESS)
+            .typed(MvxFeeMarketProxy)
+            .add_fee_exemptions_operation(hash_of_hashes, operation)
+            .run();
+    }
+
     pub fn add_users_to_whitelist(
         &mut self,
         hash_of_hashes: &ManagedBuffer<StaticApi>,
@@ -1,15 +1,22 @@
 use common_test_setup::base_setup::helpers::BLSKey;
+use common_test_setup::base_setup::init::ExpectedLogs;
 use common_test_setup::constants::{
-    ESDT_SAFE_ADDRESS, FEE_MARKET_ADDRESS, FIRST_TEST_TOKEN, OWNER_ADDRESS, OWNER_BALANCE,
-    PER_TRANSFER, SECOND_TEST_TOKEN, USER_ADDRESS, WRONG_TOKEN_ID,
+    ESDT_SAFE_ADDRESS, FEE_COLLECTED_EVENT, FEE_MARKET_ADDRESS, FIRST_TEST_TOKEN,
+    ONE_HUNDRED_THOUSAND, OWNER_ADDRESS, OWNER_BALANCE, PER_TRANSFER, RECEIVER_ADDRESS,
+    SECOND_TEST_TOKEN, SUBTRACT_FEE_ENDPOINT, USER_ADDRESS, WRONG_TOKEN_ID,
 };
+use common_test_setup::log;
 use error_messages::{
-    CURRENT_OPERATION_NOT_REGISTERED, INVALID_FEE, INVALID_FEE_TYPE, INVALID_PERCENTAGE_SUM,
-    INVALID_TOKEN_ID, PAYMENT_DOES_NOT_COVER_FEE, SETUP_PHASE_NOT_COMPLETED,
+    CURRENT_OPERATION_NOT_REGISTERED, DISTRIBUTION_INTERVAL_NOT_ELAPSED, FEE_SPLIT_NOT_SET,
+    INVALID_FEE, INVALID_FEE_BASIS_POINTS, INVALID_FEE_TIERS, INVALID_FEE_TYPE,
+    INVALID_PERCENTAGE_SUM, INVALID_TOKEN_ID, MIN_FEE_ABOVE_MAX_FEE, NO_FEES_TO_CLAIM,
+    PAYMENT_DOES_NOT_COVER_FEE, PERCENTAGE_FEE_TOKEN_NOT_PRICED, SETUP_PHASE_NOT_COMPLETED,
     TOKEN_NOT_ACCEPTED_AS_FEE,
 };
+use fee_common::endpoints::FeeCommonEndpointsModule;
+use fee_common::helpers::FEE_RATE_PRECISION;
 use fee_common::storage::FeeCommonStorageModule;
-use multiversx_sc::types::EgldOrEsdtTokenIdentifier;
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment};
 use multiversx_sc::{
     imports::OptionalValue,
     types::{BigUint, ManagedBuffer, ManagedVec, MultiEgldOrEsdtPayment, MultiValueEncoded},
@@ -17,12 +24,17 @@ use multiversx_sc::{
 use multiversx_sc_scenario::{
     api::StaticApi, multiversx_chain_vm::crypto_functions::sha256, ScenarioTxWhitebox,
 };
+use mvx_fee_market::MvxFeeMarket;
 use structs::configs::SovereignConfig;
-use structs::fee::{RemoveFeeOperation, SetFeeOperation};
+use structs::fee::{
+    RemoveFeeOperation, SetFeeOperation, SetFeePaymentTokenOperation, SetFeeSplitOperation,
+};
 use structs::{
+    aliases::PaymentsVec,
     fee::{
-        AddUsersToWhitelistOperation, AddressPercentagePair, DistributeFeesOperation, FeeStruct,
-        FeeType, RemoveUsersFromWhitelistOperation,
+        AddFeeExemptionsOperation, AddUsersToWhitelistOperation, AddressPercentagePair,
+        AllocateFeesOperation, DistributeFeesOperation, FeeStruct, FeeType,
+        RemoveUsersFromWhitelistOperation,
     },
     forge::ScArray,
     generate_hash::GenerateHash,
@@ -758,7 +770,7 @@ fn test_distribute_fees() {
     state.subtract_fee(
         WantedFeeType::Correct,
         USER_ADDRESS.to_address(),
-        1u64 as usize,
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
         OptionalValue::Some(30u64),
         None,
     );
@@ -802,7 +814,7 @@ fn test_distribute_fees() {
 /// Call 'subtract_fee()' with no fee set
 ///
 /// ### EXPECTED
-/// User balance is unchanged
+/// The payment is refunded to the user
 #[test]
 fn test_subtract_fee_no_fee() {
     let mut state = MvxFeeMarketTestState::new();
@@ -818,7 +830,7 @@ fn test_subtract_fee_no_fee() {
     state.subtract_fee(
         WantedFeeType::Correct,
         USER_ADDRESS.to_address(),
-        1u64 as usize,
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
         OptionalValue::Some(30u64),
         None,
     );
@@ -827,14 +839,14 @@ fn test_subtract_fee_no_fee() {
         ESDT_SAFE_ADDRESS.to_address(),
         FIRST_TEST_TOKEN,
         0u64,
-        BigUint::from(OWNER_BALANCE),
+        BigUint::from(OWNER_BALANCE - 200),
     );
 
     state.common_setup.check_account_single_esdt(
         USER_ADDRESS.to_address(),
         FIRST_TEST_TOKEN,
         0u64,
-        BigUint::from(OWNER_BALANCE),
+        BigUint::from(OWNER_BALANCE + 200),
     );
 }
 
@@ -845,7 +857,7 @@ fn test_subtract_fee_no_fee() {
 /// Call 'subtract_fee()' with a whitelisted user
 ///
 /// ### EXPECTED
-/// User balance is unchanged
+/// The payment is refunded to the user
 #[test]
 fn test_subtract_fee_whitelisted() {
     let mut state = MvxFeeMarketTestState::new();
@@ -863,7 +875,7 @@ fn test_subtract_fee_whitelisted() {
     state.subtract_fee(
         WantedFeeType::Correct,
         USER_ADDRESS.to_address(),
-        1u64 as usize,
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
         OptionalValue::Some(30u64),
         None,
     );
@@ -872,14 +884,14 @@ fn test_subtract_fee_whitelisted() {
         ESDT_SAFE_ADDRESS.to_address(),
         FIRST_TEST_TOKEN,
         0u64,
-        BigUint::from(OWNER_BALANCE),
+        BigUint::from(OWNER_BALANCE - 200),
     );
 
     state.common_setup.check_account_single_esdt(
         USER_ADDRESS.to_address(),
         FIRST_TEST_TOKEN,
         0u64,
-        BigUint::from(OWNER_BALANCE),
+        BigUint::from(OWNER_BALANCE + 200),
     );
 }
 
@@ -904,7 +916,7 @@ fn test_subtract_fee_invalid_payment_token() {
     state.subtract_fee(
         WantedFeeType::InvalidToken,
         USER_ADDRESS.to_address(),
-        1u64 as usize,
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
         OptionalValue::Some(30u64),
         Some(TOKEN_NOT_ACCEPTED_AS_FEE),
     );
@@ -948,7 +960,7 @@ fn test_subtract_fixed_fee_payment_not_covered() {
     state.subtract_fee(
         WantedFeeType::LessThanFee,
         USER_ADDRESS.to_address(),
-        1u64 as usize,
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
         OptionalValue::Some(30u64),
         Some(PAYMENT_DOES_NOT_COVER_FEE),
     );
@@ -992,7 +1004,7 @@ fn test_subtract_fee_fixed_payment_bigger_than_fee() {
     state.subtract_fee(
         WantedFeeType::Correct,
         USER_ADDRESS.to_address(),
-        1u64 as usize,
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
         OptionalValue::Some(30u64),
         None,
     );
@@ -1011,3 +1023,1130 @@ fn test_subtract_fee_fixed_payment_bigger_than_fee() {
         BigUint::from(OWNER_BALANCE + 100),
     );
 }
+
+/// ### TEST
+/// F-MARKET_SET_FEE_DURING_SETUP_PHASE_FAIL
+///
+/// ### ACTION
+/// Call 'set_fee_during_setup_phase()' with invalid percentage fees
+///
+/// ### EXPECTED
+/// Errors: INVALID_FEE_BASIS_POINTS, MIN_FEE_ABOVE_MAX_FEE
+#[test]
+fn test_set_percentage_fee_during_setup_phase_wrong_params() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    let fee_over_total_percentage = state.get_percentage_fee(10_001, 0, 100);
+    state.set_fee_struct_during_setup_phase(
+        fee_over_total_percentage,
+        Some(INVALID_FEE_BASIS_POINTS),
+    );
+
+    let fee_with_min_above_max = state.get_percentage_fee(100, 100, 10);
+    state.set_fee_struct_during_setup_phase(fee_with_min_above_max, Some(MIN_FEE_ABOVE_MAX_FEE));
+}
+
+/// ### TEST
+/// F-MARKET_SUBTRACT_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' with a percentage fee
+///
+/// ### EXPECTED
+/// The fee is a percentage of the bridged amount and the user is refunded with the difference
+#[test]
+fn test_subtract_percentage_fee() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    // 1% of the bridged amount
+    let fee = state.get_percentage_fee(100, 10, 1_000);
+
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(10_000),
+        OptionalValue::None,
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE - 200),
+    );
+
+    state.common_setup.check_account_single_esdt(
+        USER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE + 100),
+    );
+}
+
+/// ### TEST
+/// F-MARKET_SUBTRACT_FEE_FAIL
+///
+/// ### ACTION
+/// Call 'subtract_fee()' with a percentage fee for a transfer in a token that is not accepted as fee
+///
+/// ### EXPECTED
+/// Error PERCENTAGE_FEE_TOKEN_NOT_PRICED
+#[test]
+fn test_subtract_percentage_fee_unpriced_token() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = state.get_percentage_fee(100, 10, 1_000);
+
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        PaymentsVec::from_single_item(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN),
+            0,
+            BigUint::from(10_000u64),
+        )),
+        OptionalValue::None,
+        Some(PERCENTAGE_FEE_TOKEN_NOT_PRICED),
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE),
+    );
+}
+
+/// ### TEST
+/// F-MARKET_SUBTRACT_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' with a fixed plus percentage fee where the percentage is above the max
+///
+/// ### EXPECTED
+/// The percentage part is capped at the max and added to the fixed part
+#[test]
+fn test_subtract_fixed_plus_percentage_fee_capped() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = FeeStruct {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        fee_type: FeeType::FixedPlusPercentage {
+            token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            per_transfer: BigUint::from(100u64),
+            per_gas: BigUint::zero(),
+            basis_points: 100,
+            min: BigUint::zero(),
+            max: BigUint::from(50u64),
+        },
+    };
+
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        USER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE + 50),
+    );
+}
+
+/// ### TEST
+/// F-MARKET_REMOVE_FEE_OK
+///
+/// ### ACTION
+/// Set fees for two tokens and remove the fee of one of them
+///
+/// ### EXPECTED
+/// The other token stays an active fee token
+#[test]
+fn test_remove_fee_keeps_other_fee_tokens_active() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    let second_fee = FeeStruct {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN),
+        fee_type: FeeType::Fixed {
+            token: EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN),
+            per_transfer: PER_TRANSFER.into(),
+            per_gas: BigUint::zero(),
+        },
+    };
+    state.set_fee_struct_during_setup_phase(second_fee, None);
+
+    state.remove_fee_during_setup_phase(FIRST_TEST_TOKEN);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(FEE_MARKET_ADDRESS)
+        .whitebox(mvx_fee_market::contract_obj, |sc| {
+            assert!(sc.is_fee_enabled());
+            assert!(!sc.is_fee_token_active(&EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)));
+            assert!(sc.is_fee_token_active(&EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN)));
+        });
+}
+
+/// ### TEST
+/// F-MARKET_UPGRADE_OK
+///
+/// ### ACTION
+/// Simulate a fee market with the legacy global fee flag, then call 'upgrade()' with the fee tokens
+///
+/// ### EXPECTED
+/// Only the tokens with a fee set become active fee tokens and the legacy flag is cleared
+#[test]
+fn test_upgrade_migrates_active_fee_tokens() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(FEE_MARKET_ADDRESS)
+        .whitebox(mvx_fee_market::contract_obj, |sc| {
+            let first_token = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            let second_token = EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN);
+            let no_fee_token = EgldOrEsdtTokenIdentifier::esdt(WRONG_TOKEN_ID);
+
+            sc.active_fee_tokens().swap_remove(&first_token);
+            sc.token_fee(&second_token).set(FeeType::Fixed {
+                token: second_token.clone(),
+                per_transfer: BigUint::from(PER_TRANSFER),
+                per_gas: BigUint::zero(),
+            });
+            sc.legacy_fee_enabled().set(true);
+
+            let mut fee_tokens = MultiValueEncoded::new();
+            fee_tokens.push(first_token.clone());
+            fee_tokens.push(second_token.clone());
+            fee_tokens.push(no_fee_token.clone());
+            sc.upgrade(fee_tokens);
+
+            assert!(sc.is_fee_token_active(&first_token));
+            assert!(sc.is_fee_token_active(&second_token));
+            assert!(!sc.is_fee_token_active(&no_fee_token));
+            assert!(sc.legacy_fee_enabled().is_empty());
+        });
+}
+
+/// ### TEST
+/// F-MARKET_SET_FEE_PAYMENT_TOKEN_OK
+///
+/// ### ACTION
+/// Call `set_fee_payment_token()` for a base token with a fee
+///
+/// ### EXPECTED
+/// The payment token is accepted for the base token fee with the given rate
+#[test]
+fn test_set_fee_payment_token() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let operation = SetFeePaymentTokenOperation {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        payment_token: EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN),
+        rate: BigUint::from(FEE_RATE_PRECISION) / 20u64,
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::FeeMarket, ScArray::ChainConfig]);
+
+    state.common_setup.complete_fee_market_setup_phase();
+
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.set_fee_payment_token(&hash_of_hashes, operation, None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(FEE_MARKET_ADDRESS)
+        .whitebox(mvx_fee_market::contract_obj, |sc| {
+            let payment_token = EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN);
+            assert!(!sc.is_fee_token_active(&payment_token));
+            assert!(
+                sc.fee_payment_token_base(&payment_token).get()
+                    == EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)
+            );
+            assert!(
+                sc.fee_payment_token_rates(&EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN))
+                    .get(&payment_token)
+                    == Some(BigUint::from(FEE_RATE_PRECISION) / 20u64)
+            );
+        });
+}
+
+/// ### TEST
+/// F-MARKET_SUBTRACT_EGLD_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' with EGLD as the fee token, then 'distribute_fees()'
+///
+/// ### EXPECTED
+/// EGLD fee is kept, the rest is sent to the original caller and the fee is distributed
+#[test]
+fn test_subtract_and_distribute_egld_fee() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let address_pair: AddressPercentagePair<StaticApi> = AddressPercentagePair {
+        address: OWNER_ADDRESS.to_managed_address(),
+        percentage: 10_000,
+    };
+
+    let operation = DistributeFeesOperation {
+        pairs: ManagedVec::from_iter(vec![address_pair]),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    let fee = state.get_egld_fee();
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.subtract_egld_fee(
+        2 * PER_TRANSFER,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::Some(30u64),
+        None,
+    );
+
+    state
+        .common_setup
+        .world
+        .check_account(FEE_MARKET_ADDRESS)
+        .balance(PER_TRANSFER);
+    state
+        .common_setup
+        .world
+        .check_account(USER_ADDRESS)
+        .balance(PER_TRANSFER);
+
+    state.common_setup.complete_fee_market_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::FeeMarket]);
+
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.distribute_fees(&hash_of_hashes, operation, None);
+
+    state
+        .common_setup
+        .world
+        .check_account(FEE_MARKET_ADDRESS)
+        .balance(0u64);
+    state
+        .common_setup
+        .world
+        .check_account(OWNER_ADDRESS)
+        .balance(PER_TRANSFER);
+}
+
+/// ### TEST
+/// F-MARKET_SUBTRACT_EGLD_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' with EGLD for a whitelisted user
+///
+/// ### EXPECTED
+/// EGLD payment is refunded to the user
+#[test]
+fn test_subtract_egld_fee_whitelisted() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = state.get_egld_fee();
+
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.add_users_to_whitelist_during_setup_phase(vec![USER_ADDRESS]);
+
+    state.subtract_egld_fee(
+        2 * PER_TRANSFER,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::Some(30u64),
+        None,
+    );
+
+    state
+        .common_setup
+        .world
+        .check_account(ESDT_SAFE_ADDRESS)
+        .balance(OWNER_BALANCE - 2 * PER_TRANSFER as u128);
+    state
+        .common_setup
+        .world
+        .check_account(USER_ADDRESS)
+        .balance(2 * PER_TRANSFER);
+    state
+        .common_setup
+        .world
+        .check_account(FEE_MARKET_ADDRESS)
+        .balance(0u64);
+}
+
+/// ### TEST
+/// F-MARKET_SUBTRACT_TIERED_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' with a tiered fee until the caller reaches the next tier
+///
+/// ### EXPECTED
+/// The caller pays the cheaper tier once the volume threshold is reached
+#[test]
+fn test_subtract_tiered_fee() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = state.get_tiered_fee(vec![(0, 100), (1, 40)]);
+
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        USER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE + 100 + 160),
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(FEE_MARKET_ADDRESS)
+        .whitebox(mvx_fee_market::contract_obj, |sc| {
+            let user = USER_ADDRESS.to_managed_address();
+            let token_id = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            assert_eq!(sc.get_user_volume(user.clone()), 2);
+            assert_eq!(sc.get_user_fee_tier(user, token_id), Some(1));
+        });
+}
+
+/// ### TEST
+/// F-MARKET_SET_FEE_DURING_SETUP_PHASE_FAIL
+///
+/// ### ACTION
+/// Call 'set_fee_during_setup_phase()' with invalid fee tiers
+///
+/// ### EXPECTED
+/// Error INVALID_FEE_TIERS
+#[test]
+fn test_set_tiered_fee_during_setup_phase_invalid_tiers() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    let fee_without_tiers = state.get_tiered_fee(vec![]);
+    state.set_fee_struct_during_setup_phase(fee_without_tiers, Some(INVALID_FEE_TIERS));
+
+    let fee_not_increasing = state.get_tiered_fee(vec![(0, 100), (10, 50), (3, 10)]);
+    state.set_fee_struct_during_setup_phase(fee_not_increasing, Some(INVALID_FEE_TIERS));
+}
+
+/// ### TEST
+/// F-MARKET_ALLOCATE_FEES_OK
+///
+/// ### ACTION
+/// Call 'allocate_fees()' with one pair, then 'claim_fees()' from the recipient
+///
+/// ### EXPECTED
+/// The fee is claimable by the recipient and sent only when claimed
+#[test]
+fn test_allocate_and_claim_fees() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let address_pair: AddressPercentagePair<StaticApi> = AddressPercentagePair {
+        address: OWNER_ADDRESS.to_managed_address(),
+        percentage: 10_000,
+    };
+
+    let operation = AllocateFeesOperation {
+        pairs: ManagedVec::from_iter(vec![address_pair]),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    state.common_setup.complete_fee_market_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::FeeMarket]);
+
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.allocate_fees(&hash_of_hashes, operation, None);
+
+    state.common_setup.check_account_single_esdt(
+        OWNER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0,
+        BigUint::from(OWNER_BALANCE),
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(FEE_MARKET_ADDRESS)
+        .whitebox(mvx_fee_market::contract_obj, |sc| {
+            let claimable_amount = sc
+                .claimable_fees(&OWNER_ADDRESS.to_managed_address())
+                .get(&EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN));
+            assert!(claimable_amount == Some(BigUint::from(PER_TRANSFER)));
+        });
+
+    state.claim_fees(USER_ADDRESS, vec![], Some(NO_FEES_TO_CLAIM));
+    state.claim_fees(OWNER_ADDRESS, vec![], None);
+
+    state.common_setup.check_account_single_esdt(
+        OWNER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0,
+        BigUint::from(OWNER_BALANCE) + PER_TRANSFER,
+    );
+}
+
+/// ### TEST
+/// F-MARKET_SET_FEE_SPLIT_OK
+///
+/// ### ACTION
+/// Call 'set_fee_split()' through a signed operation, then 'trigger_distribution()'
+///
+/// ### EXPECTED
+/// Accumulated fees are distributed with the standing split once the interval elapsed
+#[test]
+fn test_set_fee_split_and_trigger_distribution() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let address_pair: AddressPercentagePair<StaticApi> = AddressPercentagePair {
+        address: OWNER_ADDRESS.to_managed_address(),
+        percentage: 10_000,
+    };
+
+    let distribution_interval = 5u64;
+    let operation = SetFeeSplitOperation {
+        pairs: ManagedVec::from_iter(vec![address_pair]),
+        distribution_interval,
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    state.common_setup.complete_fee_market_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::FeeMarket]);
+
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.trigger_distribution(Some(FEE_SPLIT_NOT_SET));
+
+    state.set_fee_split(&hash_of_hashes, operation, None);
+
+    state.trigger_distribution(Some(DISTRIBUTION_INTERVAL_NOT_ELAPSED));
+
+    state
+        .common_setup
+        .world
+        .current_block()
+        .block_nonce(distribution_interval);
+
+    state.trigger_distribution(None);
+
+    state.common_setup.check_account_single_esdt(
+        OWNER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0,
+        BigUint::from(OWNER_BALANCE) + PER_TRANSFER,
+    );
+}
+
+/// ### TEST
+/// F-MARKET_GET_FEE_QUOTE_OK
+///
+/// ### ACTION
+/// Query 'get_fee_quote()' with a percentage fee
+///
+/// ### EXPECTED
+/// The quote matches the percentage fee
+#[test]
+fn test_get_fee_quote() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = state.get_percentage_fee(100, 10, 1_000);
+
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(FEE_MARKET_ADDRESS)
+        .whitebox(mvx_fee_market::contract_obj, |sc| {
+            let fee_token = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            let transfers = ManagedVec::from_single_item(EgldOrEsdtTokenPayment::new(
+                fee_token.clone(),
+                0,
+                BigUint::from(50_000u64),
+            ));
+            let quote = sc.get_fee_quote(fee_token, transfers, 0, OptionalValue::None);
+            assert_eq!(quote.fee.amount, BigUint::from(500u64));
+        });
+}
+
+/// ### TEST
+/// F-MARKET_FEE_REVENUE_ACCOUNTING_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' in two epochs, then 'distribute_fees()'
+///
+/// ### EXPECTED
+/// `feeCollected` is emitted and the collected and distributed counters are updated
+#[test]
+fn test_fee_revenue_accounting() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let address_pair: AddressPercentagePair<StaticApi> = AddressPercentagePair {
+        address: OWNER_ADDRESS.to_managed_address(),
+        percentage: 10_000,
+    };
+
+    let operation = DistributeFeesOperation {
+        pairs: ManagedVec::from_iter(vec![address_pair]),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.subtract_fee_and_check_logs(
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        vec![log!(SUBTRACT_FEE_ENDPOINT, topics: [FEE_COLLECTED_EVENT])],
+    );
+
+    state.common_setup.world.current_block().block_epoch(1);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    state.common_setup.complete_fee_market_setup_phase();
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::ChainConfig, ScArray::FeeMarket]);
+
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.distribute_fees(&hash_of_hashes, operation, None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(FEE_MARKET_ADDRESS)
+        .whitebox(mvx_fee_market::contract_obj, |sc| {
+            let token_id = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            let owner = OWNER_ADDRESS.to_managed_address();
+            assert_eq!(
+                sc.total_fees_collected(&token_id).get(),
+                BigUint::from(2 * PER_TRANSFER)
+            );
+            assert_eq!(
+                sc.epoch_fees_collected(0, &token_id).get(),
+                BigUint::from(PER_TRANSFER)
+            );
+            assert_eq!(
+                sc.epoch_fees_collected(1, &token_id).get(),
+                BigUint::from(PER_TRANSFER)
+            );
+            assert_eq!(
+                sc.total_fees_distributed(&token_id).get(),
+                BigUint::from(2 * PER_TRANSFER)
+            );
+            assert_eq!(
+                sc.epoch_fees_distributed(1, &token_id).get(),
+                BigUint::from(2 * PER_TRANSFER)
+            );
+            assert_eq!(
+                sc.recipient_fees_distributed(&owner, &token_id).get(),
+                BigUint::from(2 * PER_TRANSFER)
+            );
+        });
+}
+
+/// ### TEST
+/// F-MARKET_SUBTRACT_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' towards a fee exempt destination
+///
+/// ### EXPECTED
+/// The payment is refunded to the user and no fee is accumulated
+#[test]
+fn test_subtract_fee_exempt_destination() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.add_fee_exemptions_during_setup_phase(vec![RECEIVER_ADDRESS], vec![]);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::Some(30u64),
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE - 200),
+    );
+
+    state.common_setup.check_account_single_esdt(
+        USER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE + 200),
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(FEE_MARKET_ADDRESS)
+        .whitebox(mvx_fee_market::contract_obj, |sc| {
+            let token_id = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            assert_eq!(sc.accumulated_fees(&token_id).get(), BigUint::zero());
+        });
+}
+
+/// ### TEST
+/// F-MARKET_SUBTRACT_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' with transfers of a fee exempt token
+///
+/// ### EXPECTED
+/// The payment is refunded to the user and no fee is accumulated
+#[test]
+fn test_subtract_fee_exempt_token() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.add_fee_exemptions_during_setup_phase(vec![], vec![FIRST_TEST_TOKEN]);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::Some(30u64),
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        ESDT_SAFE_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE - 200),
+    );
+
+    state.common_setup.check_account_single_esdt(
+        USER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE + 200),
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(FEE_MARKET_ADDRESS)
+        .whitebox(mvx_fee_market::contract_obj, |sc| {
+            let token_id = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            assert_eq!(sc.accumulated_fees(&token_id).get(), BigUint::zero());
+        });
+}
+
+/// ### TEST
+/// F-MARKET_SUBTRACT_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' with transfers of a fee exempt token and a fee paid in a chargeable token
+///
+/// ### EXPECTED
+/// The gas fee is charged, as the ESDT safe also checks the fee payment token, and the rest goes
+/// to the user
+#[test]
+fn test_subtract_fee_exempt_transfers_chargeable_fee_token() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    let fee = FeeStruct {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        fee_type: FeeType::Fixed {
+            token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            per_transfer: BigUint::from(PER_TRANSFER),
+            per_gas: BigUint::from(1u64),
+        },
+    };
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.add_fee_exemptions_during_setup_phase(vec![], vec![SECOND_TEST_TOKEN]);
+
+    let transfers = ManagedVec::from_single_item(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    ));
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        transfers,
+        OptionalValue::Some(30u64),
+        None,
+    );
+
+    state.common_setup.check_account_single_esdt(
+        USER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE + 170),
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(FEE_MARKET_ADDRESS)
+        .whitebox(mvx_fee_market::contract_obj, |sc| {
+            let token_id = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            assert_eq!(sc.accumulated_fees(&token_id).get(), BigUint::from(30u64));
+        });
+}
+
+/// ### TEST
+/// F-MARKET_ADD_FEE_EXEMPTIONS_OK
+///
+/// ### ACTION
+/// Call 'add_fee_exemptions()' with a registered operation
+///
+/// ### EXPECTED
+/// The destination and the token are fee exempt
+#[test]
+fn test_add_fee_exemptions() {
+    let mut state = MvxFeeMarketTestState::new();
+
+    state.common_setup.deploy_chain_config(
+        OptionalValue::Some(SovereignConfig::default_config_for_test()),
+        None,
+    );
+
+    let operation = AddFeeExemptionsOperation {
+        destinations: ManagedVec::from_single_item(RECEIVER_ADDRESS.to_managed_address()),
+        tokens: ManagedVec::from_single_item(EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)),
+        nonce: state.common_setup.next_operation_nonce(),
+    };
+    let operation_hash = operation.generate_hash();
+    let hash_of_hashes = ManagedBuffer::new_from_bytes(&sha256(&operation_hash.to_vec()));
+
+    let (signature, public_keys) = state.common_setup.get_sig_and_pub_keys(1, &hash_of_hashes);
+    state.common_setup.register(
+        public_keys.first().unwrap(),
+        &MultiEgldOrEsdtPayment::new(),
+        None,
+    );
+
+    state.common_setup.complete_chain_config_setup_phase();
+
+    state
+        .common_setup
+        .deploy_fee_market(None, ESDT_SAFE_ADDRESS);
+
+    state
+        .common_setup
+        .deploy_header_verifier(vec![ScArray::FeeMarket, ScArray::ChainConfig]);
+
+    state.common_setup.complete_fee_market_setup_phase();
+
+    state
+        .common_setup
+        .complete_header_verifier_setup_phase(None);
+
+    state.common_setup.register_operation(
+        OWNER_ADDRESS,
+        signature,
+        &hash_of_hashes,
+        state.common_setup.full_bitmap(1),
+        0,
+        MultiValueEncoded::from_iter(vec![operation_hash]),
+    );
+
+    state.add_fee_exemptions(&hash_of_hashes, operation);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(FEE_MARKET_ADDRESS)
+        .whitebox(mvx_fee_market::contract_obj, |sc| {
+            assert!(sc
+                .fee_exempt_destinations()
+                .contains(&RECEIVER_ADDRESS.to_managed_address()));
+            assert!(sc
+                .fee_exempt_tokens()
+                .contains(&EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)));
+        });
+}
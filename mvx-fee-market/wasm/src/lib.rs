@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           13
+// Endpoints:                           39
 // Async Callback (empty):               1
-// Total number of exported functions:  16
+// Total number of exported functions:  42
 
 #![no_std]
 
@@ -22,17 +22,43 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         completeSetupPhase => complete_setup_phase
         distributeFees => distribute_fees
+        allocateFees => allocate_fees
+        setFeeSplit => set_fee_split
         removeFeeDuringSetupPhase => remove_fee_during_setup_phase
         removeFee => remove_fee
         setFeeDuringSetupPhase => set_fee_during_setup_phase
         setFee => set_fee
+        setFeePaymentToken => set_fee_payment_token
+        removeFeePaymentToken => remove_fee_payment_token
         getTokenFee => token_fee
+        getActiveFeeTokens => active_fee_tokens
+        getFeePaymentTokenRates => fee_payment_token_rates
         getUsersWhitelist => users_whitelist
+        getFeeExemptDestinations => fee_exempt_destinations
+        getFeeExemptTokens => fee_exempt_tokens
+        getClaimableFees => claimable_fees
+        getFeeSplit => fee_split
+        getDistributionInterval => distribution_interval
+        getLastDistributionBlock => last_distribution_block
+        getTotalFeesCollected => total_fees_collected
+        getTotalFeesDistributed => total_fees_distributed
+        getRecipientFeesDistributed => recipient_fees_distributed
+        getEpochFeesCollected => epoch_fees_collected
+        getEpochFeesDistributed => epoch_fees_distributed
         subtractFee => subtract_fee
+        claimFees => claim_fees
+        triggerDistribution => trigger_distribution
+        getFeeQuote => get_fee_quote
+        getUserVolume => get_user_volume
+        getUserFeeTier => get_user_fee_tier
         addUsersToWhitelistSetupPhase => add_users_to_whitelist_during_setup_phase
         addUsersToWhitelist => add_users_to_whitelist
         removeUsersFromWhitelistSetupPhase => remove_users_from_whitelist_during_setup_phase
         removeUsersFromWhitelist => remove_users_from_whitelist
+        addFeeExemptionsSetupPhase => add_fee_exemptions_during_setup_phase
+        addFeeExemptions => add_fee_exemptions_operation
+        removeFeeExemptionsSetupPhase => remove_fee_exemptions_during_setup_phase
+        removeFeeExemptions => remove_fee_exemptions_operation
     )
 }
 
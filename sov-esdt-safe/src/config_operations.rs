@@ -1,4 +1,4 @@
-use structs::configs::{EsdtSafeConfig, SovereignConfig};
+use structs::configs::{EsdtSafeConfig, EsdtSafeConfigPatch, SovereignConfig};
 
 multiversx_sc::imports!();
 
@@ -22,6 +22,12 @@ pub trait ConfigOperationsModule:
         self.update_esdt_safe_config_event(esdt_safe_config, self.get_and_save_next_tx_id());
     }
 
+    #[only_owner]
+    #[endpoint(patchEsdtSafeConfig)]
+    fn patch_esdt_safe_config(&self, patch: EsdtSafeConfigPatch<Self::Api>) {
+        self.patch_esdt_safe_config_event(patch, self.get_and_save_next_tx_id());
+    }
+
     #[only_owner]
     #[endpoint(setTokenBurnMechanism)]
     fn set_token_burn_mechanism(&self, token_id: EgldOrEsdtTokenIdentifier<Self::Api>) {
@@ -1,5 +1,10 @@
 multiversx_sc::imports!();
-use structs::aliases::{EventPaymentTuple, OptionalValueTransferDataTuple};
+use proxies::sov_fee_market_proxy::SovFeeMarketProxy;
+use structs::{
+    aliases::{EventPaymentTuple, GasLimit, OptionalValueTransferDataTuple, PaymentsVec},
+    fee::FeeQuote,
+    operation::MultiDepositEntry,
+};
 
 #[multiversx_sc::module]
 pub trait DepositModule:
@@ -17,11 +22,61 @@ pub trait DepositModule:
         to: ManagedAddress,
         opt_transfer_data: OptionalValueTransferDataTuple<Self::Api>,
     ) {
-        self.deposit_common(to, opt_transfer_data, |payment| {
+        self.deposit_common(to, opt_transfer_data, None, |payment| {
             self.process_payment(payment)
         });
     }
 
+    #[payable]
+    #[endpoint(depositWithRefundAddress)]
+    fn deposit_with_refund_address(
+        &self,
+        to: ManagedAddress,
+        refund_address: ManagedAddress,
+        opt_transfer_data: OptionalValueTransferDataTuple<Self::Api>,
+    ) {
+        self.deposit_common(to, opt_transfer_data, Some(refund_address), |payment| {
+            self.process_payment(payment)
+        });
+    }
+
+    #[payable]
+    #[endpoint(depositMulti)]
+    fn deposit_multi(&self, entries: MultiValueEncoded<MultiDepositEntry<Self::Api>>) {
+        self.deposit_multi_common(entries, None, |payment| self.process_payment(payment));
+    }
+
+    #[payable]
+    #[endpoint(depositMultiWithRefundAddress)]
+    fn deposit_multi_with_refund_address(
+        &self,
+        refund_address: ManagedAddress,
+        entries: MultiValueEncoded<MultiDepositEntry<Self::Api>>,
+    ) {
+        self.deposit_multi_common(entries, Some(refund_address), |payment| {
+            self.process_payment(payment)
+        });
+    }
+
+    /// Quotes the fee of a deposit in `fee_token`. Takes the `transfers` to bridge, without the
+    /// fee payment, instead of their count, since percentage and exempt token fees depend on
+    /// the amounts and tokens.
+    #[view(getFeeQuote)]
+    fn get_fee_quote(
+        &self,
+        fee_token: EgldOrEsdtTokenIdentifier,
+        transfers: PaymentsVec<Self::Api>,
+        gas_limit: GasLimit,
+        opt_caller: OptionalValue<ManagedAddress>,
+    ) -> FeeQuote<Self::Api> {
+        self.tx()
+            .to(self.fee_market_address().get())
+            .typed(SovFeeMarketProxy)
+            .get_fee_quote(fee_token, transfers, gas_limit, opt_caller)
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+    }
+
     fn process_payment(
         &self,
         payment: &EgldOrEsdtTokenPayment<Self::Api>,
@@ -41,4 +41,24 @@ pub trait FeeOperationsModule:
     fn remove_users_from_fee_whitelist(&self, users: ManagedVec<ManagedAddress<Self::Api>>) {
         self.remove_users_from_fee_whitelist_event(users, self.get_and_save_next_tx_id());
     }
+
+    #[only_owner]
+    #[endpoint(addFeeExemptions)]
+    fn add_fee_exemptions(
+        &self,
+        destinations: ManagedVec<ManagedAddress<Self::Api>>,
+        tokens: ManagedVec<EgldOrEsdtTokenIdentifier<Self::Api>>,
+    ) {
+        self.add_fee_exemptions_event(destinations, tokens, self.get_and_save_next_tx_id());
+    }
+
+    #[only_owner]
+    #[endpoint(removeFeeExemptions)]
+    fn remove_fee_exemptions(
+        &self,
+        destinations: ManagedVec<ManagedAddress<Self::Api>>,
+        tokens: ManagedVec<EgldOrEsdtTokenIdentifier<Self::Api>>,
+    ) {
+        self.remove_fee_exemptions_event(destinations, tokens, self.get_and_save_next_tx_id());
+    }
 }
@@ -1,6 +1,8 @@
 #![no_std]
 use cross_chain::DEFAULT_ISSUE_COST;
-use error_messages::{EGLD_TOKEN_IDENTIFIER_EXPECTED, ISSUE_COST_NOT_COVERED, TOKEN_ID_NO_PREFIX};
+use error_messages::{
+    EGLD_TOKEN_IDENTIFIER_EXPECTED, INVALID_ISSUE_COST, ISSUE_COST_NOT_COVERED, TOKEN_ID_NO_PREFIX,
+};
 use multiversx_sc::api::ESDT_LOCAL_BURN_FUNC_NAME;
 use multiversx_sc::imports::*;
 use structs::{configs::EsdtSafeConfig, operation::OperationData};
@@ -8,10 +10,12 @@ use structs::{configs::EsdtSafeConfig, operation::OperationData};
 pub mod config_operations;
 pub mod deposit;
 pub mod fee_operations;
+pub mod nft_metadata;
 
 #[multiversx_sc::contract]
 pub trait SovEsdtSafe:
     deposit::DepositModule
+    + nft_metadata::NftMetadataModule
     + cross_chain::LibCommon
     + cross_chain::deposit_common::DepositCommonModule
     + cross_chain::execute_common::ExecuteCommonModule
@@ -31,7 +35,7 @@ pub trait SovEsdtSafe:
 
         let new_config = self.resolve_esdt_safe_config(opt_config);
 
-        self.esdt_safe_config().set(new_config);
+        self.store_esdt_safe_config(new_config);
 
         self.set_paused(true);
     }
@@ -52,7 +56,7 @@ pub trait SovEsdtSafe:
             EGLD_TOKEN_IDENTIFIER_EXPECTED
         );
         require!(
-            call_value.amount == DEFAULT_ISSUE_COST,
+            call_value.amount == self.get_issue_cost(),
             ISSUE_COST_NOT_COVERED
         );
         require!(self.has_prefix(&token_id), TOKEN_ID_NO_PREFIX);
@@ -70,11 +74,11 @@ pub trait SovEsdtSafe:
             token_name,
             token_ticker,
             token_decimals,
-            OperationData {
-                op_nonce: self.get_current_and_increment_tx_nonce(),
-                op_sender: self.blockchain().get_caller(),
-                opt_transfer_data: None,
-            },
+            OperationData::new(
+                self.get_current_and_increment_tx_nonce(),
+                self.blockchain().get_caller(),
+                None,
+            ),
         );
     }
 
@@ -85,7 +89,7 @@ pub trait SovEsdtSafe:
             sc_panic!(error_message);
         }
 
-        self.esdt_safe_config().set(new_config);
+        self.store_esdt_safe_config(new_config);
     }
 
     #[only_owner]
@@ -95,6 +99,31 @@ pub trait SovEsdtSafe:
         self.fee_market_address().set(fee_market_address);
     }
 
+    /// Should match the issue cost of the MultiversX safe, which refunds that amount when
+    /// the registration fails.
+    #[only_owner]
+    #[endpoint(setIssueCost)]
+    fn set_issue_cost(&self, issue_cost: BigUint) {
+        require!(issue_cost > 0u64, INVALID_ISSUE_COST);
+
+        self.issue_cost().set(issue_cost);
+    }
+
+    #[view(getIssueCost)]
+    fn get_issue_cost(&self) -> BigUint {
+        let issue_cost_mapper = self.issue_cost();
+        if issue_cost_mapper.is_empty() {
+            return BigUint::from(DEFAULT_ISSUE_COST);
+        }
+
+        issue_cost_mapper.get()
+    }
+
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.migrate_legacy_esdt_safe_config();
+    }
+
+    #[storage_mapper("issueCost")]
+    fn issue_cost(&self) -> SingleValueMapper<BigUint>;
 }
@@ -0,0 +1,60 @@
+use error_messages::{ESDT_SAFE_STILL_PAUSED, NFT_NOT_DYNAMIC, TOKEN_ID_NO_PREFIX};
+use structs::{configs::NftMetadata, operation::OperationData};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait NftMetadataModule:
+    multiversx_sc_modules::pause::PauseModule
+    + common_utils::CommonUtilsModule
+    + cross_chain::deposit_common::DepositCommonModule
+    + cross_chain::execute_common::ExecuteCommonModule
+    + cross_chain::storage::CrossChainStorage
+    + custom_events::CustomEventsModule
+{
+    /// The NFT is only shown to the safe so its current metadata can be read, and is sent back
+    /// to the caller. The emitted event is picked up as an `UpdateNftMetadataOperation`.
+    #[payable]
+    #[endpoint(syncNftMetadata)]
+    fn sync_nft_metadata(&self) {
+        require!(self.not_paused(), ESDT_SAFE_STILL_PAUSED);
+
+        let payment = self.call_value().single_esdt().clone();
+        let token_id = EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier.clone());
+        require!(self.has_prefix(&token_id), TOKEN_ID_NO_PREFIX);
+
+        let token_data = self.blockchain().get_esdt_token_data(
+            &self.blockchain().get_sc_address(),
+            &payment.token_identifier,
+            payment.token_nonce,
+        );
+        require!(
+            matches!(
+                token_data.token_type,
+                EsdtTokenType::DynamicNFT | EsdtTokenType::DynamicSFT | EsdtTokenType::DynamicMeta
+            ),
+            NFT_NOT_DYNAMIC
+        );
+
+        let caller = self.blockchain().get_caller();
+        let metadata = NftMetadata {
+            name: token_data.name,
+            royalties: token_data.royalties.to_u64().unwrap_or_default(),
+            hash: token_data.hash,
+            attributes: token_data.attributes,
+            uris: token_data.uris,
+        };
+        self.update_nft_metadata_event(
+            token_id,
+            payment.token_nonce,
+            metadata,
+            OperationData::new(
+                self.get_current_and_increment_tx_nonce(),
+                caller.clone(),
+                None,
+            ),
+        );
+
+        self.tx().to(&caller).payment(payment).transfer();
+    }
+}
@@ -108,6 +108,8 @@ impl SovEsdtSafeTestState {
                     ManagedVec::new(),
                     ManagedVec::new(),
                     ManagedVec::new(),
+                    ManagedVec::new(),
+                    ManagedVec::new(),
                 );
 
                 sc.init(
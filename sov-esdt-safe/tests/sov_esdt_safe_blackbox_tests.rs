@@ -4,22 +4,25 @@ use common_test_setup::constants::{
     SECOND_TEST_TOKEN, SOV_TOKEN, TESTING_SC_ENDPOINT, USER_ADDRESS,
 };
 use error_messages::{
-    ACTION_IS_NOT_ALLOWED, EGLD_TOKEN_IDENTIFIER_EXPECTED, ISSUE_COST_NOT_COVERED,
-    NOTHING_TO_TRANSFER, TOKEN_ID_NO_PREFIX,
+    ACTION_IS_NOT_ALLOWED, DEPOSIT_UNDER_MIN_AMOUNT, EGLD_TOKEN_IDENTIFIER_EXPECTED,
+    INVALID_ISSUE_COST, ISSUE_COST_NOT_COVERED, NOTHING_TO_TRANSFER, TOKEN_ID_NO_PREFIX,
 };
 use multiversx_sc::{
     chain_core::EGLD_000000_TOKEN_IDENTIFIER,
     imports::{MultiValue3, OptionalValue},
     types::{
         BigUint, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, EsdtTokenPayment,
-        EsdtTokenType, ManagedBuffer, ManagedVec, MultiValueEncoded,
+        EsdtTokenType, ManagedBuffer, ManagedVec, MultiValueEncoded, ReturnsHandledOrError,
+        ReturnsResult,
     },
 };
 use multiversx_sc_scenario::api::StaticApi;
+use multiversx_sc_scenario::ScenarioTxRun;
+use proxies::sov_esdt_safe_proxy::SovEsdtSafeProxy;
 use sov_esdt_safe_blackbox_setup::SovEsdtSafeTestState;
 use structs::{
     aliases::PaymentsVec,
-    configs::EsdtSafeConfig,
+    configs::{EsdtSafeConfig, MinBridgedAmount},
     fee::{FeeStruct, FeeType},
     RegisterTokenStruct,
 };
@@ -415,6 +418,88 @@ fn test_deposit_no_transfer_data_no_payments() {
     );
 }
 
+/// ### TEST
+/// S-ESDT_DEPOSIT_FAIL
+///
+/// ### ACTION
+/// Call 'deposit()' with an amount under the min bridged amount, then with exactly the min
+///
+/// ### EXPECTED
+/// Error DEPOSIT_UNDER_MIN_AMOUNT, the deposit of the min amount is executed successfully
+#[test]
+fn test_deposit_under_min_bridged_amount() {
+    let mut state = SovEsdtSafeTestState::new();
+
+    state.deploy_contract_with_roles();
+    state
+        .common_setup
+        .deploy_fee_market(None, ESDT_SAFE_ADDRESS);
+    state.common_setup.deploy_testing_sc();
+    state.set_fee_market_address(FEE_MARKET_ADDRESS);
+
+    let config = EsdtSafeConfig {
+        min_bridged_token_amounts: ManagedVec::from(vec![MinBridgedAmount {
+            token_id: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            amount: BigUint::from(ONE_HUNDRED_THOUSAND),
+        }]),
+        ..EsdtSafeConfig::default_config()
+    };
+
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(SovEsdtSafeProxy)
+        .update_configuration(config)
+        .run();
+
+    let under_min_payment = PaymentsVec::from(vec![EsdtTokenPayment::<StaticApi>::new(
+        FIRST_TEST_TOKEN.into(),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND - 1),
+    )]);
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        under_min_payment,
+        Some(DEPOSIT_UNDER_MIN_AMOUNT),
+    );
+
+    state.common_setup.check_account_multiple_esdts(
+        OWNER_ADDRESS.to_address(),
+        vec![MultiValue3::from((
+            FIRST_TEST_TOKEN,
+            0u64,
+            BigUint::from(ONE_HUNDRED_MILLION),
+        ))],
+    );
+
+    let min_payment = PaymentsVec::from(vec![EsdtTokenPayment::<StaticApi>::new(
+        FIRST_TEST_TOKEN.into(),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    )]);
+
+    state.deposit(
+        USER_ADDRESS.to_managed_address(),
+        OptionalValue::None,
+        min_payment,
+        None,
+    );
+
+    state.common_setup.check_account_multiple_esdts(
+        OWNER_ADDRESS.to_address(),
+        vec![MultiValue3::from((
+            FIRST_TEST_TOKEN,
+            0u64,
+            BigUint::from(ONE_HUNDRED_MILLION - ONE_HUNDRED_THOUSAND),
+        ))],
+    );
+}
+
 /// ### TEST
 /// S-ESDT_DEPOSIT_OK
 ///
@@ -487,6 +572,91 @@ fn test_register_token_not_enough_issue_cost() {
     state.register_token(new_token, egld_token_payment, Some(ISSUE_COST_NOT_COVERED));
 }
 
+/// ### TEST
+/// S-ESDT_REGISTER_TOKEN_FAIL
+///
+/// ### ACTION
+/// Call 'set_issue_cost()' with zero and with twice the default cost, then 'register_token()' paying the default and the new cost
+///
+/// ### EXPECTED
+/// INVALID_ISSUE_COST for zero, ISSUE_COST_NOT_COVERED for the default cost and the new cost passes the issue cost check
+#[test]
+fn test_register_token_with_configured_issue_cost() {
+    let mut state = SovEsdtSafeTestState::new();
+
+    state.deploy_contract_with_roles();
+    state
+        .common_setup
+        .deploy_fee_market(None, ESDT_SAFE_ADDRESS);
+    state.common_setup.deploy_testing_sc();
+    state.set_fee_market_address(FEE_MARKET_ADDRESS);
+
+    let new_issue_cost = BigUint::<StaticApi>::from(ISSUE_COST * 2);
+
+    let response = state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(SovEsdtSafeProxy)
+        .set_issue_cost(BigUint::zero())
+        .returns(ReturnsHandledOrError::new())
+        .run();
+    state
+        .common_setup
+        .assert_expected_error_message(response, Some(INVALID_ISSUE_COST));
+
+    state
+        .common_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(SovEsdtSafeProxy)
+        .set_issue_cost(new_issue_cost.clone())
+        .run();
+
+    let issue_cost = state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(SovEsdtSafeProxy)
+        .get_issue_cost()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(issue_cost, new_issue_cost);
+
+    let new_token = RegisterTokenStruct {
+        token_id: EgldOrEsdtTokenIdentifier::from(SOV_TOKEN.as_str()),
+        token_type: EsdtTokenType::Fungible,
+        token_display_name: ManagedBuffer::from("Test Token"),
+        token_ticker: ManagedBuffer::from("TST"),
+        num_decimals: 18,
+    };
+
+    state.register_token(
+        new_token,
+        EgldOrEsdtTokenPayment::new(EGLD_000000_TOKEN_IDENTIFIER.into(), 0, ISSUE_COST.into()),
+        Some(ISSUE_COST_NOT_COVERED),
+    );
+
+    let no_prefix_token = RegisterTokenStruct {
+        token_id: EgldOrEsdtTokenIdentifier::from(FIRST_TEST_TOKEN.as_str()),
+        token_type: EsdtTokenType::Fungible,
+        token_display_name: ManagedBuffer::from("Test Token"),
+        token_ticker: ManagedBuffer::from("TST"),
+        num_decimals: 18,
+    };
+
+    state.register_token(
+        no_prefix_token,
+        EgldOrEsdtTokenPayment::new(EGLD_000000_TOKEN_IDENTIFIER.into(), 0, new_issue_cost),
+        Some(TOKEN_ID_NO_PREFIX),
+    );
+}
+
 /// ### TEST
 /// S-ESDT_REGISTER_TOKEN_FAIL
 ///
@@ -599,3 +769,63 @@ fn test_register_token() {
 
     state.register_token(new_token, egld_token_payment, Some(ACTION_IS_NOT_ALLOWED));
 }
+
+/// ### TEST
+/// S-ESDT_GET_FEE_QUOTE_OK
+///
+/// ### ACTION
+/// Query `getFeeQuote` on the ESDT safe for a whitelisted caller and for a regular caller
+///
+/// ### EXPECTED
+/// The quote is forwarded from the fee market
+#[test]
+fn test_get_fee_quote() {
+    let mut state = SovEsdtSafeTestState::new();
+
+    state.deploy_contract_with_roles();
+
+    let fee = FeeStruct {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+        fee_type: FeeType::Fixed {
+            token: EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+            per_transfer: PER_TRANSFER.into(),
+            per_gas: PER_GAS.into(),
+        },
+    };
+
+    state
+        .common_setup
+        .deploy_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+    state.set_fee_market_address(FEE_MARKET_ADDRESS);
+
+    let gas_limit = 1_000u64;
+    let transfers = PaymentsVec::from_single_item(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+        0,
+        BigUint::from(ONE_HUNDRED_THOUSAND),
+    ));
+
+    let fee_quote = state
+        .common_setup
+        .world
+        .query()
+        .to(ESDT_SAFE_ADDRESS)
+        .typed(SovEsdtSafeProxy)
+        .get_fee_quote(
+            EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN),
+            transfers,
+            gas_limit,
+            OptionalValue::Some(USER_ADDRESS.to_managed_address()),
+        )
+        .returns(ReturnsResult)
+        .run();
+
+    assert_eq!(
+        fee_quote.fee.amount,
+        BigUint::from(PER_TRANSFER + gas_limit * PER_GAS)
+    );
+    assert_eq!(
+        fee_quote.accepted_tokens.get(0).clone(),
+        EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN)
+    );
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           12
+// Endpoints:                           20
 // Async Callback (empty):               1
-// Total number of exported functions:  15
+// Total number of exported functions:  23
 
 #![no_std]
 
@@ -23,7 +23,15 @@ multiversx_sc_wasm_adapter::endpoints! {
         registerToken => register_token
         updateConfiguration => update_configuration
         setFeeMarketAddress => set_fee_market_address
+        setIssueCost => set_issue_cost
+        getIssueCost => get_issue_cost
         deposit => deposit
+        depositWithRefundAddress => deposit_with_refund_address
+        depositMulti => deposit_multi
+        depositMultiWithRefundAddress => deposit_multi_with_refund_address
+        getFeeQuote => get_fee_quote
+        syncNftMetadata => sync_nft_metadata
+        getEsdtSafeConfig => get_esdt_safe_config
         getSovToMvxTokenId => sovereign_to_multiversx_token_id_mapper
         getMvxToSovTokenId => multiversx_to_sovereign_token_id_mapper
         getSovEsdtTokenInfo => sovereign_to_multiversx_esdt_info_mapper
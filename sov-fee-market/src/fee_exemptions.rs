@@ -0,0 +1,30 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait FeeExemptionsModule:
+    fee_common::storage::FeeCommonStorageModule
+    + fee_common::helpers::FeeCommonHelpersModule
+    + common_utils::CommonUtilsModule
+    + custom_events::CustomEventsModule
+{
+    #[only_owner]
+    #[endpoint(addFeeExemptions)]
+    fn add_fee_exemptions(
+        &self,
+        destinations: ManagedVec<ManagedAddress>,
+        tokens: ManagedVec<EgldOrEsdtTokenIdentifier>,
+    ) {
+        self.add_fee_exemptions_to_storage(&destinations, &tokens);
+    }
+
+    #[only_owner]
+    #[endpoint(removeFeeExemptions)]
+    fn remove_fee_exemptions(
+        &self,
+        destinations: ManagedVec<ManagedAddress>,
+        tokens: ManagedVec<EgldOrEsdtTokenIdentifier>,
+    ) {
+        self.remove_fee_exemptions_from_storage(&destinations, &tokens);
+    }
+}
@@ -25,6 +25,33 @@ pub trait FeeOperationsModule:
         self.distribute_fees_and_reset(&pairs);
     }
 
+    #[only_owner]
+    #[endpoint(allocateFees)]
+    fn allocate_fees(
+        &self,
+        address_percentage_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
+    ) {
+        let pairs = self.parse_pairs(address_percentage_pairs);
+        if let Some(percentage_validation_err) = self.validate_percentage_sum(&pairs) {
+            sc_panic!(percentage_validation_err);
+        }
+
+        self.allocate_fees_and_reset(&pairs);
+    }
+
+    #[only_owner]
+    #[endpoint(setFeeSplit)]
+    fn set_fee_split(
+        &self,
+        distribution_interval: u64,
+        address_percentage_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
+    ) {
+        let pairs = self.parse_pairs(address_percentage_pairs);
+        if let Some(err_msg) = self.set_fee_split_in_storage(&pairs, distribution_interval) {
+            sc_panic!(err_msg);
+        }
+    }
+
     #[only_owner]
     #[endpoint(removeFee)]
     fn remove_fee(&self, token_id: EgldOrEsdtTokenIdentifier) {
@@ -38,4 +65,33 @@ pub trait FeeOperationsModule:
             sc_panic!(set_fee_error_msg);
         }
     }
+
+    #[only_owner]
+    #[endpoint(setFeePaymentToken)]
+    fn set_fee_payment_token(
+        &self,
+        base_token: EgldOrEsdtTokenIdentifier,
+        payment_token: EgldOrEsdtTokenIdentifier,
+        rate: BigUint,
+    ) {
+        if let Some(err_msg) =
+            self.set_fee_payment_token_in_storage(&base_token, &payment_token, &rate)
+        {
+            sc_panic!(err_msg);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeFeePaymentToken)]
+    fn remove_fee_payment_token(
+        &self,
+        base_token: EgldOrEsdtTokenIdentifier,
+        payment_token: EgldOrEsdtTokenIdentifier,
+    ) {
+        if let Some(err_msg) =
+            self.remove_fee_payment_token_from_storage(&base_token, &payment_token)
+        {
+            sc_panic!(err_msg);
+        }
+    }
 }
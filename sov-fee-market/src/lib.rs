@@ -3,12 +3,14 @@
 use fee_common::storage;
 use multiversx_sc::imports::*;
 use structs::fee::FeeStruct;
+pub mod fee_exemptions;
 pub mod fee_operations;
 pub mod fee_whitelist;
 
 #[multiversx_sc::contract]
 pub trait SovFeeMarket:
     fee_whitelist::FeeWhitelistModule
+    + fee_exemptions::FeeExemptionsModule
     + storage::FeeCommonStorageModule
     + fee_operations::FeeOperationsModule
     + common_utils::CommonUtilsModule
@@ -22,5 +24,7 @@ pub trait SovFeeMarket:
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, fee_tokens: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
+        self.migrate_active_fee_tokens(fee_tokens);
+    }
 }
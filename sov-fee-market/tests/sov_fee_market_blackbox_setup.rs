@@ -2,22 +2,34 @@ use fee_common::storage::FeeCommonStorageModule;
 use multiversx_sc::{
     imports::OptionalValue,
     types::{
-        Address, BigUint, EgldOrEsdtTokenIdentifier, EsdtTokenPayment, ReturnsHandledOrError,
-        TestTokenIdentifier,
+        Address, BigUint, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, EsdtTokenPayment,
+        ReturnsHandledOrError, TestTokenIdentifier,
     },
 };
 use multiversx_sc_scenario::imports::*;
 
 use common_test_setup::{
-    base_setup::init::{AccountSetup, BaseSetup},
+    base_setup::{
+        init::{AccountSetup, BaseSetup, ExpectedLogs},
+        log_validations::assert_expected_logs,
+    },
     constants::{
-        CROWD_TOKEN_ID, ESDT_SAFE_ADDRESS, FIRST_TEST_TOKEN, MVX_ESDT_SAFE_CODE_PATH,
-        OWNER_ADDRESS, OWNER_BALANCE, PER_GAS, PER_TRANSFER, SECOND_TEST_TOKEN,
-        SOV_FEE_MARKET_ADDRESS, USER_ADDRESS,
+        ADD_FEE_EXEMPTIONS_ENDPOINT, CROWD_TOKEN_ID, ESDT_SAFE_ADDRESS, FEE_EXEMPTIONS_ADDED_EVENT,
+        FEE_EXEMPTIONS_REMOVED_EVENT, FIRST_TEST_TOKEN, MVX_ESDT_SAFE_CODE_PATH, OWNER_ADDRESS,
+        OWNER_BALANCE, PER_GAS, PER_TRANSFER, RECEIVER_ADDRESS, REMOVE_FEE_EXEMPTIONS_ENDPOINT,
+        SECOND_TEST_TOKEN, SOV_FEE_MARKET_ADDRESS, USER_ADDRESS,
     },
+    log,
 };
 use proxies::sov_fee_market_proxy::SovFeeMarketProxy;
-use structs::fee::{FeeStruct, FeeType};
+use structs::{
+    aliases::PaymentsVec,
+    fee::{FeeStruct, FeeTier, FeeType},
+};
+
+pub fn get_destinations() -> ManagedVec<StaticApi, ManagedAddress<StaticApi>> {
+    ManagedVec::from_single_item(RECEIVER_ADDRESS.to_managed_address())
+}
 
 pub struct SovFeeMarketTestState {
     pub common_setup: BaseSetup,
@@ -54,7 +66,7 @@ impl SovFeeMarketTestState {
                 (SECOND_TEST_TOKEN, 0, BigUint::from(OWNER_BALANCE)),
                 (CROWD_TOKEN_ID, 0, BigUint::from(OWNER_BALANCE)),
             ]),
-            egld_balance: None,
+            egld_balance: Some(BigUint::from(OWNER_BALANCE)),
         };
 
         let account_setups = vec![owner_account, user_account, esdt_safe_address];
@@ -75,11 +87,49 @@ impl SovFeeMarketTestState {
         }
     }
 
+    pub fn get_egld_fee(&self) -> FeeStruct<StaticApi> {
+        FeeStruct {
+            base_token: EgldOrEsdtTokenIdentifier::egld(),
+            fee_type: FeeType::Fixed {
+                token: EgldOrEsdtTokenIdentifier::egld(),
+                per_transfer: BigUint::from(PER_TRANSFER),
+                per_gas: BigUint::from(PER_GAS),
+            },
+        }
+    }
+
+    pub fn get_tiered_fee(&self, tiers: Vec<(u64, u64)>) -> FeeStruct<StaticApi> {
+        let tiers = tiers
+            .into_iter()
+            .map(|(min_volume, per_transfer)| FeeTier {
+                min_volume,
+                per_transfer: BigUint::from(per_transfer),
+                per_gas: BigUint::zero(),
+            })
+            .collect::<Vec<_>>();
+
+        FeeStruct {
+            base_token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            fee_type: FeeType::Tiered {
+                token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+                tiers: ManagedVec::from(tiers),
+            },
+        }
+    }
+
+    pub fn get_transfers(&self, amount: u64) -> PaymentsVec<StaticApi> {
+        PaymentsVec::from_single_item(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            0,
+            BigUint::from(amount),
+        ))
+    }
+
     pub fn subtract_fee(
         &mut self,
         payment_wanted: WantedFeeType,
         original_caller: Address,
-        total_transfers: usize,
+        transfers: PaymentsVec<StaticApi>,
         opt_gas_limit: OptionalValue<u64>,
         expected_error_message: Option<&str>,
     ) {
@@ -108,7 +158,12 @@ impl SovFeeMarketTestState {
             .from(ESDT_SAFE_ADDRESS)
             .to(SOV_FEE_MARKET_ADDRESS)
             .typed(SovFeeMarketProxy)
-            .subtract_fee(original_caller, total_transfers, opt_gas_limit)
+            .subtract_fee(
+                original_caller,
+                get_destinations(),
+                transfers,
+                opt_gas_limit,
+            )
             .payment(payment)
             .returns(ReturnsHandledOrError::new())
             .run();
@@ -117,6 +172,35 @@ impl SovFeeMarketTestState {
             .assert_expected_error_message(response, expected_error_message);
     }
 
+    pub fn subtract_egld_fee(
+        &mut self,
+        amount: u64,
+        original_caller: Address,
+        transfers: PaymentsVec<StaticApi>,
+        opt_gas_limit: OptionalValue<u64>,
+        expected_error_message: Option<&str>,
+    ) {
+        let response = self
+            .common_setup
+            .world
+            .tx()
+            .from(ESDT_SAFE_ADDRESS)
+            .to(SOV_FEE_MARKET_ADDRESS)
+            .typed(SovFeeMarketProxy)
+            .subtract_fee(
+                original_caller,
+                get_destinations(),
+                transfers,
+                opt_gas_limit,
+            )
+            .egld(BigUint::from(amount))
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+    }
+
     pub fn remove_fee(
         &mut self,
         token_id: TestTokenIdentifier,
@@ -137,6 +221,32 @@ impl SovFeeMarketTestState {
             .assert_expected_error_message(response, expected_error_message);
     }
 
+    pub fn set_fee_payment_token(
+        &mut self,
+        base_token: TestTokenIdentifier,
+        payment_token: TestTokenIdentifier,
+        rate: BigUint<StaticApi>,
+        expected_error_message: Option<&str>,
+    ) {
+        let response = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SOV_FEE_MARKET_ADDRESS)
+            .typed(SovFeeMarketProxy)
+            .set_fee_payment_token(
+                EgldOrEsdtTokenIdentifier::esdt(base_token),
+                EgldOrEsdtTokenIdentifier::esdt(payment_token),
+                rate,
+            )
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+    }
+
     pub fn set_fee(
         &mut self,
         fee_struct: &FeeStruct<StaticApi>,
@@ -181,6 +291,170 @@ impl SovFeeMarketTestState {
             .assert_expected_error_message(response, expected_error_message);
     }
 
+    pub fn allocate_fees(
+        &mut self,
+        address_percentage_pairs: Vec<MultiValue2<ManagedAddress<StaticApi>, usize>>,
+        expected_error_message: Option<&str>,
+    ) {
+        let response = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SOV_FEE_MARKET_ADDRESS)
+            .typed(SovFeeMarketProxy)
+            .allocate_fees(MultiValueEncoded::from_iter(address_percentage_pairs))
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+    }
+
+    pub fn claim_fees(
+        &mut self,
+        recipient: TestAddress,
+        token_ids: Vec<EgldOrEsdtTokenIdentifier<StaticApi>>,
+        expected_error_message: Option<&str>,
+    ) {
+        let response = self
+            .common_setup
+            .world
+            .tx()
+            .from(recipient)
+            .to(SOV_FEE_MARKET_ADDRESS)
+            .typed(SovFeeMarketProxy)
+            .claim_fees(MultiValueEncoded::from_iter(token_ids))
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+    }
+
+    pub fn check_claimable_fees(
+        &mut self,
+        recipient: TestAddress,
+        token_id: TestTokenIdentifier,
+        amount: u64,
+    ) {
+        self.common_setup
+            .world
+            .query()
+            .to(SOV_FEE_MARKET_ADDRESS)
+            .whitebox(sov_fee_market::contract_obj, |sc| {
+                let claimable_amount = sc
+                    .claimable_fees(&recipient.to_managed_address())
+                    .get(&EgldOrEsdtTokenIdentifier::esdt(token_id))
+                    .unwrap_or_default();
+                assert_eq!(claimable_amount, BigUint::from(amount));
+            });
+    }
+
+    pub fn set_fee_split(
+        &mut self,
+        distribution_interval: u64,
+        address_percentage_pairs: Vec<MultiValue2<ManagedAddress<StaticApi>, usize>>,
+        expected_error_message: Option<&str>,
+    ) {
+        let response = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SOV_FEE_MARKET_ADDRESS)
+            .typed(SovFeeMarketProxy)
+            .set_fee_split(
+                distribution_interval,
+                MultiValueEncoded::from_iter(address_percentage_pairs),
+            )
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+    }
+
+    pub fn trigger_distribution(&mut self, expected_error_message: Option<&str>) {
+        let response = self
+            .common_setup
+            .world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(SOV_FEE_MARKET_ADDRESS)
+            .typed(SovFeeMarketProxy)
+            .trigger_distribution()
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+    }
+
+    pub fn add_fee_exemptions(
+        &mut self,
+        destinations: Vec<ManagedAddress<StaticApi>>,
+        tokens: Vec<EgldOrEsdtTokenIdentifier<StaticApi>>,
+        expected_error_message: Option<&str>,
+    ) {
+        let (response, logs) = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SOV_FEE_MARKET_ADDRESS)
+            .typed(SovFeeMarketProxy)
+            .add_fee_exemptions(
+                ManagedVec::<StaticApi, ManagedAddress<StaticApi>>::from(destinations),
+                ManagedVec::<StaticApi, EgldOrEsdtTokenIdentifier<StaticApi>>::from(tokens),
+            )
+            .returns(ReturnsHandledOrError::new())
+            .returns(ReturnsLogs)
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+
+        if expected_error_message.is_none() {
+            assert_expected_logs(
+                logs,
+                vec![log!(ADD_FEE_EXEMPTIONS_ENDPOINT, topics: [FEE_EXEMPTIONS_ADDED_EVENT])],
+            );
+        }
+    }
+
+    pub fn remove_fee_exemptions(
+        &mut self,
+        destinations: Vec<ManagedAddress<StaticApi>>,
+        tokens: Vec<EgldOrEsdtTokenIdentifier<StaticApi>>,
+        expected_error_message: Option<&str>,
+    ) {
+        let (response, logs) = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SOV_FEE_MARKET_ADDRESS)
+            .typed(SovFeeMarketProxy)
+            .remove_fee_exemptions(
+                ManagedVec::<StaticApi, ManagedAddress<StaticApi>>::from(destinations),
+                ManagedVec::<StaticApi, EgldOrEsdtTokenIdentifier<StaticApi>>::from(tokens),
+            )
+            .returns(ReturnsHandledOrError::new())
+            .returns(ReturnsLogs)
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+
+        if expected_error_message.is_none() {
+            assert_expected_logs(
+                logs,
+                vec![log!(REMOVE_FEE_EXEMPTIONS_ENDPOINT, topics: [FEE_EXEMPTIONS_REMOVED_EVENT])],
+            );
+        }
+    }
+
     pub fn add_users_to_whitelist(
         &mut self,
         users: Vec<ManagedAddress<StaticApi>>,
@@ -239,7 +513,18 @@ impl SovFeeMarketTestState {
             .to(SOV_FEE_MARKET_ADDRESS)
             .whitebox(sov_fee_market::contract_obj, |sc| {
                 let accumulated_fees =
-                    sc.accumulated_fees(&EgldOrEsdtTokenIdentifier::esdt(token_id).unwrap_esdt());
+                    sc.accumulated_fees(&EgldOrEsdtTokenIdentifier::esdt(token_id));
+                assert_eq!(accumulated_fees.get(), BigUint::from(amount));
+            });
+    }
+
+    pub fn check_accumulated_egld_fees(&mut self, amount: u64) {
+        self.common_setup
+            .world
+            .query()
+            .to(SOV_FEE_MARKET_ADDRESS)
+            .whitebox(sov_fee_market::contract_obj, |sc| {
+                let accumulated_fees = sc.accumulated_fees(&EgldOrEsdtTokenIdentifier::egld());
                 assert_eq!(accumulated_fees.get(), BigUint::from(amount));
             });
     }
@@ -1,18 +1,26 @@
 use common_test_setup::constants::{
-    ESDT_SAFE_ADDRESS, FIRST_TEST_TOKEN, OWNER_ADDRESS, PER_GAS, PER_TRANSFER, SECOND_TEST_TOKEN,
+    ESDT_SAFE_ADDRESS, FEE_TOKEN, FIRST_TEST_TOKEN, ONE_HUNDRED_THOUSAND, OWNER_ADDRESS,
+    OWNER_BALANCE, PER_GAS, PER_TRANSFER, RECEIVER_ADDRESS, SECOND_TEST_TOKEN,
     SOV_FEE_MARKET_ADDRESS, USER_ADDRESS, WRONG_TOKEN_ID,
 };
 use error_messages::{
-    INVALID_FEE, INVALID_FEE_TYPE, INVALID_PERCENTAGE_SUM, INVALID_TOKEN_ID, ITEM_NOT_IN_LIST,
-    PAYMENT_DOES_NOT_COVER_FEE, TOKEN_NOT_ACCEPTED_AS_FEE,
+    BASE_TOKEN_FEE_NOT_SET, DISTRIBUTION_INTERVAL_NOT_ELAPSED, FEE_PAYMENT_TOKEN_ALREADY_USED,
+    FEE_SPLIT_NOT_SET, INVALID_FEE, INVALID_FEE_TIERS, INVALID_FEE_TYPE, INVALID_PERCENTAGE_SUM,
+    INVALID_TOKEN_ID, ITEM_NOT_IN_LIST, NO_FEES_TO_CLAIM, PAYMENT_DOES_NOT_COVER_FEE,
+    TOKEN_NOT_ACCEPTED_AS_FEE,
 };
+use fee_common::endpoints::FeeCommonEndpointsModule;
+use fee_common::helpers::{FEE_RATE_PRECISION, VOLUME_WINDOW_SECONDS};
 use fee_common::storage::FeeCommonStorageModule;
 use multiversx_sc::{
     imports::{MultiValue2, OptionalValue},
-    types::{BigUint, EgldOrEsdtTokenIdentifier},
+    types::{
+        BigUint, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, ManagedVec, TimestampSeconds,
+    },
 };
 use multiversx_sc_scenario::ScenarioTxWhitebox;
 use sov_fee_market_blackbox_setup::{SovFeeMarketTestState, WantedFeeType};
+use structs::aliases::PaymentsVec;
 use structs::fee::{FeeStruct, FeeType};
 
 mod sov_fee_market_blackbox_setup;
@@ -211,7 +219,7 @@ fn test_remove_fee_non_existent() {
 /// Call 'subtract_fee()' with correct payment
 ///
 /// ### EXPECTED
-/// Fee is subtracted successfully
+/// Fee is subtracted successfully and no volume is recorded for the fixed fee
 #[test]
 fn test_subtract_fee() {
     let mut state = SovFeeMarketTestState::new();
@@ -224,12 +232,23 @@ fn test_subtract_fee() {
     state.subtract_fee(
         WantedFeeType::Correct,
         USER_ADDRESS.to_address(),
-        1,
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
         OptionalValue::None,
         None,
     );
 
     state.check_accumulated_fees(FIRST_TEST_TOKEN, PER_TRANSFER);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SOV_FEE_MARKET_ADDRESS)
+        .whitebox(sov_fee_market::contract_obj, |sc| {
+            assert!(sc
+                .user_volume(&USER_ADDRESS.to_managed_address())
+                .is_empty());
+        });
 }
 
 /// ### TEST
@@ -252,7 +271,7 @@ fn test_subtract_fee_invalid_token() {
     state.subtract_fee(
         WantedFeeType::InvalidToken,
         USER_ADDRESS.to_address(),
-        1,
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
         OptionalValue::None,
         Some(TOKEN_NOT_ACCEPTED_AS_FEE),
     );
@@ -280,7 +299,7 @@ fn test_subtract_fee_insufficient_payment() {
     state.subtract_fee(
         WantedFeeType::LessThanFee,
         USER_ADDRESS.to_address(),
-        1,
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
         OptionalValue::None,
         Some(PAYMENT_DOES_NOT_COVER_FEE),
     );
@@ -308,7 +327,7 @@ fn test_distribute_fees() {
     state.subtract_fee(
         WantedFeeType::Correct,
         USER_ADDRESS.to_address(),
-        1,
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
         OptionalValue::None,
         None,
     );
@@ -435,3 +454,817 @@ fn test_remove_users_from_whitelist_not_in_list() {
     let users_to_remove = vec![USER_ADDRESS.to_managed_address()];
     state.remove_users_from_whitelist(users_to_remove, Some(ITEM_NOT_IN_LIST));
 }
+
+/// ### TEST
+/// S-FEE-MARKET_SUBTRACT_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' with a token accepted as fee payment for the base token
+///
+/// ### EXPECTED
+/// The fee is converted with the conversion rate and accumulated in the payment token
+#[test]
+fn test_subtract_fee_in_payment_token() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    // 1 SECOND_TEST_TOKEN is worth 20 FIRST_TEST_TOKEN
+    let rate = BigUint::from(FEE_RATE_PRECISION) / 20u64;
+    state.set_fee_payment_token(FIRST_TEST_TOKEN, SECOND_TEST_TOKEN, rate, None);
+
+    state.subtract_fee(
+        WantedFeeType::InvalidToken,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    state.check_accumulated_fees(SECOND_TEST_TOKEN, PER_TRANSFER / 20);
+}
+
+/// ### TEST
+/// S-FEE-MARKET_SUBTRACT_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' with a percentage fee for a transfer in a token accepted as fee payment
+///
+/// ### EXPECTED
+/// The transfer is valued in the fee token through the conversion rate
+#[test]
+fn test_subtract_percentage_fee_in_payment_token() {
+    let mut state = SovFeeMarketTestState::new();
+
+    // 1% of the bridged amount
+    let fee = FeeStruct {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+        fee_type: FeeType::Percentage {
+            token: EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN),
+            basis_points: 100,
+            min: BigUint::from(10u64),
+            max: BigUint::from(1_000u64),
+        },
+    };
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    // 1 SECOND_TEST_TOKEN is worth 20 FIRST_TEST_TOKEN
+    let rate = BigUint::from(FEE_RATE_PRECISION) / 20u64;
+    state.set_fee_payment_token(FIRST_TEST_TOKEN, SECOND_TEST_TOKEN, rate, None);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        PaymentsVec::from_single_item(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN),
+            0,
+            BigUint::from(500u64),
+        )),
+        OptionalValue::None,
+        None,
+    );
+
+    state.check_accumulated_fees(FIRST_TEST_TOKEN, 100);
+}
+
+/// ### TEST
+/// S-FEE-MARKET_SET_FEE_PAYMENT_TOKEN_FAIL
+///
+/// ### ACTION
+/// Call 'set_fee_payment_token()' for a base token without a fee, then remove the base token fee
+///
+/// ### EXPECTED
+/// Error BASE_TOKEN_FEE_NOT_SET, and the payment token is dropped when the base token fee is removed
+#[test]
+fn test_fee_payment_token_follows_base_token_fee() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.set_fee_payment_token(
+        SECOND_TEST_TOKEN,
+        FIRST_TEST_TOKEN,
+        BigUint::from(FEE_RATE_PRECISION),
+        Some(BASE_TOKEN_FEE_NOT_SET),
+    );
+
+    state.set_fee_payment_token(
+        FIRST_TEST_TOKEN,
+        SECOND_TEST_TOKEN,
+        BigUint::from(FEE_RATE_PRECISION),
+        None,
+    );
+    state.remove_fee(FIRST_TEST_TOKEN, None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SOV_FEE_MARKET_ADDRESS)
+        .whitebox(sov_fee_market::contract_obj, |sc| {
+            let payment_token = EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN);
+            assert!(!sc.is_fee_token_active(&payment_token));
+            assert!(sc.fee_payment_token_base(&payment_token).is_empty());
+            assert!(sc
+                .fee_payment_token_rates(&EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN))
+                .is_empty());
+        });
+}
+
+/// ### TEST
+/// S-FEE-MARKET_SET_FEE_PAYMENT_TOKEN_FAIL
+///
+/// ### ACTION
+/// Accept a payment token for one base token, then use it for another base token and use a base token as payment token
+///
+/// ### EXPECTED
+/// The payment token is not an active fee token, the other uses fail with FEE_PAYMENT_TOKEN_ALREADY_USED
+#[test]
+fn test_fee_payment_token_already_used() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    let second_fee = FeeStruct {
+        base_token: EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN),
+        fee_type: FeeType::Fixed {
+            token: EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN),
+            per_transfer: BigUint::from(PER_TRANSFER),
+            per_gas: BigUint::from(PER_GAS),
+        },
+    };
+    state.set_fee(&second_fee, None);
+
+    state.set_fee_payment_token(
+        FIRST_TEST_TOKEN,
+        FEE_TOKEN,
+        BigUint::from(FEE_RATE_PRECISION),
+        None,
+    );
+    state.set_fee_payment_token(
+        SECOND_TEST_TOKEN,
+        FEE_TOKEN,
+        BigUint::from(FEE_RATE_PRECISION),
+        Some(FEE_PAYMENT_TOKEN_ALREADY_USED),
+    );
+    state.set_fee_payment_token(
+        FIRST_TEST_TOKEN,
+        SECOND_TEST_TOKEN,
+        BigUint::from(FEE_RATE_PRECISION),
+        Some(FEE_PAYMENT_TOKEN_ALREADY_USED),
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SOV_FEE_MARKET_ADDRESS)
+        .whitebox(sov_fee_market::contract_obj, |sc| {
+            assert!(!sc.is_fee_token_active(&EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN)));
+            assert!(sc.is_fee_token_active(&EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)));
+            assert!(sc.is_fee_token_active(&EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN)));
+        });
+}
+
+/// ### TEST
+/// S-FEE-MARKET_SUBTRACT_EGLD_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' and 'distribute_fees()' with EGLD as the fee token
+///
+/// ### EXPECTED
+/// EGLD fee is accumulated, the rest is sent to the original caller and the fee is distributed
+#[test]
+fn test_subtract_and_distribute_egld_fee() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_egld_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.subtract_egld_fee(
+        2 * PER_TRANSFER,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    state.check_accumulated_egld_fees(PER_TRANSFER);
+    state
+        .common_setup
+        .world
+        .check_account(USER_ADDRESS)
+        .balance(PER_TRANSFER);
+
+    let pairs = vec![
+        MultiValue2::from((USER_ADDRESS.to_managed_address(), 5000usize)),
+        MultiValue2::from((OWNER_ADDRESS.to_managed_address(), 5000usize)),
+    ];
+
+    state.distribute_fees(pairs, None);
+
+    state.check_accumulated_egld_fees(0);
+    state
+        .common_setup
+        .world
+        .check_account(USER_ADDRESS)
+        .balance(PER_TRANSFER + PER_TRANSFER / 2);
+    state
+        .common_setup
+        .world
+        .check_account(OWNER_ADDRESS)
+        .balance(PER_TRANSFER / 2);
+}
+
+/// ### TEST
+/// S-FEE-MARKET_SUBTRACT_EGLD_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' with EGLD for a whitelisted user
+///
+/// ### EXPECTED
+/// EGLD payment is refunded to the user and no fee is accumulated
+#[test]
+fn test_subtract_egld_fee_whitelisted() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_egld_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.add_users_to_whitelist(vec![USER_ADDRESS.to_managed_address()], None);
+
+    state.subtract_egld_fee(
+        2 * PER_TRANSFER,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    state.check_accumulated_egld_fees(0);
+    state
+        .common_setup
+        .world
+        .check_account(ESDT_SAFE_ADDRESS)
+        .balance(OWNER_BALANCE - 2 * PER_TRANSFER as u128);
+    state
+        .common_setup
+        .world
+        .check_account(USER_ADDRESS)
+        .balance(2 * PER_TRANSFER);
+}
+
+/// ### TEST
+/// S-FEE-MARKET_SUBTRACT_TIERED_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' repeatedly with a tiered fee, then let the volume decay
+///
+/// ### EXPECTED
+/// The cheaper tier applies once the volume threshold is reached and is lost as the volume decays
+#[test]
+fn test_subtract_tiered_fee() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_tiered_fee(vec![(0, PER_TRANSFER), (2, PER_TRANSFER / 2)]);
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    for _ in 0..3 {
+        state.subtract_fee(
+            WantedFeeType::Correct,
+            USER_ADDRESS.to_address(),
+            state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+            OptionalValue::None,
+            None,
+        );
+    }
+
+    state.check_accumulated_fees(FIRST_TEST_TOKEN, 2 * PER_TRANSFER + PER_TRANSFER / 2);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SOV_FEE_MARKET_ADDRESS)
+        .whitebox(sov_fee_market::contract_obj, |sc| {
+            let user = USER_ADDRESS.to_managed_address();
+            let token_id = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            assert_eq!(sc.get_user_volume(user.clone()), 3);
+            assert_eq!(sc.get_user_fee_tier(user, token_id), Some(1));
+        });
+
+    state
+        .common_setup
+        .world
+        .current_block()
+        .block_timestamp(TimestampSeconds::new(VOLUME_WINDOW_SECONDS / 3));
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SOV_FEE_MARKET_ADDRESS)
+        .whitebox(sov_fee_market::contract_obj, |sc| {
+            let user = USER_ADDRESS.to_managed_address();
+            let token_id = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            assert_eq!(sc.get_user_volume(user.clone()), 2);
+            assert_eq!(sc.get_user_fee_tier(user, token_id), Some(1));
+        });
+
+    state
+        .common_setup
+        .world
+        .current_block()
+        .block_timestamp(TimestampSeconds::new(VOLUME_WINDOW_SECONDS));
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SOV_FEE_MARKET_ADDRESS)
+        .whitebox(sov_fee_market::contract_obj, |sc| {
+            let user = USER_ADDRESS.to_managed_address();
+            let token_id = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            assert_eq!(sc.get_user_volume(user.clone()), 0);
+            assert_eq!(sc.get_user_fee_tier(user, token_id), Some(0));
+        });
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    state.check_accumulated_fees(FIRST_TEST_TOKEN, 3 * PER_TRANSFER + PER_TRANSFER / 2);
+}
+
+/// ### TEST
+/// S-FEE-MARKET_SET_TIERED_FEE_FAIL
+///
+/// ### ACTION
+/// Call 'set_fee()' with tiers that do not start at zero or are not increasing
+///
+/// ### EXPECTED
+/// Error INVALID_FEE_TIERS
+#[test]
+fn test_set_tiered_fee_invalid_tiers() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    let fee_not_starting_at_zero = state.get_tiered_fee(vec![(1, PER_TRANSFER)]);
+    state.set_fee(&fee_not_starting_at_zero, Some(INVALID_FEE_TIERS));
+
+    let fee_not_increasing =
+        state.get_tiered_fee(vec![(0, PER_TRANSFER), (5, PER_TRANSFER / 2), (5, 1)]);
+    state.set_fee(&fee_not_increasing, Some(INVALID_FEE_TIERS));
+}
+
+/// ### TEST
+/// S-FEE-MARKET_ALLOCATE_AND_CLAIM_FEES_OK
+///
+/// ### ACTION
+/// Call 'allocate_fees()' and let each recipient call 'claim_fees()'
+///
+/// ### EXPECTED
+/// Shares are recorded as claimable and sent only when claimed
+#[test]
+fn test_allocate_and_claim_fees() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    let pairs = vec![
+        MultiValue2::from((USER_ADDRESS.to_managed_address(), 5000usize)),
+        MultiValue2::from((OWNER_ADDRESS.to_managed_address(), 5000usize)),
+    ];
+
+    state.allocate_fees(pairs, None);
+
+    state.check_accumulated_fees(FIRST_TEST_TOKEN, 0);
+    state.check_claimable_fees(USER_ADDRESS, FIRST_TEST_TOKEN, PER_TRANSFER / 2);
+    state.check_claimable_fees(OWNER_ADDRESS, FIRST_TEST_TOKEN, PER_TRANSFER / 2);
+
+    state.claim_fees(USER_ADDRESS, vec![], None);
+    state.claim_fees(
+        OWNER_ADDRESS,
+        vec![EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)],
+        None,
+    );
+
+    state.check_claimable_fees(USER_ADDRESS, FIRST_TEST_TOKEN, 0);
+    state.check_claimable_fees(OWNER_ADDRESS, FIRST_TEST_TOKEN, 0);
+    state.common_setup.check_account_single_esdt(
+        USER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE + u128::from(PER_TRANSFER + PER_TRANSFER / 2)),
+    );
+    state.common_setup.check_account_single_esdt(
+        OWNER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE + u128::from(PER_TRANSFER / 2)),
+    );
+
+    state.claim_fees(USER_ADDRESS, vec![], Some(NO_FEES_TO_CLAIM));
+}
+
+/// ### TEST
+/// S-FEE-MARKET_ALLOCATE_FEES_FAIL
+///
+/// ### ACTION
+/// Call 'allocate_fees()' with invalid percentage sum
+///
+/// ### EXPECTED
+/// Error INVALID_PERCENTAGE_SUM
+#[test]
+fn test_allocate_fees_invalid_percentage() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    let pairs = vec![MultiValue2::from((
+        USER_ADDRESS.to_managed_address(),
+        6000usize,
+    ))];
+
+    state.allocate_fees(pairs, Some(INVALID_PERCENTAGE_SUM));
+}
+
+/// ### TEST
+/// S-FEE-MARKET_TRIGGER_DISTRIBUTION_OK
+///
+/// ### ACTION
+/// Set a standing fee split and call 'trigger_distribution()' before and after the interval
+///
+/// ### EXPECTED
+/// Fees are distributed only once the interval elapsed and the rounding remainder is carried over
+#[test]
+fn test_trigger_distribution() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.trigger_distribution(Some(FEE_SPLIT_NOT_SET));
+
+    let distribution_interval = 10u64;
+    let pairs = vec![
+        MultiValue2::from((USER_ADDRESS.to_managed_address(), 3333usize)),
+        MultiValue2::from((OWNER_ADDRESS.to_managed_address(), 6667usize)),
+    ];
+    state.set_fee_split(distribution_interval, pairs, None);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+
+    state.trigger_distribution(Some(DISTRIBUTION_INTERVAL_NOT_ELAPSED));
+
+    state
+        .common_setup
+        .world
+        .current_block()
+        .block_nonce(distribution_interval);
+
+    state.trigger_distribution(None);
+
+    state.check_accumulated_fees(FIRST_TEST_TOKEN, 1);
+    state.common_setup.check_account_single_esdt(
+        USER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE + u128::from(PER_TRANSFER) + 33),
+    );
+    state.common_setup.check_account_single_esdt(
+        OWNER_ADDRESS.to_address(),
+        FIRST_TEST_TOKEN,
+        0u64,
+        BigUint::from(OWNER_BALANCE + 66),
+    );
+
+    state.trigger_distribution(Some(DISTRIBUTION_INTERVAL_NOT_ELAPSED));
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SOV_FEE_MARKET_ADDRESS)
+        .whitebox(sov_fee_market::contract_obj, |sc| {
+            assert!(sc
+                .tokens_for_fees()
+                .contains(&EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)));
+            assert_eq!(sc.last_distribution_block().get(), distribution_interval);
+        });
+}
+
+/// ### TEST
+/// S-FEE-MARKET_SET_FEE_SPLIT_FAIL
+///
+/// ### ACTION
+/// Call 'set_fee_split()' with invalid percentage sum
+///
+/// ### EXPECTED
+/// Error INVALID_PERCENTAGE_SUM
+#[test]
+fn test_set_fee_split_invalid_percentage() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    let pairs = vec![
+        MultiValue2::from((USER_ADDRESS.to_managed_address(), 5000usize)),
+        MultiValue2::from((OWNER_ADDRESS.to_managed_address(), 4000usize)),
+    ];
+    state.set_fee_split(10, pairs, Some(INVALID_PERCENTAGE_SUM));
+}
+
+/// ### TEST
+/// S-FEE-MARKET_GET_FEE_QUOTE_OK
+///
+/// ### ACTION
+/// Query 'get_fee_quote()' in the base token, in an accepted payment token and for a whitelisted caller
+///
+/// ### EXPECTED
+/// The quote matches what 'subtract_fee()' would charge
+#[test]
+fn test_get_fee_quote() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    let rate = BigUint::from(FEE_RATE_PRECISION) / 20u64;
+    state.set_fee_payment_token(FIRST_TEST_TOKEN, SECOND_TEST_TOKEN, rate, None);
+    state.add_users_to_whitelist(vec![OWNER_ADDRESS.to_managed_address()], None);
+
+    let gas_limit = 10u64;
+    let base_fee = PER_TRANSFER + gas_limit * PER_GAS;
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SOV_FEE_MARKET_ADDRESS)
+        .whitebox(sov_fee_market::contract_obj, |sc| {
+            let first_token = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            let second_token = EgldOrEsdtTokenIdentifier::esdt(SECOND_TEST_TOKEN);
+            let transfers = ManagedVec::from_single_item(EgldOrEsdtTokenPayment::new(
+                first_token.clone(),
+                0,
+                BigUint::from(ONE_HUNDRED_THOUSAND),
+            ));
+
+            let base_quote = sc.get_fee_quote(
+                first_token.clone(),
+                transfers.clone(),
+                gas_limit,
+                OptionalValue::Some(USER_ADDRESS.to_managed_address()),
+            );
+            assert_eq!(base_quote.fee.token_identifier, first_token);
+            assert_eq!(base_quote.fee.amount, BigUint::from(base_fee));
+            assert_eq!(base_quote.accepted_tokens.len(), 2);
+
+            let payment_token_quote = sc.get_fee_quote(
+                second_token.clone(),
+                transfers.clone(),
+                gas_limit,
+                OptionalValue::None,
+            );
+            assert_eq!(payment_token_quote.fee.token_identifier, second_token);
+            assert_eq!(
+                payment_token_quote.fee.amount,
+                BigUint::from(base_fee.div_ceil(20))
+            );
+
+            let whitelisted_quote = sc.get_fee_quote(
+                first_token,
+                transfers,
+                gas_limit,
+                OptionalValue::Some(OWNER_ADDRESS.to_managed_address()),
+            );
+            assert_eq!(whitelisted_quote.fee.amount, BigUint::zero());
+        });
+}
+
+/// ### TEST
+/// S-FEE-MARKET_FEE_REVENUE_ACCOUNTING_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()', then 'allocate_fees()' and 'distribute_fees()'
+///
+/// ### EXPECTED
+/// Collected and distributed counters are kept per token, per recipient and per epoch
+#[test]
+fn test_fee_revenue_accounting() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+    state.allocate_fees(
+        vec![MultiValue2::from((
+            USER_ADDRESS.to_managed_address(),
+            10_000usize,
+        ))],
+        None,
+    );
+
+    state.common_setup.world.current_block().block_epoch(1);
+
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+    state.distribute_fees(
+        vec![MultiValue2::from((
+            OWNER_ADDRESS.to_managed_address(),
+            10_000usize,
+        ))],
+        None,
+    );
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SOV_FEE_MARKET_ADDRESS)
+        .whitebox(sov_fee_market::contract_obj, |sc| {
+            let token_id = EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN);
+            assert_eq!(
+                sc.total_fees_collected(&token_id).get(),
+                BigUint::from(2 * PER_TRANSFER)
+            );
+            assert_eq!(
+                sc.total_fees_distributed(&token_id).get(),
+                BigUint::from(2 * PER_TRANSFER)
+            );
+            assert_eq!(
+                sc.epoch_fees_distributed(0, &token_id).get(),
+                BigUint::from(PER_TRANSFER)
+            );
+            assert_eq!(
+                sc.epoch_fees_distributed(1, &token_id).get(),
+                BigUint::from(PER_TRANSFER)
+            );
+            assert_eq!(
+                sc.recipient_fees_distributed(&USER_ADDRESS.to_managed_address(), &token_id)
+                    .get(),
+                BigUint::from(PER_TRANSFER)
+            );
+            assert_eq!(
+                sc.recipient_fees_distributed(&OWNER_ADDRESS.to_managed_address(), &token_id)
+                    .get(),
+                BigUint::from(PER_TRANSFER)
+            );
+        });
+}
+
+/// ### TEST
+/// S-FEE-MARKET_SUBTRACT_FEE_OK
+///
+/// ### ACTION
+/// Call 'subtract_fee()' towards an exempt destination, with an exempt token, then without exemptions
+///
+/// ### EXPECTED
+/// No fee is accumulated while an exemption applies
+#[test]
+fn test_subtract_fee_with_exemptions() {
+    let mut state = SovFeeMarketTestState::new();
+
+    let fee = state.get_fee();
+    state
+        .common_setup
+        .deploy_sov_fee_market(Some(fee), ESDT_SAFE_ADDRESS);
+
+    state.add_fee_exemptions(vec![RECEIVER_ADDRESS.to_managed_address()], vec![], None);
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+    state.check_accumulated_fees(FIRST_TEST_TOKEN, 0);
+
+    state.remove_fee_exemptions(vec![RECEIVER_ADDRESS.to_managed_address()], vec![], None);
+    state.add_fee_exemptions(
+        vec![],
+        vec![EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)],
+        None,
+    );
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+    state.check_accumulated_fees(FIRST_TEST_TOKEN, 0);
+
+    state.remove_fee_exemptions(
+        vec![],
+        vec![EgldOrEsdtTokenIdentifier::esdt(FIRST_TEST_TOKEN)],
+        None,
+    );
+    state.subtract_fee(
+        WantedFeeType::Correct,
+        USER_ADDRESS.to_address(),
+        state.get_transfers(ONE_HUNDRED_THOUSAND.into()),
+        OptionalValue::None,
+        None,
+    );
+    state.check_accumulated_fees(FIRST_TEST_TOKEN, PER_TRANSFER);
+}
+
+/// ### TEST
+/// S-FEE-MARKET_REMOVE_FEE_EXEMPTIONS_OK
+///
+/// ### ACTION
+/// Call 'remove_fee_exemptions()' with a destination that is not exempt
+///
+/// ### EXPECTED
+/// The destination is ignored, as on the MultiversX fee market
+#[test]
+fn test_remove_fee_exemptions_not_exempt() {
+    let mut state = SovFeeMarketTestState::new();
+
+    state
+        .common_setup
+        .deploy_sov_fee_market(None, ESDT_SAFE_ADDRESS);
+
+    state.add_fee_exemptions(vec![USER_ADDRESS.to_managed_address()], vec![], None);
+    state.remove_fee_exemptions(vec![RECEIVER_ADDRESS.to_managed_address()], vec![], None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SOV_FEE_MARKET_ADDRESS)
+        .whitebox(sov_fee_market::contract_obj, |sc| {
+            assert!(sc
+                .fee_exempt_destinations()
+                .contains(&USER_ADDRESS.to_managed_address()));
+            assert_eq!(sc.fee_exempt_destinations().len(), 1);
+        });
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            8
+// Endpoints:                           32
 // Async Callback (empty):               1
-// Total number of exported functions:  11
+// Total number of exported functions:  35
 
 #![no_std]
 
@@ -22,12 +22,36 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         addUsersToWhitelist => add_users_to_whitelist
         removeUsersFromWhitelist => remove_users_from_whitelist
+        addFeeExemptions => add_fee_exemptions
+        removeFeeExemptions => remove_fee_exemptions
         getTokenFee => token_fee
+        getActiveFeeTokens => active_fee_tokens
+        getFeePaymentTokenRates => fee_payment_token_rates
         getUsersWhitelist => users_whitelist
+        getFeeExemptDestinations => fee_exempt_destinations
+        getFeeExemptTokens => fee_exempt_tokens
+        getClaimableFees => claimable_fees
+        getFeeSplit => fee_split
+        getDistributionInterval => distribution_interval
+        getLastDistributionBlock => last_distribution_block
+        getTotalFeesCollected => total_fees_collected
+        getTotalFeesDistributed => total_fees_distributed
+        getRecipientFeesDistributed => recipient_fees_distributed
+        getEpochFeesCollected => epoch_fees_collected
+        getEpochFeesDistributed => epoch_fees_distributed
         distributeFees => distribute_fees
+        allocateFees => allocate_fees
+        setFeeSplit => set_fee_split
         removeFee => remove_fee
         setFee => set_fee
+        setFeePaymentToken => set_fee_payment_token
+        removeFeePaymentToken => remove_fee_payment_token
         subtractFee => subtract_fee
+        claimFees => claim_fees
+        triggerDistribution => trigger_distribution
+        getFeeQuote => get_fee_quote
+        getUserVolume => get_user_volume
+        getUserFeeTier => get_user_fee_tier
     )
 }
 
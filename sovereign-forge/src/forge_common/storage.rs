@@ -21,6 +21,13 @@ pub trait StorageModule {
         chain_id: &ChainId<Self::Api>,
     ) -> UnorderedSetMapper<ContractInfo<Self::Api>>;
 
+    #[view(getEsdtSafeSuccessor)]
+    #[storage_mapper("esdtSafeSuccessor")]
+    fn esdt_safe_successor(
+        &self,
+        chain_id: &ChainId<Self::Api>,
+    ) -> SingleValueMapper<ManagedAddress>;
+
     #[view(getTrustedTokens)]
     #[storage_mapper("trustedTokens")]
     fn trusted_tokens(&self) -> UnorderedSetMapper<ManagedBuffer>;
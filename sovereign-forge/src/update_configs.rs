@@ -19,6 +19,18 @@ pub trait UpdateConfigsModule:
     + callbacks::ForgeCallbackModule
     + pause::PauseModule
 {
+    #[endpoint(registerEsdtSafeSuccessor)]
+    fn register_esdt_safe_successor(&self, successor: ManagedAddress) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+
+        self.require_phase_two_completed(&caller);
+        self.require_sc_address(&successor);
+
+        self.esdt_safe_successor(&self.sovereigns_mapper(&caller).get())
+            .set(successor);
+    }
+
     #[endpoint(updateEsdtSafeConfig)]
     fn update_esdt_safe_config(&self, new_config: EsdtSafeConfig<Self::Api>) {
         self.require_not_paused();
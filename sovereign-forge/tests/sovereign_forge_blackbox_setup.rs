@@ -143,6 +143,26 @@ impl SovereignForgeTestState {
         }
     }
 
+    pub fn register_esdt_safe_successor(
+        &mut self,
+        successor: TestSCAddress,
+        expected_error_message: Option<&str>,
+    ) {
+        let response = self
+            .common_setup
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SOVEREIGN_FORGE_SC_ADDRESS)
+            .typed(SovereignForgeProxy)
+            .register_esdt_safe_successor(successor)
+            .returns(ReturnsHandledOrError::new())
+            .run();
+
+        self.common_setup
+            .assert_expected_error_message(response, expected_error_message);
+    }
+
     pub fn set_fee(&mut self, new_fee: FeeStruct<StaticApi>, expected_error_message: Option<&str>) {
         let response = self
             .common_setup
@@ -281,7 +281,7 @@ fn test_update_esdt_safe_config() {
         .query()
         .to(mvx_esdt_safe_address_from_sovereign_forge)
         .whitebox(mvx_esdt_safe::contract_obj, |sc| {
-            let max_bridged_amount = sc.esdt_safe_config().get().max_tx_gas_limit;
+            let max_bridged_amount = sc.max_tx_gas_limit().get();
             let expected_amount: u64 = ONE_HUNDRED_THOUSAND.into();
             assert!(max_bridged_amount == expected_amount);
         })
@@ -881,6 +881,57 @@ fn test_deploy_phase_one_with_chain_id_used() {
     );
 }
 
+/// ### TEST
+/// S-FORGE_REGISTER_ESDT_SAFE_SUCCESSOR_OK
+///
+/// ### ACTION
+/// Call register_esdt_safe_successor before and after deploy_phase_two
+///
+/// ### EXPECTED
+/// The first call fails with ESDT_SAFE_NOT_DEPLOYED, the second one stores the successor for the chain
+#[test]
+fn test_register_esdt_safe_successor() {
+    let mut state = SovereignForgeTestState::new();
+    state
+        .common_setup
+        .deploy_sovereign_forge(OptionalValue::Some(DEPLOY_COST.into()));
+
+    state.deploy_template_scs(Some(vec![
+        ScArray::ChainFactory,
+        ScArray::ChainConfig,
+        ScArray::ESDTSafe,
+    ]));
+    state.finish_setup();
+
+    state
+        .common_setup
+        .deploy_phase_one(&DEPLOY_COST.into(), None, OptionalValue::None, None);
+
+    state.register_esdt_safe_successor(CHAIN_FACTORY_SC_ADDRESS, Some(ESDT_SAFE_NOT_DEPLOYED));
+
+    state
+        .common_setup
+        .deploy_phase_two(None, OptionalValue::None);
+
+    state.register_esdt_safe_successor(CHAIN_FACTORY_SC_ADDRESS, None);
+
+    state
+        .common_setup
+        .world
+        .query()
+        .to(SOVEREIGN_FORGE_SC_ADDRESS)
+        .whitebox(sovereign_forge::contract_obj, |sc| {
+            let chain_id = sc
+                .sovereigns_mapper(&OWNER_ADDRESS.to_managed_address())
+                .get();
+
+            assert!(
+                sc.esdt_safe_successor(&chain_id).get()
+                    == CHAIN_FACTORY_SC_ADDRESS.to_managed_address()
+            );
+        });
+}
+
 /// ### TEST
 /// S-FORGE_DEPLOY_PHASE_TWO_FAIL
 ///
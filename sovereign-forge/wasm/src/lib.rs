@@ -6,10 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           25
+// Endpoints:                           27
 // Async Callback (empty):               1
 // Promise callbacks:                    3
-// Total number of exported functions:  31
+// Total number of exported functions:  33
 
 #![no_std]
 
@@ -29,11 +29,13 @@ multiversx_sc_wasm_adapter::endpoints! {
         deployPhaseFour => deploy_phase_four
         completeSetupPhase => complete_setup_phase
         getDeployedSovereignContracts => sovereign_deployed_contracts
+        getEsdtSafeSuccessor => esdt_safe_successor
         getTrustedTokens => trusted_tokens
         getSovereignSetupPhase => sovereign_setup_phase
         getChainFactoryAddress => chain_factories
         getDeployCost => deploy_cost
         getAllChainIds => chain_ids
+        registerEsdtSafeSuccessor => register_esdt_safe_successor
         updateEsdtSafeConfig => update_esdt_safe_config
         updateSovereignConfig => update_sovereign_config
         setFee => set_fee